@@ -0,0 +1,224 @@
+#[cfg(feature = "std")]
+extern crate std;
+
+pub use cipher;
+pub use digest::{self, Mac};
+
+use cipher::{BlockCipher, BlockEncrypt, BlockSizeUser, InnerIvInit, IvSizeUser};
+use core::{fmt, marker::PhantomData};
+use digest::{
+    block_buffer::{BlockBuffer, Eager},
+    core_api::AlgorithmName,
+    crypto_common::InnerUser,
+    generic_array::ArrayLength,
+    typenum::{U12, U16},
+    FixedOutput, MacMarker, Output, OutputSizeUser, Update,
+};
+use ghash::{
+    universal_hash::{NewUniversalHash, UniversalHash},
+    GHash,
+};
+
+pub type Nonce<NonceSize = U12> = cipher::generic_array::GenericArray<u8, NonceSize>;
+
+/// GMAC: GCM authentication of a message passed entirely as associated data.
+///
+/// The tag is equal to the one produced by AES-GCM encrypting an empty
+/// plaintext with the message as AAD, as used by IEEE 802.1AE for frames
+/// which are integrity protected but not encrypted.
+///
+/// A nonce must never be used twice with the same key, so this type can
+/// only be created through [`KeyIvInit`][cipher::KeyIvInit] and implements
+/// neither [`Reset`][digest::Reset] nor `Clone`.
+pub struct Gmac<C, NonceSize = U12>
+where
+    C: BlockCipher + BlockSizeUser<BlockSize = U16> + BlockEncrypt,
+    NonceSize: ArrayLength<u8>,
+{
+    ghash: GHash,
+    buffer: BlockBuffer<U16, Eager>,
+    len: u64,
+    mask: ghash::Block,
+    _pd: PhantomData<(C, NonceSize)>,
+}
+
+impl<C, NonceSize> InnerUser for Gmac<C, NonceSize>
+where
+    C: BlockCipher + BlockSizeUser<BlockSize = U16> + BlockEncrypt,
+    NonceSize: ArrayLength<u8>,
+{
+    type Inner = C;
+}
+
+impl<C, NonceSize> IvSizeUser for Gmac<C, NonceSize>
+where
+    C: BlockCipher + BlockSizeUser<BlockSize = U16> + BlockEncrypt,
+    NonceSize: ArrayLength<u8>,
+{
+    type IvSize = NonceSize;
+}
+
+impl<C, NonceSize> InnerIvInit for Gmac<C, NonceSize>
+where
+    C: BlockCipher + BlockSizeUser<BlockSize = U16> + BlockEncrypt,
+    NonceSize: ArrayLength<u8>,
+{
+    #[inline]
+    fn inner_iv_init(cipher: C, nonce: &Nonce<NonceSize>) -> Self {
+        let mut ghash_key = ghash::Key::default();
+        cipher.encrypt_block(&mut ghash_key);
+        let ghash = GHash::new(&ghash_key);
+
+        // Same `J0` derivation as AES-GCM
+        let mut mask = if NonceSize::USIZE == 12 {
+            let mut block = ghash::Block::default();
+            block[..12].copy_from_slice(nonce);
+            block[15] = 1;
+            block
+        } else {
+            let mut ghash = ghash.clone();
+            ghash.update_padded(nonce);
+
+            let mut block = ghash::Block::default();
+            let nonce_bits = (NonceSize::USIZE as u64) * 8;
+            block[8..].copy_from_slice(&nonce_bits.to_be_bytes());
+            ghash.update(&block);
+            ghash.finalize().into_bytes()
+        };
+        cipher.encrypt_block(&mut mask);
+
+        Self {
+            ghash,
+            buffer: Default::default(),
+            len: 0,
+            mask,
+            _pd: PhantomData,
+        }
+    }
+}
+
+impl<C, NonceSize> OutputSizeUser for Gmac<C, NonceSize>
+where
+    C: BlockCipher + BlockSizeUser<BlockSize = U16> + BlockEncrypt,
+    NonceSize: ArrayLength<u8>,
+{
+    type OutputSize = U16;
+}
+
+impl<C, NonceSize> MacMarker for Gmac<C, NonceSize>
+where
+    C: BlockCipher + BlockSizeUser<BlockSize = U16> + BlockEncrypt,
+    NonceSize: ArrayLength<u8>,
+{
+}
+
+impl<C, NonceSize> Update for Gmac<C, NonceSize>
+where
+    C: BlockCipher + BlockSizeUser<BlockSize = U16> + BlockEncrypt,
+    NonceSize: ArrayLength<u8>,
+{
+    #[inline]
+    fn update(&mut self, data: &[u8]) {
+        let Self { ghash, buffer, .. } = self;
+        self.len += data.len() as u64;
+        buffer.digest_blocks(data, |blocks| {
+            for block in blocks {
+                ghash.update(block);
+            }
+        });
+    }
+}
+
+impl<C, NonceSize> FixedOutput for Gmac<C, NonceSize>
+where
+    C: BlockCipher + BlockSizeUser<BlockSize = U16> + BlockEncrypt,
+    NonceSize: ArrayLength<u8>,
+{
+    #[inline]
+    fn finalize_into(mut self, out: &mut Output<Self>) {
+        if self.buffer.get_pos() != 0 {
+            self.ghash.update(self.buffer.pad_with_zeros());
+        }
+
+        let mut block = ghash::Block::default();
+        block[..8].copy_from_slice(&(self.len * 8).to_be_bytes());
+        self.ghash.update(&block);
+
+        let tag = self.ghash.finalize().into_bytes();
+        for ((o, t), m) in out.iter_mut().zip(tag.iter()).zip(self.mask.iter()) {
+            *o = t ^ m;
+        }
+    }
+}
+
+impl<C, NonceSize> AlgorithmName for Gmac<C, NonceSize>
+where
+    C: BlockCipher + BlockSizeUser<BlockSize = U16> + BlockEncrypt + AlgorithmName,
+    NonceSize: ArrayLength<u8>,
+{
+    fn write_alg_name(f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("Gmac<")?;
+        <C as AlgorithmName>::write_alg_name(f)?;
+        f.write_str(">")
+    }
+}
+
+impl<C, NonceSize> fmt::Debug for Gmac<C, NonceSize>
+where
+    C: BlockCipher + BlockSizeUser<BlockSize = U16> + BlockEncrypt + AlgorithmName,
+    NonceSize: ArrayLength<u8>,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("Gmac<")?;
+        <C as AlgorithmName>::write_alg_name(f)?;
+        f.write_str("> { ... }")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Gmac, Mac};
+    use aes::{Aes128, Aes256};
+    use cipher::KeyIvInit;
+    use digest::{consts::U16, dev::blobby::Blob4Iterator};
+    use hex_literal::hex;
+
+    macro_rules! gmac_test {
+        ($name:ident, $test_name:expr, $mac:ty) => {
+            #[test]
+            fn $name() {
+                let data = include_bytes!(concat!("data/", $test_name, ".blb"));
+                for row in Blob4Iterator::new(data).unwrap() {
+                    let [key, nonce, msg, tag] = row.unwrap();
+                    let new = || <$mac>::new_from_slices(key, nonce).unwrap();
+
+                    let mut mac = new();
+                    mac.update(msg);
+                    mac.verify_slice(tag).unwrap();
+
+                    for n in 1..msg.len().min(33) {
+                        let mut mac = new();
+                        for chunk in msg.chunks(n) {
+                            mac.update(chunk);
+                        }
+                        assert_eq!(&mac.finalize().into_bytes()[..], tag);
+                    }
+                }
+            }
+        };
+    }
+
+    // Vectors checked against AES-GCM with an empty plaintext.
+    gmac_test!(gmac_aes128, "aes128", Gmac<Aes128>);
+    gmac_test!(gmac_aes256, "aes256", Gmac<Aes256>);
+    gmac_test!(gmac_aes128_nonce16, "aes128_nonce16", Gmac<Aes128, U16>);
+
+    #[test]
+    fn gcm_spec_test_case_1() {
+        let mac = <Gmac<Aes128> as KeyIvInit>::new(&Default::default(), &Default::default());
+        assert_eq!(
+            mac.finalize().into_bytes()[..],
+            hex!("58e2fccefa7e3061367f1d57a4e7455a")
+        );
+    }
+}
@@ -0,0 +1,239 @@
+use crate::optim::{keyed_cores, Hmac, HmacCore};
+use core::{
+    fmt::{self, Write},
+    marker::PhantomData,
+    ops::Add,
+};
+use digest::{
+    block_buffer::Eager,
+    core_api::{
+        AlgorithmName, BlockSizeUser, BufferKindUser, CoreProxy, FixedOutputCore, UpdateCore,
+    },
+    crypto_common::{
+        hazmat::{DeserializeStateError, SerializableState, SerializedState},
+        Key, KeySizeUser,
+    },
+    generic_array::{
+        typenum::{IsLess, Le, NonZero, Sum, Unsigned, U256, U8},
+        ArrayLength, GenericArray,
+    },
+    HashMarker, InvalidLength, KeyInit, OutputSizeUser,
+};
+use zeroize::Zeroize;
+
+type StateSize<D> = <<D as CoreProxy>::Core as SerializableState>::SerializedStateSize;
+
+const ID_LEN: usize = U8::USIZE;
+
+/// Serialized [`HmacKeyState`]: an identifier of the hash function, then the
+/// inner state followed by the outer one.
+pub type SerializedKeyState<D> = GenericArray<u8, <D as KeyStateDigest>::SerializedKeyStateSize>;
+
+/// Hash functions whose HMAC key states can be serialized.
+///
+/// Collects the bounds of [`HmacKeyState`] and is implemented for every
+/// hash function meeting them.
+pub trait KeyStateDigest: CoreProxy<Core = Self::KeyCore> {
+    type KeyCore: HashMarker
+        + AlgorithmName
+        + UpdateCore
+        + FixedOutputCore
+        + BufferKindUser<BufferKind = Eager>
+        + BlockSizeUser<BlockSize = Self::KeyBlockSize>
+        + SerializableState
+        + Default
+        + Clone;
+    type KeyBlockSize: ArrayLength<u8> + IsLess<U256, Output = Self::KeyBlockSizeLe>;
+    type KeyBlockSizeLe: NonZero;
+    type SerializedKeyStateSize: ArrayLength<u8>;
+}
+
+impl<D> KeyStateDigest for D
+where
+    D: CoreProxy,
+    D::Core: HashMarker
+        + AlgorithmName
+        + UpdateCore
+        + FixedOutputCore
+        + BufferKindUser<BufferKind = Eager>
+        + SerializableState
+        + Default
+        + Clone,
+    <D::Core as BlockSizeUser>::BlockSize: IsLess<U256>,
+    Le<<D::Core as BlockSizeUser>::BlockSize, U256>: NonZero,
+    StateSize<D>: Add,
+    U8: Add<Sum<StateSize<D>, StateSize<D>>>,
+    Sum<U8, Sum<StateSize<D>, StateSize<D>>>: ArrayLength<u8>,
+{
+    type KeyCore = D::Core;
+    type KeyBlockSize = <D::Core as BlockSizeUser>::BlockSize;
+    type KeyBlockSizeLe = Le<<D::Core as BlockSizeUser>::BlockSize, U256>;
+    type SerializedKeyStateSize = Sum<U8, Sum<StateSize<D>, StateSize<D>>>;
+}
+
+/// Inner and outer hash states of HMAC after absorbing the key.
+///
+/// Creating a MAC with [`HmacKeyState::to_mac`] skips the two compressions
+/// needed to process the key, so a long-lived key can be set up once and
+/// used for many messages. The states are kept in serialized form, which
+/// can be exported with [`HmacKeyState::as_bytes`] and is zeroized on drop.
+/// The exported bytes are as sensitive as the key itself.
+///
+/// The BLAKE2 cores buffer blocks lazily and do not implement
+/// [`SerializableState`], so they can only be used with
+/// [`SimpleHmac`][crate::SimpleHmac] and have no key state.
+pub struct HmacKeyState<D>
+where
+    D: KeyStateDigest,
+{
+    bytes: SerializedKeyState<D>,
+}
+
+impl<D> HmacKeyState<D>
+where
+    D: KeyStateDigest,
+{
+    pub fn as_bytes(&self) -> &SerializedKeyState<D> {
+        &self.bytes
+    }
+
+    /// Import states previously exported with [`HmacKeyState::as_bytes`].
+    ///
+    /// Fails if the states were exported for a different hash function.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, DeserializeStateError> {
+        if bytes.len() != D::SerializedKeyStateSize::USIZE || bytes[..ID_LEN] != Self::id() {
+            return Err(DeserializeStateError);
+        }
+        let state = Self {
+            bytes: GenericArray::clone_from_slice(bytes),
+        };
+        let (inner, outer) = state.halves();
+        D::Core::deserialize(inner)?;
+        D::Core::deserialize(outer)?;
+        Ok(state)
+    }
+
+    pub fn to_mac(&self) -> Hmac<D> {
+        Hmac::from_core(self.into())
+    }
+
+    /// FNV-1a hash of the algorithm name, block size and output size.
+    fn id() -> [u8; ID_LEN] {
+        struct Name<C>(PhantomData<C>);
+
+        impl<C: AlgorithmName> fmt::Display for Name<C> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                C::write_alg_name(f)
+            }
+        }
+
+        struct Fnv(u64);
+
+        impl Write for Fnv {
+            fn write_str(&mut self, s: &str) -> fmt::Result {
+                for b in s.bytes() {
+                    self.0 = (self.0 ^ b as u64).wrapping_mul(0x0000_0100_0000_01b3);
+                }
+                Ok(())
+            }
+        }
+
+        let mut fnv = Fnv(0xcbf2_9ce4_8422_2325);
+        write!(
+            fnv,
+            "{}/{}/{}",
+            Name::<D::Core>(PhantomData),
+            <D::Core as BlockSizeUser>::BlockSize::USIZE,
+            <D::Core as OutputSizeUser>::OutputSize::USIZE,
+        )
+        .unwrap();
+        fnv.0.to_le_bytes()
+    }
+
+    fn halves(&self) -> (&SerializedState<D::Core>, &SerializedState<D::Core>) {
+        let (inner, outer) = self.bytes[ID_LEN..].split_at(StateSize::<D>::USIZE);
+        (
+            GenericArray::from_slice(inner),
+            GenericArray::from_slice(outer),
+        )
+    }
+}
+
+impl<D> From<&HmacKeyState<D>> for HmacCore<D>
+where
+    D: KeyStateDigest,
+{
+    #[inline]
+    fn from(state: &HmacKeyState<D>) -> Self {
+        // Both halves were produced by `serialize` or checked in `from_bytes`
+        let (inner, outer) = state.halves();
+        let digest = D::Core::deserialize(inner).unwrap();
+        let opad_digest = D::Core::deserialize(outer).unwrap();
+        Self::from_cores(digest, opad_digest)
+    }
+}
+
+impl<D> KeySizeUser for HmacKeyState<D>
+where
+    D: KeyStateDigest,
+{
+    type KeySize = <<D as CoreProxy>::Core as BlockSizeUser>::BlockSize;
+}
+
+impl<D> KeyInit for HmacKeyState<D>
+where
+    D: KeyStateDigest,
+{
+    #[inline(always)]
+    fn new(key: &Key<Self>) -> Self {
+        Self::new_from_slice(key.as_slice()).unwrap()
+    }
+
+    #[inline(always)]
+    fn new_from_slice(key: &[u8]) -> Result<Self, InvalidLength> {
+        let (inner, outer) = keyed_cores::<D>(key);
+        let mut bytes = SerializedKeyState::<D>::default();
+        let (id, states) = bytes.split_at_mut(ID_LEN);
+        id.copy_from_slice(&Self::id());
+        for (dst, core) in states
+            .chunks_exact_mut(StateSize::<D>::USIZE)
+            .zip([inner, outer])
+        {
+            let mut state = core.serialize();
+            dst.copy_from_slice(&state);
+            state.as_mut_slice().zeroize();
+        }
+        Ok(Self { bytes })
+    }
+}
+
+impl<D> Clone for HmacKeyState<D>
+where
+    D: KeyStateDigest,
+{
+    fn clone(&self) -> Self {
+        Self {
+            bytes: self.bytes.clone(),
+        }
+    }
+}
+
+impl<D> Drop for HmacKeyState<D>
+where
+    D: KeyStateDigest,
+{
+    fn drop(&mut self) {
+        self.bytes.as_mut_slice().zeroize();
+    }
+}
+
+impl<D> fmt::Debug for HmacKeyState<D>
+where
+    D: KeyStateDigest,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("HmacKeyState<")?;
+        <D::Core as AlgorithmName>::write_alg_name(f)?;
+        f.write_str("> { ... }")
+    }
+}
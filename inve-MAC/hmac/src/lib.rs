@@ -9,9 +9,11 @@ use digest::{
     Digest,
 };
 
+mod key_state;
 mod optim;
 mod simple;
 
+pub use key_state::{HmacKeyState, SerializedKeyState};
 pub use optim::{Hmac, HmacCore};
 pub use simple::SimpleHmac;
 
@@ -33,3 +35,73 @@ fn get_der_key<D: Digest + BlockSizeUser>(key: &[u8]) -> Block<D> {
     }
     der_key
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{Hmac, HmacKeyState, Mac, SimpleHmac};
+    use digest::{new_mac_test, KeyInit};
+    use streebog::{Streebog256, Streebog512};
+
+    // Test vectors from RFC 7836
+    new_mac_test!(hmac_streebog256, "streebog256", Hmac<Streebog256>);
+    new_mac_test!(hmac_streebog512, "streebog512", Hmac<Streebog512>);
+    new_mac_test!(
+        hmac_streebog256_simple,
+        "streebog256",
+        SimpleHmac<Streebog256>
+    );
+    new_mac_test!(
+        hmac_streebog512_simple,
+        "streebog512",
+        SimpleHmac<Streebog512>
+    );
+
+    macro_rules! key_state_test {
+        ($name:ident, $hash:ty) => {
+            #[test]
+            fn $name() {
+                for key_len in [0, 20, 64, 200] {
+                    let key: Vec<u8> = (0..key_len).map(|i| i as u8).collect();
+                    let state = HmacKeyState::<$hash>::new_from_slice(&key).unwrap();
+                    let imported = HmacKeyState::<$hash>::from_bytes(state.as_bytes()).unwrap();
+                    assert_eq!(imported.as_bytes(), state.as_bytes());
+
+                    let expected = <Hmac<$hash> as Mac>::new_from_slice(&key)
+                        .unwrap()
+                        .chain_update(b"message")
+                        .finalize()
+                        .into_bytes();
+                    for state in [&state, &imported, &state.clone()] {
+                        let mut mac = state.to_mac();
+                        mac.update(b"message");
+                        assert_eq!(mac.finalize().into_bytes(), expected);
+                    }
+                }
+
+                let state = HmacKeyState::<$hash>::new_from_slice(b"key").unwrap();
+                let bytes = state.as_bytes();
+                assert!(HmacKeyState::<$hash>::from_bytes(&bytes[1..]).is_err());
+            }
+        };
+    }
+
+    key_state_test!(key_state_sha1, sha1::Sha1);
+    key_state_test!(key_state_sha224, sha2::Sha224);
+    key_state_test!(key_state_sha256, sha2::Sha256);
+    key_state_test!(key_state_sha512_256, sha2::Sha512_256);
+    key_state_test!(key_state_sha512, sha2::Sha512);
+    key_state_test!(key_state_sha3_256, sha3::Sha3_256);
+    key_state_test!(key_state_keccak512, sha3::Keccak512);
+    key_state_test!(key_state_sm3, sm3::Sm3);
+    key_state_test!(key_state_streebog512, Streebog512);
+
+    #[test]
+    fn key_state_other_hash() {
+        let state = HmacKeyState::<sha2::Sha224>::new_from_slice(b"key").unwrap();
+        assert_eq!(state.as_bytes().len(), 8 + 2 * 40);
+        assert!(HmacKeyState::<sha2::Sha256>::from_bytes(state.as_bytes()).is_err());
+
+        let state = HmacKeyState::<sm3::Sm3>::new_from_slice(b"key").unwrap();
+        assert!(HmacKeyState::<sha2::Sha256>::from_bytes(state.as_bytes()).is_err());
+    }
+}
@@ -148,29 +148,65 @@ where
 
     #[inline(always)]
     fn new_from_slice(key: &[u8]) -> Result<Self, InvalidLength> {
-        let mut buf = get_der_key::<CoreWrapper<D::Core>>(key);
-        for b in buf.iter_mut() {
-            *b ^= IPAD;
-        }
-        let mut digest = D::Core::default();
-        digest.update_blocks(slice::from_ref(&buf));
-
-        for b in buf.iter_mut() {
-            *b ^= IPAD ^ OPAD;
-        }
-
-        let mut opad_digest = D::Core::default();
-        opad_digest.update_blocks(slice::from_ref(&buf));
+        let (digest, opad_digest) = keyed_cores::<D>(key);
+        Ok(Self::from_cores(digest, opad_digest))
+    }
+}
 
-        Ok(Self {
+impl<D> HmacCore<D>
+where
+    D: CoreProxy,
+    D::Core: HashMarker
+        + UpdateCore
+        + FixedOutputCore
+        + BufferKindUser<BufferKind = Eager>
+        + Default
+        + Clone,
+    <D::Core as BlockSizeUser>::BlockSize: IsLess<U256>,
+    Le<<D::Core as BlockSizeUser>::BlockSize, U256>: NonZero,
+{
+    #[inline(always)]
+    pub(crate) fn from_cores(digest: D::Core, opad_digest: D::Core) -> Self {
+        Self {
             #[cfg(feature = "reset")]
             ipad_digest: digest.clone(),
             opad_digest,
             digest,
-        })
+        }
     }
 }
 
+/// Digest cores after absorbing the key padded with `IPAD` and `OPAD`.
+#[inline(always)]
+pub(crate) fn keyed_cores<D>(key: &[u8]) -> (D::Core, D::Core)
+where
+    D: CoreProxy,
+    D::Core: HashMarker
+        + UpdateCore
+        + FixedOutputCore
+        + BufferKindUser<BufferKind = Eager>
+        + Default
+        + Clone,
+    <D::Core as BlockSizeUser>::BlockSize: IsLess<U256>,
+    Le<<D::Core as BlockSizeUser>::BlockSize, U256>: NonZero,
+{
+    let mut buf = get_der_key::<CoreWrapper<D::Core>>(key);
+    for b in buf.iter_mut() {
+        *b ^= IPAD;
+    }
+    let mut digest = D::Core::default();
+    digest.update_blocks(slice::from_ref(&buf));
+
+    for b in buf.iter_mut() {
+        *b ^= IPAD ^ OPAD;
+    }
+
+    let mut opad_digest = D::Core::default();
+    opad_digest.update_blocks(slice::from_ref(&buf));
+
+    (digest, opad_digest)
+}
+
 impl<D> UpdateCore for HmacCore<D>
 where
     D: CoreProxy,
@@ -0,0 +1,135 @@
+#[cfg(feature = "std")]
+extern crate std;
+
+pub use aes;
+pub use digest::{self, Mac};
+
+use aes::Aes128;
+use cipher::{BlockEncrypt, Iv, IvSizeUser, Key, KeyInit, KeyIvInit, KeySizeUser};
+use core::fmt;
+use digest::{
+    block_buffer::{BlockBuffer, Eager},
+    core_api::AlgorithmName,
+    typenum::{U16, U32},
+    FixedOutput, MacMarker, Output, OutputSizeUser, Update,
+};
+use poly1305::{
+    universal_hash::{NewUniversalHash, UniversalHash},
+    Poly1305,
+};
+
+/// Poly1305-AES as originally specified by Bernstein.
+///
+/// The 32-byte key is `k || r`, where `k` is the AES-128 key and `r` the
+/// Poly1305 multiplier (clamped as usual). The per-message nonce `n` is
+/// encrypted under `k` to form the final addend `AES_k(n)`.
+///
+/// A nonce must never be used twice with the same key, so this type
+/// implements neither [`Reset`][digest::Reset] nor `Clone`.
+pub struct Poly1305Aes {
+    poly: Poly1305,
+    buffer: BlockBuffer<U16, Eager>,
+}
+
+impl KeySizeUser for Poly1305Aes {
+    type KeySize = U32;
+}
+
+impl IvSizeUser for Poly1305Aes {
+    type IvSize = U16;
+}
+
+impl KeyIvInit for Poly1305Aes {
+    #[inline]
+    fn new(key: &Key<Self>, nonce: &Iv<Self>) -> Self {
+        let (k, r) = key.split_at(16);
+        let mut poly_key = poly1305::Key::default();
+        poly_key[..16].copy_from_slice(r);
+
+        let s = poly1305::Block::from_mut_slice(&mut poly_key[16..]);
+        s.copy_from_slice(nonce);
+        Aes128::new_from_slice(k).unwrap().encrypt_block(s);
+
+        let poly = Poly1305::new(&poly_key);
+        for b in poly_key.iter_mut() {
+            *b = 0;
+        }
+        Self {
+            poly,
+            buffer: Default::default(),
+        }
+    }
+}
+
+impl OutputSizeUser for Poly1305Aes {
+    type OutputSize = U16;
+}
+
+impl MacMarker for Poly1305Aes {}
+
+impl Update for Poly1305Aes {
+    #[inline]
+    fn update(&mut self, data: &[u8]) {
+        let Self { poly, buffer } = self;
+        buffer.digest_blocks(data, |blocks| {
+            for block in blocks {
+                poly.update(block);
+            }
+        });
+    }
+}
+
+impl FixedOutput for Poly1305Aes {
+    #[inline]
+    fn finalize_into(self, out: &mut Output<Self>) {
+        let tag = self.poly.compute_unpadded(self.buffer.get_data());
+        out.copy_from_slice(&tag.into_bytes());
+    }
+}
+
+impl AlgorithmName for Poly1305Aes {
+    fn write_alg_name(f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("Poly1305-AES")
+    }
+}
+
+impl fmt::Debug for Poly1305Aes {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("Poly1305Aes { ... }")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Mac, Poly1305Aes};
+    use cipher::KeyIvInit;
+    use digest::dev::blobby::Blob4Iterator;
+
+    // Vectors from Appendix B of "The Poly1305-AES message-authentication
+    // code", followed by longer messages covering partial final blocks.
+    #[test]
+    fn poly1305_aes() {
+        let data = include_bytes!("data/poly1305_aes.blb");
+        for row in Blob4Iterator::new(data).unwrap() {
+            let [key, nonce, msg, tag] = row.unwrap();
+            let new = || Poly1305Aes::new_from_slices(key, nonce).unwrap();
+
+            let mut mac = new();
+            mac.update(msg);
+            mac.verify_slice(tag).unwrap();
+
+            for n in 1..msg.len().min(33) {
+                let mut mac = new();
+                for chunk in msg.chunks(n) {
+                    mac.update(chunk);
+                }
+                assert_eq!(&mac.finalize().into_bytes()[..], tag);
+            }
+
+            let mut mac = new();
+            mac.update(msg);
+            mac.update(&[0]);
+            assert!(mac.verify_slice(tag).is_err());
+        }
+    }
+}
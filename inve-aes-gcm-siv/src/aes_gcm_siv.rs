@@ -0,0 +1,231 @@
+pub use aead::{self, AeadCore, AeadInPlace, Error, NewAead};
+pub use cipher::Key;
+
+#[cfg(feature = "aes")]
+pub use aes;
+
+use cipher::{
+    consts::{U0, U12, U16},
+    generic_array::GenericArray,
+    BlockCipher, BlockEncrypt, BlockSizeUser, InnerIvInit, KeyInit, KeySizeUser, StreamCipherCore,
+};
+use polyval::{
+    universal_hash::{NewUniversalHash, UniversalHash},
+    Polyval,
+};
+use zeroize::Zeroize;
+
+#[cfg(feature = "aes")]
+use aes::{Aes128, Aes256};
+
+pub const A_MAX: u64 = 1 << 36;
+
+pub const P_MAX: u64 = 1 << 36;
+
+pub const C_MAX: u64 = (1 << 36) + 16;
+
+pub type Nonce = GenericArray<u8, U12>;
+
+pub type Tag = GenericArray<u8, U16>;
+
+#[cfg(feature = "aes")]
+#[cfg_attr(docsrs, doc(cfg(feature = "aes")))]
+pub type Aes128GcmSiv = AesGcmSiv<Aes128>;
+
+#[cfg(feature = "aes")]
+#[cfg_attr(docsrs, doc(cfg(feature = "aes")))]
+pub type Aes256GcmSiv = AesGcmSiv<Aes256>;
+
+type Block = GenericArray<u8, U16>;
+
+type Ctr32LE<Aes> = ctr::CtrCore<Aes, ctr::flavors::Ctr32LE>;
+
+/// AES-GCM-SIV (RFC 8452): repeating a nonce only reveals whether two
+/// messages were identical.
+#[derive(Clone)]
+pub struct AesGcmSiv<Aes> {
+    key_generating_key: Aes,
+}
+
+impl<Aes> KeySizeUser for AesGcmSiv<Aes>
+where
+    Aes: KeyInit,
+{
+    type KeySize = Aes::KeySize;
+}
+
+impl<Aes> NewAead for AesGcmSiv<Aes>
+where
+    Aes: BlockSizeUser<BlockSize = U16> + BlockEncrypt + KeyInit,
+{
+    type KeySize = Aes::KeySize;
+
+    fn new(key: &Key<Self>) -> Self {
+        Aes::new(key).into()
+    }
+}
+
+impl<Aes> From<Aes> for AesGcmSiv<Aes>
+where
+    Aes: BlockSizeUser<BlockSize = U16> + BlockEncrypt,
+{
+    fn from(key_generating_key: Aes) -> Self {
+        Self { key_generating_key }
+    }
+}
+
+impl<Aes> AeadCore for AesGcmSiv<Aes> {
+    type NonceSize = U12;
+    type TagSize = U16;
+    type CiphertextOverhead = U0;
+}
+
+impl<Aes> AeadInPlace for AesGcmSiv<Aes>
+where
+    Aes: BlockCipher + BlockSizeUser<BlockSize = U16> + BlockEncrypt + KeyInit,
+{
+    fn encrypt_in_place_detached(
+        &self,
+        nonce: &Nonce,
+        associated_data: &[u8],
+        buffer: &mut [u8],
+    ) -> Result<Tag, Error> {
+        if buffer.len() as u64 > P_MAX || associated_data.len() as u64 > A_MAX {
+            return Err(Error);
+        }
+
+        Cipher::new(&self.key_generating_key, nonce)
+            .encrypt_in_place_detached(associated_data, buffer)
+    }
+
+    fn decrypt_in_place_detached(
+        &self,
+        nonce: &Nonce,
+        associated_data: &[u8],
+        buffer: &mut [u8],
+        tag: &Tag,
+    ) -> Result<(), Error> {
+        if buffer.len() as u64 > C_MAX || associated_data.len() as u64 > A_MAX {
+            return Err(Error);
+        }
+
+        Cipher::new(&self.key_generating_key, nonce).decrypt_in_place_detached(
+            associated_data,
+            buffer,
+            tag,
+        )
+    }
+}
+
+struct Cipher<Aes>
+where
+    Aes: BlockCipher + BlockSizeUser<BlockSize = U16> + BlockEncrypt,
+{
+    enc_cipher: Aes,
+
+    polyval: Polyval,
+
+    nonce: Nonce,
+}
+
+impl<Aes> Cipher<Aes>
+where
+    Aes: BlockCipher + BlockSizeUser<BlockSize = U16> + BlockEncrypt + KeyInit,
+{
+    /// Derives the per-nonce POLYVAL and encryption keys (RFC 8452, Section 4).
+    fn new(key_generating_key: &Aes, nonce: &Nonce) -> Self {
+        let mut mac_key = polyval::Key::default();
+        let mut enc_key = Key::<Aes>::default();
+        let mut block = Block::default();
+        let mut counter = 0u32;
+
+        for derived_key in [mac_key.as_mut_slice(), enc_key.as_mut_slice()] {
+            for chunk in derived_key.chunks_mut(8) {
+                block[..4].copy_from_slice(&counter.to_le_bytes());
+                block[4..].copy_from_slice(nonce);
+
+                key_generating_key.encrypt_block(&mut block);
+                chunk.copy_from_slice(&block[..8]);
+
+                counter += 1;
+            }
+        }
+
+        let cipher = Self {
+            enc_cipher: Aes::new(&enc_key),
+            polyval: Polyval::new(&mac_key),
+            nonce: *nonce,
+        };
+
+        mac_key.zeroize();
+        enc_key.zeroize();
+        block.zeroize();
+
+        cipher
+    }
+
+    fn encrypt_in_place_detached(
+        mut self,
+        associated_data: &[u8],
+        buffer: &mut [u8],
+    ) -> Result<Tag, Error> {
+        self.polyval.update_padded(associated_data);
+        self.polyval.update_padded(buffer);
+
+        let tag = self.finish_tag(associated_data.len(), buffer.len());
+        init_ctr(&self.enc_cipher, &tag).apply_keystream_partial(buffer.into());
+
+        Ok(tag)
+    }
+
+    fn decrypt_in_place_detached(
+        mut self,
+        associated_data: &[u8],
+        buffer: &mut [u8],
+        tag: &Tag,
+    ) -> Result<(), Error> {
+        self.polyval.update_padded(associated_data);
+
+        init_ctr(&self.enc_cipher, tag).apply_keystream_partial(buffer.into());
+        self.polyval.update_padded(buffer);
+
+        let expected_tag = self.finish_tag(associated_data.len(), buffer.len());
+
+        use subtle::ConstantTimeEq;
+        if expected_tag.ct_eq(tag).unwrap_u8() == 1 {
+            Ok(())
+        } else {
+            // Do not leave unauthenticated plaintext in the caller's buffer
+            init_ctr(&self.enc_cipher, tag).apply_keystream_partial(buffer.into());
+            Err(Error)
+        }
+    }
+
+    fn finish_tag(&mut self, associated_data_len: usize, buffer_len: usize) -> Tag {
+        let associated_data_bits = (associated_data_len as u64) * 8;
+        let buffer_bits = (buffer_len as u64) * 8;
+
+        let mut block = polyval::Block::default();
+        block[..8].copy_from_slice(&associated_data_bits.to_le_bytes());
+        block[8..].copy_from_slice(&buffer_bits.to_le_bytes());
+        self.polyval.update(&block);
+
+        let mut tag = self.polyval.finalize_reset().into_bytes();
+        for (a, b) in tag[..12].iter_mut().zip(self.nonce.as_slice()) {
+            *a ^= *b;
+        }
+        tag[15] &= 0x7f;
+
+        self.enc_cipher.encrypt_block(&mut tag);
+        tag
+    }
+}
+
+fn init_ctr<'a, Aes>(cipher: &'a Aes, tag: &Tag) -> Ctr32LE<&'a Aes>
+where
+    Aes: BlockCipher + BlockSizeUser<BlockSize = U16> + BlockEncrypt,
+{
+    let mut counter_block = *tag;
+    counter_block[15] |= 0x80;
+    Ctr32LE::inner_iv_init(cipher, &counter_block)
+}
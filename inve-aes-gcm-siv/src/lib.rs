@@ -0,0 +1,18 @@
+pub mod aes_gcm_siv;
+
+pub use aead::{self, AeadCore, AeadInPlace, Error, NewAead};
+
+pub use aes_gcm_siv::{AesGcmSiv, Key, Nonce, Tag};
+
+#[cfg(feature = "aes")]
+pub use aes_gcm_siv::{Aes128GcmSiv, Aes256GcmSiv};
+
+#[cfg(test)]
+mod tests {
+    use super::{Aes128GcmSiv, Aes256GcmSiv};
+
+    aead::new_test!(rfc8452_aes128, "rfc8452-128", Aes128GcmSiv);
+    aead::new_test!(rfc8452_aes256, "rfc8452-256", Aes256GcmSiv);
+    aead::new_test!(wycheproof_aes128, "wycheproof-128", Aes128GcmSiv);
+    aead::new_test!(wycheproof_aes256, "wycheproof-256", Aes256GcmSiv);
+}
@@ -1,7 +1,7 @@
 #![cfg_attr(feature = "heapless", doc = " ```")]
 #![cfg_attr(not(feature = "heapless"), doc = " ```ignore")]
 
-pub use aead::{self, AeadCore, AeadInPlace, Error, NewAead};
+pub use aead::{self, AeadComputeTag, AeadCore, AeadInPlace, Error, NewAead};
 pub use cipher::Key;
 
 #[cfg(feature = "aes")]
@@ -32,7 +32,32 @@ pub const C_MAX: u64 = (1 << 36) + 16;
 
 pub type Nonce<NonceSize> = GenericArray<u8, NonceSize>;
 
-pub type Tag = GenericArray<u8, U16>;
+pub type Tag<TagSize = U16> = GenericArray<u8, TagSize>;
+
+/// Tag sizes permitted by NIST SP 800-38D for general use (12 to 16 bytes).
+///
+/// Shorter tags are rejected at compile time:
+///
+/// ```compile_fail
+/// use inve_aes_gcm::{aead::consts::{U11, U12}, aes::Aes128, AesGcm, NewAead};
+///
+/// let cipher = AesGcm::<Aes128, U12, U11>::new(&Default::default());
+/// ```
+pub trait TagSize: private::SealedTagSize {}
+
+impl<T: private::SealedTagSize> TagSize for T {}
+
+mod private {
+    use cipher::{consts, generic_array::ArrayLength, typenum::Unsigned};
+
+    pub trait SealedTagSize: ArrayLength<u8> + Unsigned {}
+
+    impl SealedTagSize for consts::U12 {}
+    impl SealedTagSize for consts::U13 {}
+    impl SealedTagSize for consts::U14 {}
+    impl SealedTagSize for consts::U15 {}
+    impl SealedTagSize for consts::U16 {}
+}
 
 #[cfg(feature = "aes")]
 #[cfg_attr(docsrs, doc(cfg(feature = "aes")))]
@@ -47,24 +72,31 @@ type Block = GenericArray<u8, U16>;
 type Ctr32BE<Aes> = ctr::CtrCore<Aes, ctr::flavors::Ctr32BE>;
 
 #[derive(Clone)]
-pub struct AesGcm<Aes, NonceSize> {
+pub struct AesGcm<Aes, NonceSize, TagSize = U16>
+where
+    TagSize: self::TagSize,
+{
     cipher: Aes,
 
     ghash: GHash,
 
     nonce_size: PhantomData<NonceSize>,
+
+    tag_size: PhantomData<TagSize>,
 }
 
-impl<Aes, NonceSize> KeySizeUser for AesGcm<Aes, NonceSize>
+impl<Aes, NonceSize, TagSize> KeySizeUser for AesGcm<Aes, NonceSize, TagSize>
 where
     Aes: KeyInit,
+    TagSize: self::TagSize,
 {
     type KeySize = Aes::KeySize;
 }
 
-impl<Aes, NonceSize> NewAead for AesGcm<Aes, NonceSize>
+impl<Aes, NonceSize, TagSize> NewAead for AesGcm<Aes, NonceSize, TagSize>
 where
     Aes: BlockSizeUser<BlockSize = U16> + BlockEncrypt + KeyInit,
+    TagSize: self::TagSize,
 {
     type KeySize = Aes::KeySize;
 
@@ -73,9 +105,10 @@ where
     }
 }
 
-impl<Aes, NonceSize> From<Aes> for AesGcm<Aes, NonceSize>
+impl<Aes, NonceSize, TagSize> From<Aes> for AesGcm<Aes, NonceSize, TagSize>
 where
     Aes: BlockSizeUser<BlockSize = U16> + BlockEncrypt,
+    TagSize: self::TagSize,
 {
     fn from(cipher: Aes) -> Self {
         let mut ghash_key = ghash::Key::default();
@@ -90,30 +123,33 @@ where
             cipher,
             ghash,
             nonce_size: PhantomData,
+            tag_size: PhantomData,
         }
     }
 }
 
-impl<Aes, NonceSize> AeadCore for AesGcm<Aes, NonceSize>
+impl<Aes, NonceSize, TagSize> AeadCore for AesGcm<Aes, NonceSize, TagSize>
 where
     NonceSize: ArrayLength<u8>,
+    TagSize: self::TagSize,
 {
     type NonceSize = NonceSize;
-    type TagSize = U16;
+    type TagSize = TagSize;
     type CiphertextOverhead = U0;
 }
 
-impl<Aes, NonceSize> AeadInPlace for AesGcm<Aes, NonceSize>
+impl<Aes, NonceSize, TagSize> AeadInPlace for AesGcm<Aes, NonceSize, TagSize>
 where
     Aes: BlockCipher + BlockSizeUser<BlockSize = U16> + BlockEncrypt,
     NonceSize: ArrayLength<u8>,
+    TagSize: self::TagSize,
 {
     fn encrypt_in_place_detached(
         &self,
         nonce: &Nonce<NonceSize>,
         associated_data: &[u8],
         buffer: &mut [u8],
-    ) -> Result<Tag, Error> {
+    ) -> Result<Tag<TagSize>, Error> {
         if buffer.len() as u64 > P_MAX || associated_data.len() as u64 > A_MAX {
             return Err(Error);
         }
@@ -121,7 +157,11 @@ where
         let (ctr, mask) = self.init_ctr(nonce);
 
         ctr.apply_keystream_partial(buffer.into());
-        Ok(self.compute_tag(mask, associated_data, buffer))
+
+        let full_tag = self.compute_tag(mask, associated_data, buffer);
+        Ok(Tag::<TagSize>::clone_from_slice(
+            &full_tag[..TagSize::USIZE],
+        ))
     }
 
     fn decrypt_in_place_detached(
@@ -129,7 +169,7 @@ where
         nonce: &Nonce<NonceSize>,
         associated_data: &[u8],
         buffer: &mut [u8],
-        tag: &Tag,
+        tag: &Tag<TagSize>,
     ) -> Result<(), Error> {
         if buffer.len() as u64 > C_MAX || associated_data.len() as u64 > A_MAX {
             return Err(Error);
@@ -138,10 +178,10 @@ where
         let (ctr, mask) = self.init_ctr(nonce);
 
         let expected_tag = self.compute_tag(mask, associated_data, buffer);
-        ctr.apply_keystream_partial(buffer.into());
 
         use subtle::ConstantTimeEq;
-        if expected_tag.ct_eq(tag).unwrap_u8() == 1 {
+        if expected_tag[..TagSize::USIZE].ct_eq(tag).unwrap_u8() == 1 {
+            ctr.apply_keystream_partial(buffer.into());
             Ok(())
         } else {
             Err(Error)
@@ -149,10 +189,35 @@ where
     }
 }
 
-impl<Aes, NonceSize> AesGcm<Aes, NonceSize>
+impl<Aes, NonceSize, TagSize> AeadComputeTag for AesGcm<Aes, NonceSize, TagSize>
+where
+    Aes: BlockCipher + BlockSizeUser<BlockSize = U16> + BlockEncrypt,
+    NonceSize: ArrayLength<u8>,
+    TagSize: self::TagSize,
+{
+    fn compute_tag(
+        &self,
+        nonce: &Nonce<NonceSize>,
+        associated_data: &[u8],
+        ciphertext: &[u8],
+    ) -> Result<Tag<TagSize>, Error> {
+        if ciphertext.len() as u64 > C_MAX || associated_data.len() as u64 > A_MAX {
+            return Err(Error);
+        }
+
+        let (_, mask) = self.init_ctr(nonce);
+        let full_tag = self.compute_tag(mask, associated_data, ciphertext);
+        Ok(Tag::<TagSize>::clone_from_slice(
+            &full_tag[..TagSize::USIZE],
+        ))
+    }
+}
+
+impl<Aes, NonceSize, TagSize> AesGcm<Aes, NonceSize, TagSize>
 where
     Aes: BlockCipher + BlockSizeUser<BlockSize = U16> + BlockEncrypt,
     NonceSize: ArrayLength<u8>,
+    TagSize: self::TagSize,
 {
     fn init_ctr(&self, nonce: &Nonce<NonceSize>) -> (Ctr32BE<&Aes>, Block) {
         let j0 = if NonceSize::to_usize() == 12 {
@@ -1,5 +1,25 @@
 pub mod aes_gcm;
 
-pub use aead::{self, AeadCore, AeadInPlace, Error, NewAead};
+pub use aead::{self, AeadComputeTag, AeadCore, AeadInPlace, Error, NewAead};
 
-pub use aes_gcm::{Aes128Gcm, Aes256Gcm, Key, Nonce};
+pub use aes_gcm::{Aes128Gcm, Aes256Gcm, AesGcm, Key, Nonce, Tag, TagSize};
+
+#[cfg(feature = "aes")]
+pub use aes_gcm::aes;
+
+#[cfg(all(test, feature = "aes"))]
+mod tests {
+    use super::{aes::Aes128, aes::Aes256, AesGcm};
+    use aead::consts::{U12, U13, U14, U15, U16};
+
+    aead::new_test!(aes128gcm_tag12, "aes128gcm-tag12", AesGcm<Aes128, U12, U12>);
+    aead::new_test!(aes128gcm_tag13, "aes128gcm-tag13", AesGcm<Aes128, U12, U13>);
+    aead::new_test!(aes128gcm_tag14, "aes128gcm-tag14", AesGcm<Aes128, U12, U14>);
+    aead::new_test!(aes128gcm_tag15, "aes128gcm-tag15", AesGcm<Aes128, U12, U15>);
+    aead::new_test!(aes128gcm_tag16, "aes128gcm-tag16", AesGcm<Aes128, U12, U16>);
+    aead::new_test!(aes256gcm_tag12, "aes256gcm-tag12", AesGcm<Aes256, U12, U12>);
+    aead::new_test!(aes256gcm_tag13, "aes256gcm-tag13", AesGcm<Aes256, U12, U13>);
+    aead::new_test!(aes256gcm_tag14, "aes256gcm-tag14", AesGcm<Aes256, U12, U14>);
+    aead::new_test!(aes256gcm_tag15, "aes256gcm-tag15", AesGcm<Aes256, U12, U15>);
+    aead::new_test!(aes256gcm_tag16, "aes256gcm-tag16", AesGcm<Aes256, U12, U16>);
+}
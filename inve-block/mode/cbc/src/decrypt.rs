@@ -0,0 +1,194 @@
+use crate::xor;
+use cipher::{
+    crypto_common::{InnerUser, IvSizeUser},
+    generic_array::{ArrayLength, GenericArray},
+    inout::InOut,
+    AlgorithmName, Block, BlockBackend, BlockCipher, BlockClosure, BlockDecryptMut, BlockSizeUser,
+    InnerIvInit, Iv, IvState, ParBlocks, ParBlocksSizeUser,
+};
+use core::fmt;
+
+#[cfg(feature = "zeroize")]
+use cipher::zeroize::{Zeroize, ZeroizeOnDrop};
+
+#[derive(Clone)]
+pub struct Decryptor<C>
+where
+    C: BlockDecryptMut + BlockCipher,
+{
+    cipher: C,
+    iv: Block<C>,
+}
+
+impl<C> BlockSizeUser for Decryptor<C>
+where
+    C: BlockDecryptMut + BlockCipher,
+{
+    type BlockSize = C::BlockSize;
+}
+
+impl<C> BlockDecryptMut for Decryptor<C>
+where
+    C: BlockDecryptMut + BlockCipher,
+{
+    fn decrypt_with_backend_mut(&mut self, f: impl BlockClosure<BlockSize = Self::BlockSize>) {
+        let Self { cipher, iv } = self;
+        cipher.decrypt_with_backend_mut(Closure { iv, f })
+    }
+}
+
+impl<C> InnerUser for Decryptor<C>
+where
+    C: BlockDecryptMut + BlockCipher,
+{
+    type Inner = C;
+}
+
+impl<C> IvSizeUser for Decryptor<C>
+where
+    C: BlockDecryptMut + BlockCipher,
+{
+    type IvSize = C::BlockSize;
+}
+
+impl<C> InnerIvInit for Decryptor<C>
+where
+    C: BlockDecryptMut + BlockCipher,
+{
+    #[inline]
+    fn inner_iv_init(cipher: C, iv: &Iv<Self>) -> Self {
+        Self {
+            cipher,
+            iv: iv.clone(),
+        }
+    }
+}
+
+impl<C> IvState for Decryptor<C>
+where
+    C: BlockDecryptMut + BlockCipher,
+{
+    #[inline]
+    fn iv_state(&self) -> Iv<Self> {
+        self.iv.clone()
+    }
+}
+
+impl<C> AlgorithmName for Decryptor<C>
+where
+    C: BlockDecryptMut + BlockCipher + AlgorithmName,
+{
+    fn write_alg_name(f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("cbc::Decryptor<")?;
+        <C as AlgorithmName>::write_alg_name(f)?;
+        f.write_str(">")
+    }
+}
+
+impl<C> fmt::Debug for Decryptor<C>
+where
+    C: BlockDecryptMut + BlockCipher + AlgorithmName,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("cbc::Decryptor<")?;
+        <C as AlgorithmName>::write_alg_name(f)?;
+        f.write_str("> { ... }")
+    }
+}
+
+#[cfg(feature = "zeroize")]
+#[cfg_attr(docsrs, doc(cfg(feature = "zeroize")))]
+impl<C: BlockDecryptMut + BlockCipher> Drop for Decryptor<C> {
+    fn drop(&mut self) {
+        self.iv.zeroize();
+    }
+}
+
+#[cfg(feature = "zeroize")]
+#[cfg_attr(docsrs, doc(cfg(feature = "zeroize")))]
+impl<C: BlockDecryptMut + BlockCipher + ZeroizeOnDrop> ZeroizeOnDrop for Decryptor<C> {}
+
+struct Closure<'a, BS, BC>
+where
+    BS: ArrayLength<u8>,
+    BC: BlockClosure<BlockSize = BS>,
+{
+    iv: &'a mut GenericArray<u8, BS>,
+    f: BC,
+}
+
+impl<'a, BS, BC> BlockSizeUser for Closure<'a, BS, BC>
+where
+    BS: ArrayLength<u8>,
+    BC: BlockClosure<BlockSize = BS>,
+{
+    type BlockSize = BS;
+}
+
+impl<'a, BS, BC> BlockClosure for Closure<'a, BS, BC>
+where
+    BS: ArrayLength<u8>,
+    BC: BlockClosure<BlockSize = BS>,
+{
+    #[inline(always)]
+    fn call<B: BlockBackend<BlockSize = Self::BlockSize>>(self, backend: &mut B) {
+        let Self { iv, f } = self;
+        f.call(&mut Backend { iv, backend });
+    }
+}
+
+struct Backend<'a, BS, BK>
+where
+    BS: ArrayLength<u8>,
+    BK: BlockBackend<BlockSize = BS>,
+{
+    iv: &'a mut GenericArray<u8, BS>,
+    backend: &'a mut BK,
+}
+
+impl<'a, BS, BK> BlockSizeUser for Backend<'a, BS, BK>
+where
+    BS: ArrayLength<u8>,
+    BK: BlockBackend<BlockSize = BS>,
+{
+    type BlockSize = BS;
+}
+
+impl<'a, BS, BK> ParBlocksSizeUser for Backend<'a, BS, BK>
+where
+    BS: ArrayLength<u8>,
+    BK: BlockBackend<BlockSize = BS>,
+{
+    type ParBlocksSize = BK::ParBlocksSize;
+}
+
+impl<'a, BS, BK> BlockBackend for Backend<'a, BS, BK>
+where
+    BS: ArrayLength<u8>,
+    BK: BlockBackend<BlockSize = BS>,
+{
+    #[inline(always)]
+    fn proc_block(&mut self, mut block: InOut<'_, '_, Block<Self>>) {
+        let in_block = block.clone_in();
+        let mut t = block.clone_in();
+        self.backend.proc_block((&mut t).into());
+        xor(&mut t, self.iv);
+        *block.get_out() = t;
+        *self.iv = in_block;
+    }
+
+    #[inline(always)]
+    fn proc_par_blocks(&mut self, mut blocks: InOut<'_, '_, ParBlocks<Self>>) {
+        let in_blocks = blocks.clone_in();
+        let mut t = blocks.clone_in();
+
+        self.backend.proc_par_blocks((&mut t).into());
+        let n = t.len();
+        xor(&mut t[0], self.iv);
+        for i in 1..n {
+            xor(&mut t[i], &in_blocks[i - 1])
+        }
+        *blocks.get_out() = t;
+        *self.iv = in_blocks[n - 1].clone();
+    }
+}
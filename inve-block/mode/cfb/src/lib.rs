@@ -0,0 +1,78 @@
+mod decrypt;
+mod encrypt;
+
+pub use cipher;
+pub use decrypt::{BufDecryptor, Decryptor};
+pub use encrypt::{BufEncryptor, Encryptor};
+
+#[cfg(test)]
+mod tests {
+    use super::{BufDecryptor, BufEncryptor, Decryptor, Encryptor};
+    use aes::{Aes128, Aes128Enc, Aes192, Aes256};
+    use cipher::{block_mode_dec_test, block_mode_enc_test, iv_state_test, KeyInit, KeyIvInit};
+    use des::{Des, TdesEde3};
+    use hex_literal::hex;
+    use kuznyechik::Kuznyechik;
+    use magma::Magma;
+    use sm4::Sm4;
+
+    iv_state_test!(aes128_cfb_enc_iv_state, Encryptor<Aes128>, encrypt);
+    iv_state_test!(aes128_cfb_dec_iv_state, Decryptor<Aes128>, decrypt);
+    iv_state_test!(tdes_cfb_enc_iv_state, Encryptor<TdesEde3>, encrypt);
+    iv_state_test!(tdes_cfb_dec_iv_state, Decryptor<TdesEde3>, decrypt);
+    iv_state_test!(sm4_cfb_enc_iv_state, Encryptor<Sm4>, encrypt);
+    iv_state_test!(sm4_cfb_dec_iv_state, Decryptor<Sm4>, decrypt);
+    iv_state_test!(magma_cfb_enc_iv_state, Encryptor<Magma>, encrypt);
+    iv_state_test!(magma_cfb_dec_iv_state, Decryptor<Magma>, decrypt);
+    iv_state_test!(kuznyechik_cfb_enc_iv_state, Encryptor<Kuznyechik>, encrypt);
+    iv_state_test!(kuznyechik_cfb_dec_iv_state, Decryptor<Kuznyechik>, decrypt);
+
+    block_mode_enc_test!(aes128_cfb_enc_test, "aes128", Encryptor<Aes128>);
+    block_mode_dec_test!(aes128_cfb_dec_test, "aes128", Decryptor<Aes128>);
+    block_mode_enc_test!(aes128enc_cfb_enc_test, "aes128", Encryptor<Aes128Enc>);
+    block_mode_dec_test!(aes128enc_cfb_dec_test, "aes128", Decryptor<Aes128Enc>);
+    block_mode_enc_test!(aes192_cfb_enc_test, "aes192", Encryptor<Aes192>);
+    block_mode_dec_test!(aes192_cfb_dec_test, "aes192", Decryptor<Aes192>);
+    block_mode_enc_test!(aes256_cfb_enc_test, "aes256", Encryptor<Aes256>);
+    block_mode_dec_test!(aes256_cfb_dec_test, "aes256", Decryptor<Aes256>);
+    block_mode_enc_test!(des_cfb_enc_test, "des", Encryptor<Des>);
+    block_mode_dec_test!(des_cfb_dec_test, "des", Decryptor<Des>);
+    block_mode_enc_test!(tdes_cfb_enc_test, "tdes", Encryptor<TdesEde3>);
+    block_mode_dec_test!(tdes_cfb_dec_test, "tdes", Decryptor<TdesEde3>);
+    block_mode_enc_test!(sm4_cfb_enc_test, "sm4", Encryptor<Sm4>);
+    block_mode_dec_test!(sm4_cfb_dec_test, "sm4", Decryptor<Sm4>);
+
+    // GOST R 34.13-2015, A.1.5 and A.2.5
+    block_mode_enc_test!(kuznyechik_cfb_enc_test, "kuznyechik", Encryptor<Kuznyechik>);
+    block_mode_dec_test!(kuznyechik_cfb_dec_test, "kuznyechik", Decryptor<Kuznyechik>);
+    block_mode_enc_test!(magma_cfb_enc_test, "magma", Encryptor<Magma>);
+    block_mode_dec_test!(magma_cfb_dec_test, "magma", Decryptor<Magma>);
+
+    #[test]
+    fn buf_resume() {
+        let key = hex!("000102030405060708090a0b0c0d0e0f");
+        let iv = hex!("0f0e0d0c0b0a09080706050403020100");
+        let mut pt = [0u8; 75];
+        for (i, b) in pt.iter_mut().enumerate() {
+            *b = i as u8;
+        }
+
+        let mut expected = pt;
+        BufEncryptor::<Aes128>::new(&key.into(), &iv.into()).encrypt(&mut expected);
+
+        let mut buf = pt;
+        let mut enc = BufEncryptor::<Aes128>::new(&key.into(), &iv.into());
+        let (head, tail) = buf.split_at_mut(21);
+        enc.encrypt(head);
+        let (state, pos) = enc.get_state();
+        let mut enc = BufEncryptor::from_state(Aes128::new(&key.into()), state, pos);
+        enc.encrypt(tail);
+        assert_eq!(buf, expected);
+
+        let mut dec = BufDecryptor::<Aes128>::new(&key.into(), &iv.into());
+        for chunk in buf.chunks_mut(7) {
+            dec.decrypt(chunk);
+        }
+        assert_eq!(buf, pt);
+    }
+}
@@ -0,0 +1,209 @@
+mod backend;
+
+pub use cipher;
+
+use backend::{Closure, KeystreamClosure};
+use cipher::{
+    crypto_common::{InnerUser, IvSizeUser},
+    AlgorithmName, Block, BlockCipher, BlockClosure, BlockDecryptMut, BlockEncryptMut,
+    BlockSizeUser, InnerIvInit, Iv, IvState, StreamCipherCore, StreamCipherCoreWrapper,
+    StreamClosure,
+};
+use core::fmt;
+
+#[cfg(feature = "zeroize")]
+use cipher::zeroize::{Zeroize, ZeroizeOnDrop};
+
+pub type Ofb<C> = StreamCipherCoreWrapper<OfbCore<C>>;
+
+#[derive(Clone)]
+pub struct OfbCore<C>
+where
+    C: BlockEncryptMut + BlockCipher,
+{
+    cipher: C,
+    iv: Block<C>,
+}
+
+impl<C> BlockSizeUser for OfbCore<C>
+where
+    C: BlockEncryptMut + BlockCipher,
+{
+    type BlockSize = C::BlockSize;
+}
+
+impl<C> InnerUser for OfbCore<C>
+where
+    C: BlockEncryptMut + BlockCipher,
+{
+    type Inner = C;
+}
+
+impl<C> IvSizeUser for OfbCore<C>
+where
+    C: BlockEncryptMut + BlockCipher,
+{
+    type IvSize = C::BlockSize;
+}
+
+impl<C> InnerIvInit for OfbCore<C>
+where
+    C: BlockEncryptMut + BlockCipher,
+{
+    #[inline]
+    fn inner_iv_init(cipher: C, iv: &Iv<Self>) -> Self {
+        Self {
+            cipher,
+            iv: iv.clone(),
+        }
+    }
+}
+
+impl<C> IvState for OfbCore<C>
+where
+    C: BlockEncryptMut + BlockCipher,
+{
+    #[inline]
+    fn iv_state(&self) -> Iv<Self> {
+        self.iv.clone()
+    }
+}
+
+impl<C> StreamCipherCore for OfbCore<C>
+where
+    C: BlockEncryptMut + BlockCipher,
+{
+    #[inline]
+    fn remaining_blocks(&self) -> Option<usize> {
+        None
+    }
+
+    #[inline]
+    fn process_with_backend(&mut self, f: impl StreamClosure<BlockSize = Self::BlockSize>) {
+        let Self { cipher, iv } = self;
+        cipher.encrypt_with_backend_mut(KeystreamClosure { iv, f });
+    }
+}
+
+impl<C> BlockEncryptMut for OfbCore<C>
+where
+    C: BlockEncryptMut + BlockCipher,
+{
+    #[inline]
+    fn encrypt_with_backend_mut(&mut self, f: impl BlockClosure<BlockSize = Self::BlockSize>) {
+        let Self { cipher, iv } = self;
+        cipher.encrypt_with_backend_mut(Closure { iv, f });
+    }
+}
+
+impl<C> BlockDecryptMut for OfbCore<C>
+where
+    C: BlockEncryptMut + BlockCipher,
+{
+    #[inline]
+    fn decrypt_with_backend_mut(&mut self, f: impl BlockClosure<BlockSize = Self::BlockSize>) {
+        let Self { cipher, iv } = self;
+        cipher.encrypt_with_backend_mut(Closure { iv, f });
+    }
+}
+
+impl<C> AlgorithmName for OfbCore<C>
+where
+    C: BlockEncryptMut + BlockCipher + AlgorithmName,
+{
+    fn write_alg_name(f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("Ofb<")?;
+        <C as AlgorithmName>::write_alg_name(f)?;
+        f.write_str(">")
+    }
+}
+
+impl<C> fmt::Debug for OfbCore<C>
+where
+    C: BlockEncryptMut + BlockCipher + AlgorithmName,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("OfbCore<")?;
+        <C as AlgorithmName>::write_alg_name(f)?;
+        f.write_str("> { ... }")
+    }
+}
+
+#[cfg(feature = "zeroize")]
+#[cfg_attr(docsrs, doc(cfg(feature = "zeroize")))]
+impl<C: BlockEncryptMut + BlockCipher> Drop for OfbCore<C> {
+    fn drop(&mut self) {
+        self.iv.zeroize();
+    }
+}
+
+#[cfg(feature = "zeroize")]
+#[cfg_attr(docsrs, doc(cfg(feature = "zeroize")))]
+impl<C: BlockEncryptMut + BlockCipher + ZeroizeOnDrop> ZeroizeOnDrop for OfbCore<C> {}
+
+#[cfg(test)]
+mod tests {
+    use super::{Ofb, OfbCore};
+    use aes::{Aes128, Aes256};
+    use cipher::{
+        block_mode_dec_test, block_mode_enc_test, iv_state_test, KeyIvInit, StreamCipher,
+    };
+    use des::{Des, TdesEde3};
+    use hex_literal::hex;
+    use kuznyechik::Kuznyechik;
+    use magma::Magma;
+    use sm4::Sm4;
+
+    iv_state_test!(aes128_ofb_enc_iv_state, OfbCore<Aes128>, encrypt);
+    iv_state_test!(aes128_ofb_dec_iv_state, OfbCore<Aes128>, decrypt);
+    iv_state_test!(aes128_ofb_apply_ks_iv_state, OfbCore<Aes128>, apply_ks);
+    iv_state_test!(tdes_ofb_iv_state, OfbCore<TdesEde3>, apply_ks);
+    iv_state_test!(sm4_ofb_iv_state, OfbCore<Sm4>, apply_ks);
+    iv_state_test!(magma_ofb_iv_state, OfbCore<Magma>, apply_ks);
+    iv_state_test!(kuznyechik_ofb_iv_state, OfbCore<Kuznyechik>, apply_ks);
+
+    block_mode_enc_test!(aes128_ofb_enc_test, "aes128", OfbCore<Aes128>);
+    block_mode_dec_test!(aes128_ofb_dec_test, "aes128", OfbCore<Aes128>);
+    block_mode_enc_test!(aes256_ofb_enc_test, "aes256", OfbCore<Aes256>);
+    block_mode_dec_test!(aes256_ofb_dec_test, "aes256", OfbCore<Aes256>);
+    block_mode_enc_test!(des_ofb_enc_test, "des", OfbCore<Des>);
+    block_mode_dec_test!(des_ofb_dec_test, "des", OfbCore<Des>);
+    block_mode_enc_test!(tdes_ofb_enc_test, "tdes", OfbCore<TdesEde3>);
+    block_mode_dec_test!(tdes_ofb_dec_test, "tdes", OfbCore<TdesEde3>);
+    block_mode_enc_test!(sm4_ofb_enc_test, "sm4", OfbCore<Sm4>);
+    block_mode_dec_test!(sm4_ofb_dec_test, "sm4", OfbCore<Sm4>);
+
+    // GOST R 34.13-2015, A.1.3 and A.2.3
+    block_mode_enc_test!(kuznyechik_ofb_enc_test, "kuznyechik", OfbCore<Kuznyechik>);
+    block_mode_dec_test!(kuznyechik_ofb_dec_test, "kuznyechik", OfbCore<Kuznyechik>);
+    block_mode_enc_test!(magma_ofb_enc_test, "magma", OfbCore<Magma>);
+    block_mode_dec_test!(magma_ofb_dec_test, "magma", OfbCore<Magma>);
+
+    // NIST SP 800-38A, F.4.1
+    #[test]
+    fn aes128_ofb_stream() {
+        let key = hex!("2b7e151628aed2a6abf7158809cf4f3c");
+        let iv = hex!("000102030405060708090a0b0c0d0e0f");
+        let pt = hex!(
+            "6bc1bee22e409f96e93d7e117393172a"
+            "ae2d8a571e03ac9c9eb76fac45af8e51"
+            "30c81c46a35ce411e5fbc1191a0a52ef"
+            "f69f2445df4f9b17ad2b417be66c3710"
+        );
+        let ct = hex!(
+            "3b3fd92eb72dad20333449f8e83cfb4a"
+            "7789508d16918f03f53c52dac54ed825"
+            "9740051e9c5fecf64344f7a82260edcc"
+            "304c6528f659c77866a510d9c1d6ae5e"
+        );
+
+        for n in [1, 15, 16, 17, 31, 64] {
+            let mut buf = pt;
+            let mut cipher = Ofb::<Aes128>::new(&key.into(), &iv.into());
+            for chunk in buf.chunks_mut(n) {
+                cipher.apply_keystream(chunk);
+            }
+            assert_eq!(buf, ct);
+        }
+    }
+}
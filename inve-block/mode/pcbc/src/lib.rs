@@ -0,0 +1,64 @@
+mod decrypt;
+mod encrypt;
+
+pub use cipher;
+pub use decrypt::Decryptor;
+pub use encrypt::Encryptor;
+
+use cipher::generic_array::{ArrayLength, GenericArray};
+
+#[inline(always)]
+fn xor<N: ArrayLength<u8>>(out: &mut GenericArray<u8, N>, buf: &GenericArray<u8, N>) {
+    for (a, b) in out.iter_mut().zip(buf) {
+        *a ^= *b;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Decryptor, Encryptor};
+    use aes::{Aes128, Aes128Dec, Aes128Enc, Aes256};
+    use cipher::{block_mode_dec_test, block_mode_enc_test, iv_state_test};
+    use des::{Des, TdesEde3};
+    use kuznyechik::Kuznyechik;
+    use magma::Magma;
+    use sm4::Sm4;
+
+    iv_state_test!(aes128_pcbc_enc_iv_state, Encryptor<Aes128>, encrypt);
+    iv_state_test!(aes128_pcbc_dec_iv_state, Decryptor<Aes128>, decrypt);
+    iv_state_test!(tdes_pcbc_enc_iv_state, Encryptor<TdesEde3>, encrypt);
+    iv_state_test!(tdes_pcbc_dec_iv_state, Decryptor<TdesEde3>, decrypt);
+    iv_state_test!(sm4_pcbc_enc_iv_state, Encryptor<Sm4>, encrypt);
+    iv_state_test!(sm4_pcbc_dec_iv_state, Decryptor<Sm4>, decrypt);
+    iv_state_test!(magma_pcbc_enc_iv_state, Encryptor<Magma>, encrypt);
+    iv_state_test!(magma_pcbc_dec_iv_state, Decryptor<Magma>, decrypt);
+    iv_state_test!(kuznyechik_pcbc_enc_iv_state, Encryptor<Kuznyechik>, encrypt);
+    iv_state_test!(kuznyechik_pcbc_dec_iv_state, Decryptor<Kuznyechik>, decrypt);
+
+    block_mode_enc_test!(aes128_pcbc_enc_test, "aes128", Encryptor<Aes128>);
+    block_mode_dec_test!(aes128_pcbc_dec_test, "aes128", Decryptor<Aes128>);
+    block_mode_enc_test!(aes128enc_pcbc_enc_test, "aes128", Encryptor<Aes128Enc>);
+    block_mode_dec_test!(aes128dec_pcbc_dec_test, "aes128", Decryptor<Aes128Dec>);
+    block_mode_enc_test!(aes256_pcbc_enc_test, "aes256", Encryptor<Aes256>);
+    block_mode_dec_test!(aes256_pcbc_dec_test, "aes256", Decryptor<Aes256>);
+    block_mode_enc_test!(des_pcbc_enc_test, "des", Encryptor<Des>);
+    block_mode_dec_test!(des_pcbc_dec_test, "des", Decryptor<Des>);
+    block_mode_enc_test!(tdes_pcbc_enc_test, "tdes", Encryptor<TdesEde3>);
+    block_mode_dec_test!(tdes_pcbc_dec_test, "tdes", Decryptor<TdesEde3>);
+    block_mode_enc_test!(sm4_pcbc_enc_test, "sm4", Encryptor<Sm4>);
+    block_mode_dec_test!(sm4_pcbc_dec_test, "sm4", Decryptor<Sm4>);
+
+    // GOST R 34.13-2015 has no PCBC; key, IV and plaintext are taken from A.1 and A.2
+    block_mode_enc_test!(
+        kuznyechik_pcbc_enc_test,
+        "kuznyechik",
+        Encryptor<Kuznyechik>
+    );
+    block_mode_dec_test!(
+        kuznyechik_pcbc_dec_test,
+        "kuznyechik",
+        Decryptor<Kuznyechik>
+    );
+    block_mode_enc_test!(magma_pcbc_enc_test, "magma", Encryptor<Magma>);
+    block_mode_dec_test!(magma_pcbc_dec_test, "magma", Decryptor<Magma>);
+}
@@ -0,0 +1,41 @@
+use crate::{next_tweak, xor, Block};
+use cipher::{
+    consts::U16, typenum::Unsigned, BlockBackend, BlockClosure, BlockSizeUser, ParBlocks,
+};
+
+pub(crate) struct Closure<'a> {
+    pub(crate) blocks: &'a mut [Block],
+    pub(crate) tweak: &'a mut u128,
+}
+
+impl<'a> BlockSizeUser for Closure<'a> {
+    type BlockSize = U16;
+}
+
+impl<'a> BlockClosure for Closure<'a> {
+    #[inline(always)]
+    fn call<B: BlockBackend<BlockSize = U16>>(self, backend: &mut B) {
+        let Self { blocks, tweak } = self;
+
+        let mut chunks = blocks.chunks_exact_mut(B::ParBlocksSize::USIZE);
+        for chunk in &mut chunks {
+            let chunk = ParBlocks::<B>::from_mut_slice(chunk);
+            let mut tweaks = ParBlocks::<B>::default();
+            for (t, block) in tweaks.iter_mut().zip(chunk.iter_mut()) {
+                *t = next_tweak(tweak);
+                xor(block, t);
+            }
+            backend.proc_par_blocks(chunk.into());
+            for (t, block) in tweaks.iter().zip(chunk.iter_mut()) {
+                xor(block, t);
+            }
+        }
+
+        for block in chunks.into_remainder() {
+            let t = next_tweak(tweak);
+            xor(block, &t);
+            backend.proc_block(block.into());
+            xor(block, &t);
+        }
+    }
+}
@@ -0,0 +1,322 @@
+mod backend;
+
+pub use cipher;
+
+use backend::Closure;
+use cipher::{
+    consts::U16,
+    generic_array::{ArrayLength, GenericArray},
+    inout::InOutBuf,
+    typenum::Sum,
+    AlgorithmName, BlockCipher, BlockDecrypt, BlockEncrypt, BlockSizeUser, InvalidLength, Key,
+    KeyInit, KeySizeUser,
+};
+use core::{fmt, ops::Add};
+
+pub type Tweak = GenericArray<u8, U16>;
+
+type Block = GenericArray<u8, U16>;
+
+const BLOCK_SIZE: usize = 16;
+
+/// XTS (IEEE 1619): `cipher_1` encrypts the data, `cipher_2` encrypts the tweak.
+#[derive(Clone)]
+pub struct Xts<C>
+where
+    C: BlockCipher + BlockSizeUser<BlockSize = U16> + BlockEncrypt + BlockDecrypt,
+{
+    cipher_1: C,
+    cipher_2: C,
+}
+
+impl<C> KeySizeUser for Xts<C>
+where
+    C: BlockCipher + BlockSizeUser<BlockSize = U16> + BlockEncrypt + BlockDecrypt + KeyInit,
+    C::KeySize: Add<C::KeySize>,
+    Sum<C::KeySize, C::KeySize>: ArrayLength<u8> + 'static,
+{
+    type KeySize = Sum<C::KeySize, C::KeySize>;
+}
+
+impl<C> KeyInit for Xts<C>
+where
+    C: BlockCipher + BlockSizeUser<BlockSize = U16> + BlockEncrypt + BlockDecrypt + KeyInit,
+    C::KeySize: Add<C::KeySize>,
+    Sum<C::KeySize, C::KeySize>: ArrayLength<u8> + 'static,
+{
+    fn new(key: &Key<Self>) -> Self {
+        let (key_1, key_2) = key.split_at(key.len() / 2);
+        Self::from_ciphers(
+            C::new(Key::<C>::from_slice(key_1)),
+            C::new(Key::<C>::from_slice(key_2)),
+        )
+    }
+}
+
+impl<C> Xts<C>
+where
+    C: BlockCipher + BlockSizeUser<BlockSize = U16> + BlockEncrypt + BlockDecrypt,
+{
+    pub fn from_ciphers(cipher_1: C, cipher_2: C) -> Self {
+        Self { cipher_1, cipher_2 }
+    }
+
+    pub fn encrypt_sector(&self, sector: &mut [u8], tweak: &Tweak) -> Result<(), InvalidLength> {
+        if sector.len() < BLOCK_SIZE {
+            return Err(InvalidLength);
+        }
+
+        let mut t = self.initial_tweak(tweak);
+        let rem = sector.len() % BLOCK_SIZE;
+        let (head, last) = sector.split_at_mut(full_blocks_len(sector.len()));
+        self.cipher_1.encrypt_with_backend(Closure {
+            blocks: into_blocks(head),
+            tweak: &mut t,
+        });
+
+        if rem != 0 {
+            let (block, tail) = last.split_at_mut(BLOCK_SIZE);
+            let t1 = next_tweak(&mut t);
+            let t2 = next_tweak(&mut t);
+
+            let mut cc = Block::clone_from_slice(block);
+            self.encrypt_block(&mut cc, &t1);
+
+            let mut pp = cc;
+            pp[..rem].copy_from_slice(tail);
+            tail.copy_from_slice(&cc[..rem]);
+            self.encrypt_block(&mut pp, &t2);
+            block.copy_from_slice(&pp);
+        }
+
+        Ok(())
+    }
+
+    pub fn decrypt_sector(&self, sector: &mut [u8], tweak: &Tweak) -> Result<(), InvalidLength> {
+        if sector.len() < BLOCK_SIZE {
+            return Err(InvalidLength);
+        }
+
+        let mut t = self.initial_tweak(tweak);
+        let rem = sector.len() % BLOCK_SIZE;
+        let (head, last) = sector.split_at_mut(full_blocks_len(sector.len()));
+        self.cipher_1.decrypt_with_backend(Closure {
+            blocks: into_blocks(head),
+            tweak: &mut t,
+        });
+
+        if rem != 0 {
+            let (block, tail) = last.split_at_mut(BLOCK_SIZE);
+            let t1 = next_tweak(&mut t);
+            let t2 = next_tweak(&mut t);
+
+            let mut pp = Block::clone_from_slice(block);
+            self.decrypt_block(&mut pp, &t2);
+
+            let mut cc = pp;
+            cc[..rem].copy_from_slice(tail);
+            tail.copy_from_slice(&pp[..rem]);
+            self.decrypt_block(&mut cc, &t1);
+            block.copy_from_slice(&cc);
+        }
+
+        Ok(())
+    }
+
+    /// Encrypts consecutive sectors of `area`, using the sector number as
+    /// tweak (see [`sector_tweak`]). The last sector may be shorter.
+    ///
+    /// Fails if the sector numbers would run past `u128::MAX`.
+    pub fn encrypt_area(
+        &self,
+        area: &mut [u8],
+        sector_size: usize,
+        first_sector: u128,
+    ) -> Result<(), InvalidLength> {
+        check_area(area.len(), sector_size, first_sector)?;
+        for (i, sector) in area.chunks_mut(sector_size).enumerate() {
+            self.encrypt_sector(sector, &sector_tweak(first_sector + i as u128))?;
+        }
+        Ok(())
+    }
+
+    pub fn decrypt_area(
+        &self,
+        area: &mut [u8],
+        sector_size: usize,
+        first_sector: u128,
+    ) -> Result<(), InvalidLength> {
+        check_area(area.len(), sector_size, first_sector)?;
+        for (i, sector) in area.chunks_mut(sector_size).enumerate() {
+            self.decrypt_sector(sector, &sector_tweak(first_sector + i as u128))?;
+        }
+        Ok(())
+    }
+
+    fn initial_tweak(&self, tweak: &Tweak) -> u128 {
+        let mut t = *tweak;
+        self.cipher_2.encrypt_block(&mut t);
+        u128::from_le_bytes(t.into())
+    }
+
+    fn encrypt_block(&self, block: &mut Block, tweak: &Block) {
+        xor(block, tweak);
+        self.cipher_1.encrypt_block(block);
+        xor(block, tweak);
+    }
+
+    fn decrypt_block(&self, block: &mut Block, tweak: &Block) {
+        xor(block, tweak);
+        self.cipher_1.decrypt_block(block);
+        xor(block, tweak);
+    }
+}
+
+impl<C> AlgorithmName for Xts<C>
+where
+    C: BlockCipher + BlockSizeUser<BlockSize = U16> + BlockEncrypt + BlockDecrypt + AlgorithmName,
+{
+    fn write_alg_name(f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("Xts<")?;
+        <C as AlgorithmName>::write_alg_name(f)?;
+        f.write_str(">")
+    }
+}
+
+impl<C> fmt::Debug for Xts<C>
+where
+    C: BlockCipher + BlockSizeUser<BlockSize = U16> + BlockEncrypt + BlockDecrypt + AlgorithmName,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("Xts<")?;
+        <C as AlgorithmName>::write_alg_name(f)?;
+        f.write_str("> { ... }")
+    }
+}
+
+/// Tweak for the given sector number (little-endian, as in IEEE 1619).
+pub fn sector_tweak(sector: u128) -> Tweak {
+    sector.to_le_bytes().into()
+}
+
+fn check_area(len: usize, sector_size: usize, first_sector: u128) -> Result<(), InvalidLength> {
+    if sector_size < BLOCK_SIZE || (len % sector_size != 0 && len % sector_size < BLOCK_SIZE) {
+        return Err(InvalidLength);
+    }
+
+    let sectors = (len + sector_size - 1) / sector_size;
+    match sectors.checked_sub(1) {
+        Some(last) => first_sector
+            .checked_add(last as u128)
+            .map(|_| ())
+            .ok_or(InvalidLength),
+        None => Ok(()),
+    }
+}
+
+fn full_blocks_len(len: usize) -> usize {
+    match len % BLOCK_SIZE {
+        0 => len,
+        rem => len - rem - BLOCK_SIZE,
+    }
+}
+
+fn into_blocks(buf: &mut [u8]) -> &mut [Block] {
+    let (blocks, _) = InOutBuf::from(buf).into_chunks::<U16>();
+    blocks.into_out()
+}
+
+#[inline(always)]
+fn next_tweak(tweak: &mut u128) -> Block {
+    let res = tweak.to_le_bytes().into();
+    let carry = *tweak >> 127;
+    *tweak = (*tweak << 1) ^ (carry * 0x87);
+    res
+}
+
+#[inline(always)]
+fn xor(block: &mut Block, tweak: &Block) {
+    for (a, b) in block.iter_mut().zip(tweak) {
+        *a ^= *b;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{sector_tweak, Tweak, Xts};
+    use aes::{Aes128, Aes256};
+    use cipher::{blobby::Blob4Iterator, KeyInit};
+    use sm4::Sm4;
+
+    macro_rules! xts_test {
+        ($name:ident, $test_name:expr, $cipher:ty) => {
+            #[test]
+            fn $name() {
+                let data = include_bytes!(concat!("data/", $test_name, ".blb"));
+                for row in Blob4Iterator::new(data).unwrap() {
+                    let [key, tweak, pt, ct] = row.unwrap();
+                    let xts = Xts::<$cipher>::new_from_slice(key).unwrap();
+                    let tweak = Tweak::from_slice(tweak);
+
+                    let mut buf = pt.to_vec();
+                    xts.encrypt_sector(&mut buf, tweak).unwrap();
+                    assert_eq!(buf, ct);
+                    xts.decrypt_sector(&mut buf, tweak).unwrap();
+                    assert_eq!(buf, pt);
+                }
+            }
+        };
+    }
+
+    xts_test!(aes128_ieee1619, "aes128", Aes128);
+    xts_test!(aes256_ieee1619, "aes256", Aes256);
+    xts_test!(sm4, "sm4", Sm4);
+
+    #[test]
+    fn area_matches_sectors() {
+        let xts = Xts::<Aes128>::new(&[0x42; 32].into());
+        let mut area = [0u8; 512 * 3 + 100];
+        for (i, b) in area.iter_mut().enumerate() {
+            *b = i as u8;
+        }
+        let pt = area;
+
+        xts.encrypt_area(&mut area, 512, 7).unwrap();
+        for (i, (sector, pt)) in area.chunks(512).zip(pt.chunks(512)).enumerate() {
+            let mut buf = pt.to_vec();
+            xts.encrypt_sector(&mut buf, &sector_tweak(7 + i as u128))
+                .unwrap();
+            assert_eq!(sector, &buf[..]);
+        }
+
+        xts.decrypt_area(&mut area, 512, 7).unwrap();
+        assert_eq!(area, pt);
+    }
+
+    #[test]
+    fn short_sectors() {
+        let xts = Xts::<Aes128>::new(&[0x42; 32].into());
+        let mut buf = [0u8; 15];
+        assert!(xts.encrypt_sector(&mut buf, &sector_tweak(0)).is_err());
+        assert!(xts.decrypt_sector(&mut buf, &sector_tweak(0)).is_err());
+
+        let mut area = [0u8; 512 + 8];
+        assert!(xts.encrypt_area(&mut area, 512, 0).is_err());
+        assert!(xts.encrypt_area(&mut area, 8, 0).is_err());
+        assert_eq!(area, [0u8; 512 + 8]);
+    }
+
+    #[test]
+    fn last_sectors() {
+        let xts = Xts::<Aes128>::new(&[0x42; 32].into());
+        let mut area = [0u8; 512 * 2];
+        xts.encrypt_area(&mut area, 512, u128::MAX - 1).unwrap();
+        xts.decrypt_area(&mut area, 512, u128::MAX - 1).unwrap();
+        assert_eq!(area, [0u8; 512 * 2]);
+
+        assert!(xts.encrypt_area(&mut area, 512, u128::MAX).is_err());
+        assert!(xts.decrypt_area(&mut area, 512, u128::MAX).is_err());
+        assert_eq!(area, [0u8; 512 * 2]);
+        xts.encrypt_area(&mut area[..512], 512, u128::MAX).unwrap();
+    }
+}
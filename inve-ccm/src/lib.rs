@@ -0,0 +1,440 @@
+mod private;
+
+pub use aead::{self, consts, AeadCore, AeadInPlace, Error, NewAead};
+
+use aead::{
+    consts::{U0, U16},
+    generic_array::{typenum::Unsigned, ArrayLength, GenericArray},
+};
+use cipher::{
+    Block, BlockCipher, BlockEncrypt, BlockSizeUser, InnerIvInit, Key, KeyInit, KeySizeUser,
+    StreamCipherCore,
+};
+use core::marker::PhantomData;
+use ctr::{flavors, CtrCore, CtrFlavor};
+use subtle::ConstantTimeEq;
+
+pub type Nonce<NonceSize> = GenericArray<u8, NonceSize>;
+
+pub type Tag<TagSize> = GenericArray<u8, TagSize>;
+
+/// Tag sizes allowed by CCM: 4, 6, 8, 10, 12, 14 and 16 bytes.
+pub trait TagSize: private::SealedTag {}
+
+impl<T: private::SealedTag> TagSize for T {}
+
+/// Nonce sizes allowed by CCM: 7 to 13 bytes.
+pub trait NonceSize: private::SealedNonce {}
+
+impl<T: private::SealedNonce> NonceSize for T {}
+
+/// CCM mode (RFC 3610, NIST SP 800-38C) over a 128-bit block cipher, with
+/// `M`-byte tags and `N`-byte nonces.
+#[derive(Clone)]
+pub struct Ccm<C, M, N>
+where
+    C: BlockCipher + BlockSizeUser<BlockSize = U16> + BlockEncrypt,
+    M: ArrayLength<u8> + TagSize,
+    N: ArrayLength<u8> + NonceSize,
+{
+    cipher: C,
+    _pd: PhantomData<(M, N)>,
+}
+
+impl<C, M, N> Ccm<C, M, N>
+where
+    C: BlockCipher + BlockSizeUser<BlockSize = U16> + BlockEncrypt,
+    M: ArrayLength<u8> + TagSize,
+    N: ArrayLength<u8> + NonceSize,
+{
+    fn calc_mac(&self, nonce: &Nonce<N>, adata: &[u8], buffer: &[u8]) -> Block<C> {
+        let is_ad = !adata.is_empty();
+        let l = N::get_l();
+        let flags = 64 * (is_ad as u8) + 8 * M::get_m_tick() + (l - 1);
+
+        let mut b0 = Block::<C>::default();
+        b0[0] = flags;
+        let n = 1 + N::USIZE;
+        b0[1..n].copy_from_slice(nonce);
+
+        // `buffer.len()` is checked against `N::get_max_len()` by the callers,
+        // so only zero bytes are discarded here
+        let b = (buffer.len() as u64).to_be_bytes();
+        let cb = b0.len() - n;
+        b0[n..].copy_from_slice(&b[b.len() - cb..]);
+
+        let mut mac = CbcMac::new(&self.cipher);
+        mac.block_update(&b0);
+
+        if is_ad {
+            let alen = adata.len();
+            let (n, mut b) = fill_aad_header(alen);
+            if b.len() - n >= alen {
+                b[n..][..alen].copy_from_slice(adata);
+                mac.block_update(&b);
+            } else {
+                let (l, r) = adata.split_at(b.len() - n);
+                b[n..].copy_from_slice(l);
+                mac.block_update(&b);
+                mac.update(r);
+            }
+        }
+
+        mac.update(buffer);
+        mac.finalize()
+    }
+
+    /// Applies the CTR keystream to `buffer` and returns the `S_0` block
+    /// used to mask the tag.
+    fn apply_ctr(&self, nonce: &Nonce<N>, buffer: &mut [u8]) -> Block<C> {
+        let mut ctr_block = Block::<C>::default();
+        ctr_block[0] = N::get_l() - 1;
+        ctr_block[1..][..N::USIZE].copy_from_slice(nonce);
+
+        // Counter field is at most 8 bytes long (for 7-byte nonces)
+        if C::BlockSize::USIZE - N::USIZE - 1 > 4 {
+            apply_keystream::<_, flavors::Ctr64BE>(&self.cipher, &ctr_block, buffer)
+        } else {
+            apply_keystream::<_, flavors::Ctr32BE>(&self.cipher, &ctr_block, buffer)
+        }
+    }
+}
+
+impl<C, M, N> From<C> for Ccm<C, M, N>
+where
+    C: BlockCipher + BlockSizeUser<BlockSize = U16> + BlockEncrypt,
+    M: ArrayLength<u8> + TagSize,
+    N: ArrayLength<u8> + NonceSize,
+{
+    fn from(cipher: C) -> Self {
+        Self {
+            cipher,
+            _pd: PhantomData,
+        }
+    }
+}
+
+impl<C, M, N> KeySizeUser for Ccm<C, M, N>
+where
+    C: BlockCipher + BlockSizeUser<BlockSize = U16> + BlockEncrypt + KeyInit,
+    M: ArrayLength<u8> + TagSize,
+    N: ArrayLength<u8> + NonceSize,
+{
+    type KeySize = C::KeySize;
+}
+
+impl<C, M, N> NewAead for Ccm<C, M, N>
+where
+    C: BlockCipher + BlockSizeUser<BlockSize = U16> + BlockEncrypt + KeyInit,
+    M: ArrayLength<u8> + TagSize,
+    N: ArrayLength<u8> + NonceSize,
+{
+    type KeySize = C::KeySize;
+
+    fn new(key: &Key<Self>) -> Self {
+        C::new(key).into()
+    }
+}
+
+impl<C, M, N> AeadCore for Ccm<C, M, N>
+where
+    C: BlockCipher + BlockSizeUser<BlockSize = U16> + BlockEncrypt,
+    M: ArrayLength<u8> + TagSize,
+    N: ArrayLength<u8> + NonceSize,
+{
+    type NonceSize = N;
+    type TagSize = M;
+    type CiphertextOverhead = U0;
+}
+
+impl<C, M, N> AeadInPlace for Ccm<C, M, N>
+where
+    C: BlockCipher + BlockSizeUser<BlockSize = U16> + BlockEncrypt,
+    M: ArrayLength<u8> + TagSize,
+    N: ArrayLength<u8> + NonceSize,
+{
+    fn encrypt_in_place_detached(
+        &self,
+        nonce: &Nonce<N>,
+        adata: &[u8],
+        buffer: &mut [u8],
+    ) -> Result<Tag<M>, Error> {
+        if buffer.len() > N::get_max_len() {
+            return Err(Error);
+        }
+
+        let mut full_tag = self.calc_mac(nonce, adata, buffer);
+        let mask = self.apply_ctr(nonce, buffer);
+        xor(&mut full_tag, &mask);
+
+        Ok(Tag::clone_from_slice(&full_tag[..M::USIZE]))
+    }
+
+    fn decrypt_in_place_detached(
+        &self,
+        nonce: &Nonce<N>,
+        adata: &[u8],
+        buffer: &mut [u8],
+        tag: &Tag<M>,
+    ) -> Result<(), Error> {
+        if buffer.len() > N::get_max_len() {
+            return Err(Error);
+        }
+
+        let mask = self.apply_ctr(nonce, buffer);
+        let mut full_tag = self.calc_mac(nonce, adata, buffer);
+        xor(&mut full_tag, &mask);
+
+        if full_tag[..M::USIZE].ct_eq(tag).unwrap_u8() == 1 {
+            Ok(())
+        } else {
+            buffer.iter_mut().for_each(|v| *v = 0);
+            Err(Error)
+        }
+    }
+}
+
+fn apply_keystream<C, F>(cipher: &C, ctr_block: &Block<C>, buffer: &mut [u8]) -> Block<C>
+where
+    C: BlockCipher + BlockSizeUser<BlockSize = U16> + BlockEncrypt,
+    F: CtrFlavor<U16>,
+{
+    let mut ctr = CtrCore::<&C, F>::inner_iv_init(cipher, ctr_block);
+    let mut mask = Block::<C>::default();
+    ctr.write_keystream_block(&mut mask);
+    ctr.apply_keystream_partial(buffer.into());
+    mask
+}
+
+fn xor(a: &mut [u8], b: &[u8]) {
+    for (a, b) in a.iter_mut().zip(b) {
+        *a ^= *b;
+    }
+}
+
+/// CBC-MAC with an all-zero IV, as used by CCM for the `B_0 || AAD || P`
+/// formatted input.
+struct CbcMac<'a, C: BlockCipher + BlockEncrypt> {
+    cipher: &'a C,
+    state: Block<C>,
+}
+
+impl<'a, C> CbcMac<'a, C>
+where
+    C: BlockCipher + BlockEncrypt,
+{
+    fn new(cipher: &'a C) -> Self {
+        Self {
+            cipher,
+            state: Default::default(),
+        }
+    }
+
+    fn update(&mut self, data: &[u8]) {
+        let mut chunks = data.chunks_exact(C::BlockSize::USIZE);
+        for chunk in &mut chunks {
+            self.block_update(Block::<C>::from_slice(chunk));
+        }
+        let rem = chunks.remainder();
+        if !rem.is_empty() {
+            let mut bn = Block::<C>::default();
+            bn[..rem.len()].copy_from_slice(rem);
+            self.block_update(&bn);
+        }
+    }
+
+    fn block_update(&mut self, block: &Block<C>) {
+        xor(&mut self.state, block);
+        self.cipher.encrypt_block(&mut self.state);
+    }
+
+    fn finalize(self) -> Block<C> {
+        self.state
+    }
+}
+
+/// Encodes the associated data length prefix (RFC 3610, Section 2.2).
+fn fill_aad_header(adata_len: usize) -> (usize, GenericArray<u8, U16>) {
+    debug_assert_ne!(adata_len, 0);
+
+    let mut b = GenericArray::<u8, U16>::default();
+    let n = if adata_len < 0xFF00 {
+        b[..2].copy_from_slice(&(adata_len as u16).to_be_bytes());
+        2
+    } else if adata_len <= u32::MAX as usize {
+        b[0] = 0xFF;
+        b[1] = 0xFE;
+        b[2..6].copy_from_slice(&(adata_len as u32).to_be_bytes());
+        6
+    } else {
+        b[0] = 0xFF;
+        b[1] = 0xFF;
+        b[2..10].copy_from_slice(&(adata_len as u64).to_be_bytes());
+        10
+    };
+    (n, b)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{consts::*, fill_aad_header, AeadInPlace, Ccm, NewAead};
+    use aead::{generic_array::GenericArray, Aead, Payload};
+    use aes::{Aes128, Aes192, Aes256};
+    use hex_literal::hex;
+
+    #[test]
+    fn aad_header() {
+        let (n, b) = fill_aad_header(0x0123);
+        assert_eq!(n, 2);
+        assert_eq!(b[..], hex!("01230000000000000000000000000000")[..]);
+
+        let (n, b) = fill_aad_header(0xFF00);
+        assert_eq!(n, 6);
+        assert_eq!(b[..], hex!("FFFE0000FF0000000000000000000000")[..]);
+
+        let (n, b) = fill_aad_header(0x01234567);
+        assert_eq!(n, 6);
+        assert_eq!(b[..], hex!("FFFE0123456700000000000000000000")[..]);
+
+        #[cfg(target_pointer_width = "64")]
+        {
+            let (n, b) = fill_aad_header(0x0123456789ABCDEF);
+            assert_eq!(n, 10);
+            assert_eq!(b[..], hex!("FFFF0123456789ABCDEF000000000000")[..]);
+        }
+    }
+
+    #[test]
+    fn data_len_check() {
+        let key = GenericArray::from_slice(&hex!("D7828D13B2B0BDC325A76236DF93CC6B"));
+        let nonce = GenericArray::from_slice(&hex!("2F1DBD38CE3EDA7C23F04DD650"));
+        let cipher = Ccm::<Aes128, U10, U13>::new(key);
+
+        let mut buf = [1; u16::MAX as usize];
+        assert!(cipher
+            .encrypt_in_place_detached(nonce, &[], &mut buf)
+            .is_ok());
+
+        let mut buf = [1; u16::MAX as usize + 1];
+        assert!(cipher
+            .encrypt_in_place_detached(nonce, &[], &mut buf)
+            .is_err());
+    }
+
+    #[test]
+    fn sp800_38c_examples() {
+        macro_rules! check {
+            ($m:ty, $n:ty, $nonce:expr, $adata:expr, $pt:expr, $ct:expr) => {
+                let key = hex!("404142434445464748494a4b4c4d4e4f");
+                let cipher = Ccm::<Aes128, $m, $n>::new(GenericArray::from_slice(&key));
+                let nonce = GenericArray::from_slice(&$nonce);
+                let ct = cipher
+                    .encrypt(
+                        nonce,
+                        Payload {
+                            aad: &$adata,
+                            msg: &$pt,
+                        },
+                    )
+                    .unwrap();
+                assert_eq!(ct, $ct.as_ref());
+                let pt = cipher
+                    .decrypt(
+                        nonce,
+                        Payload {
+                            aad: &$adata,
+                            msg: &$ct,
+                        },
+                    )
+                    .unwrap();
+                assert_eq!(pt, $pt.as_ref());
+            };
+        }
+
+        check!(
+            U4,
+            U7,
+            hex!("10111213141516"),
+            hex!("0001020304050607"),
+            hex!("20212223"),
+            hex!("7162015b4dac255d")
+        );
+        check!(
+            U6,
+            U8,
+            hex!("1011121314151617"),
+            hex!("000102030405060708090a0b0c0d0e0f"),
+            hex!("202122232425262728292a2b2c2d2e2f"),
+            hex!("d2a1f0e051ea5f62081a7792073d593d1fc64fbfaccd")
+        );
+        check!(
+            U8,
+            U12,
+            hex!("101112131415161718191a1b"),
+            hex!("000102030405060708090a0b0c0d0e0f10111213"),
+            hex!("202122232425262728292a2b2c2d2e2f3031323334353637"),
+            hex!("e3b201a9f5b71a7a9b1ceaeccd97e70b6176aad9a4428aa5484392fbc1b09951")
+        );
+
+        let adata: Vec<u8> = (0..524288 / 8).map(|i| i as u8).collect();
+        check!(
+            U14,
+            U13,
+            hex!("101112131415161718191a1b1c"),
+            adata,
+            hex!("202122232425262728292a2b2c2d2e2f303132333435363738393a3b3c3d3e3f"),
+            hex!(
+                "69915dad1e84c6376a68c2967e4dab615ae0fd1faec44cc484828529463ccf72"
+                "b4ac6bec93e8598e7f0dadbcea5b"
+            )
+        );
+    }
+
+    aead::new_test!(rfc3610_aes128_8_13, "rfc3610_ccm_aes128_8_13", Ccm<Aes128, U8, U13>);
+    aead::new_test!(rfc3610_aes128_10_13, "rfc3610_ccm_aes128_10_13", Ccm<Aes128, U10, U13>);
+
+    aead::new_test!(cavp_aes128_4_7, "cavp_ccm_aes128_4_7", Ccm<Aes128, U4, U7>);
+    aead::new_test!(cavp_aes128_4_13, "cavp_ccm_aes128_4_13", Ccm<Aes128, U4, U13>);
+    aead::new_test!(cavp_aes128_6_13, "cavp_ccm_aes128_6_13", Ccm<Aes128, U6, U13>);
+    aead::new_test!(cavp_aes128_8_13, "cavp_ccm_aes128_8_13", Ccm<Aes128, U8, U13>);
+    aead::new_test!(cavp_aes128_10_13, "cavp_ccm_aes128_10_13", Ccm<Aes128, U10, U13>);
+    aead::new_test!(cavp_aes128_12_13, "cavp_ccm_aes128_12_13", Ccm<Aes128, U12, U13>);
+    aead::new_test!(cavp_aes128_14_13, "cavp_ccm_aes128_14_13", Ccm<Aes128, U14, U13>);
+    aead::new_test!(cavp_aes128_16_7, "cavp_ccm_aes128_16_7", Ccm<Aes128, U16, U7>);
+    aead::new_test!(cavp_aes128_16_8, "cavp_ccm_aes128_16_8", Ccm<Aes128, U16, U8>);
+    aead::new_test!(cavp_aes128_16_9, "cavp_ccm_aes128_16_9", Ccm<Aes128, U16, U9>);
+    aead::new_test!(cavp_aes128_16_10, "cavp_ccm_aes128_16_10", Ccm<Aes128, U16, U10>);
+    aead::new_test!(cavp_aes128_16_11, "cavp_ccm_aes128_16_11", Ccm<Aes128, U16, U11>);
+    aead::new_test!(cavp_aes128_16_12, "cavp_ccm_aes128_16_12", Ccm<Aes128, U16, U12>);
+    aead::new_test!(cavp_aes128_16_13, "cavp_ccm_aes128_16_13", Ccm<Aes128, U16, U13>);
+
+    aead::new_test!(cavp_aes192_4_7, "cavp_ccm_aes192_4_7", Ccm<Aes192, U4, U7>);
+    aead::new_test!(cavp_aes192_4_13, "cavp_ccm_aes192_4_13", Ccm<Aes192, U4, U13>);
+    aead::new_test!(cavp_aes192_6_13, "cavp_ccm_aes192_6_13", Ccm<Aes192, U6, U13>);
+    aead::new_test!(cavp_aes192_8_13, "cavp_ccm_aes192_8_13", Ccm<Aes192, U8, U13>);
+    aead::new_test!(cavp_aes192_10_13, "cavp_ccm_aes192_10_13", Ccm<Aes192, U10, U13>);
+    aead::new_test!(cavp_aes192_12_13, "cavp_ccm_aes192_12_13", Ccm<Aes192, U12, U13>);
+    aead::new_test!(cavp_aes192_14_13, "cavp_ccm_aes192_14_13", Ccm<Aes192, U14, U13>);
+    aead::new_test!(cavp_aes192_16_7, "cavp_ccm_aes192_16_7", Ccm<Aes192, U16, U7>);
+    aead::new_test!(cavp_aes192_16_8, "cavp_ccm_aes192_16_8", Ccm<Aes192, U16, U8>);
+    aead::new_test!(cavp_aes192_16_9, "cavp_ccm_aes192_16_9", Ccm<Aes192, U16, U9>);
+    aead::new_test!(cavp_aes192_16_10, "cavp_ccm_aes192_16_10", Ccm<Aes192, U16, U10>);
+    aead::new_test!(cavp_aes192_16_11, "cavp_ccm_aes192_16_11", Ccm<Aes192, U16, U11>);
+    aead::new_test!(cavp_aes192_16_12, "cavp_ccm_aes192_16_12", Ccm<Aes192, U16, U12>);
+    aead::new_test!(cavp_aes192_16_13, "cavp_ccm_aes192_16_13", Ccm<Aes192, U16, U13>);
+
+    aead::new_test!(cavp_aes256_4_7, "cavp_ccm_aes256_4_7", Ccm<Aes256, U4, U7>);
+    aead::new_test!(cavp_aes256_4_13, "cavp_ccm_aes256_4_13", Ccm<Aes256, U4, U13>);
+    aead::new_test!(cavp_aes256_6_13, "cavp_ccm_aes256_6_13", Ccm<Aes256, U6, U13>);
+    aead::new_test!(cavp_aes256_8_13, "cavp_ccm_aes256_8_13", Ccm<Aes256, U8, U13>);
+    aead::new_test!(cavp_aes256_10_13, "cavp_ccm_aes256_10_13", Ccm<Aes256, U10, U13>);
+    aead::new_test!(cavp_aes256_12_13, "cavp_ccm_aes256_12_13", Ccm<Aes256, U12, U13>);
+    aead::new_test!(cavp_aes256_14_13, "cavp_ccm_aes256_14_13", Ccm<Aes256, U14, U13>);
+    aead::new_test!(cavp_aes256_16_7, "cavp_ccm_aes256_16_7", Ccm<Aes256, U16, U7>);
+    aead::new_test!(cavp_aes256_16_8, "cavp_ccm_aes256_16_8", Ccm<Aes256, U16, U8>);
+    aead::new_test!(cavp_aes256_16_9, "cavp_ccm_aes256_16_9", Ccm<Aes256, U16, U9>);
+    aead::new_test!(cavp_aes256_16_10, "cavp_ccm_aes256_16_10", Ccm<Aes256, U16, U10>);
+    aead::new_test!(cavp_aes256_16_11, "cavp_ccm_aes256_16_11", Ccm<Aes256, U16, U11>);
+    aead::new_test!(cavp_aes256_16_12, "cavp_ccm_aes256_16_12", Ccm<Aes256, U16, U12>);
+    aead::new_test!(cavp_aes256_16_13, "cavp_ccm_aes256_16_13", Ccm<Aes256, U16, U13>);
+}
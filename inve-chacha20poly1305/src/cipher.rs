@@ -77,6 +77,21 @@ where
         }
     }
 
+    pub(crate) fn compute_tag(
+        mut self,
+        associated_data: &[u8],
+        buffer: &[u8],
+    ) -> Result<Tag, Error> {
+        if buffer.len() / BLOCK_SIZE >= MAX_BLOCKS {
+            return Err(Error);
+        }
+
+        self.mac.update_padded(associated_data);
+        self.mac.update_padded(buffer);
+        self.authenticate_lengths(associated_data, buffer)?;
+        Ok(self.mac.finalize().into_bytes())
+    }
+
     fn authenticate_lengths(&mut self, associated_data: &[u8], buffer: &[u8]) -> Result<(), Error> {
         let associated_data_len: u64 = associated_data.len().try_into().map_err(|_| Error)?;
         let buffer_len: u64 = buffer.len().try_into().map_err(|_| Error)?;
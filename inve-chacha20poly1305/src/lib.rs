@@ -7,7 +7,7 @@ use ::cipher::{KeyIvInit, StreamCipher, StreamCipherSeek};
 use aead::{
     consts::{U0, U12, U16, U24, U32},
     generic_array::{ArrayLength, GenericArray},
-    AeadCore, AeadInPlace, Error, NewAead,
+    AeadComputeTag, AeadCore, AeadInPlace, Error, NewAead,
 };
 use core::marker::PhantomData;
 use zeroize::Zeroize;
@@ -107,6 +107,21 @@ where
     }
 }
 
+impl<C, N> AeadComputeTag for ChaChaPoly1305<C, N>
+where
+    C: KeyIvInit<KeySize = U32, IvSize = N> + StreamCipher + StreamCipherSeek,
+    N: ArrayLength<u8>,
+{
+    fn compute_tag(
+        &self,
+        nonce: &aead::Nonce<Self>,
+        associated_data: &[u8],
+        ciphertext: &[u8],
+    ) -> Result<Tag, Error> {
+        Cipher::new(C::new(&self.key, nonce)).compute_tag(associated_data, ciphertext)
+    }
+}
+
 impl<C, N> Clone for ChaChaPoly1305<C, N>
 where
     C: KeyIvInit<KeySize = U32, IvSize = N> + StreamCipher + StreamCipherSeek,
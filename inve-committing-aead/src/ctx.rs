@@ -0,0 +1,136 @@
+use aead::{
+    consts::U0, generic_array::typenum::Unsigned, AeadComputeTag, AeadCore, AeadInPlace, Buffer,
+    Error, Key, NewAead, Nonce, Tag,
+};
+use core::marker::PhantomData;
+use digest::{Digest, Output, OutputSizeUser};
+use subtle::ConstantTimeEq;
+use zeroize::Zeroize;
+
+/// The CTX construction of Chan and Rogaway: the tag of the underlying AEAD
+/// is replaced by a hash of the key, nonce, associated data and tag, which
+/// commits to all of them.
+///
+/// `D` must be collision resistant. The committing tag takes the place of
+/// the original one at the end of the ciphertext, so only the [`Buffer`]
+/// based methods are supported and the detached ones always fail.
+pub struct Ctx<A: NewAead, D> {
+    inner: A,
+    key: Key<A>,
+    digest: PhantomData<D>,
+}
+
+impl<A, D> Ctx<A, D>
+where
+    A: NewAead + AeadComputeTag,
+    D: Digest,
+{
+    fn committing_tag(&self, nonce: &Nonce<A>, associated_data: &[u8], tag: &Tag<A>) -> Output<D> {
+        D::new()
+            .chain_update(&self.key)
+            .chain_update(nonce)
+            .chain_update((associated_data.len() as u64).to_le_bytes())
+            .chain_update(associated_data)
+            .chain_update(tag)
+            .finalize()
+    }
+}
+
+impl<A: NewAead, D> NewAead for Ctx<A, D> {
+    type KeySize = A::KeySize;
+
+    fn new(key: &Key<Self>) -> Self {
+        Self {
+            inner: A::new(key),
+            key: key.clone(),
+            digest: PhantomData,
+        }
+    }
+}
+
+impl<A, D> AeadCore for Ctx<A, D>
+where
+    A: NewAead + AeadCore,
+    D: OutputSizeUser,
+{
+    type NonceSize = A::NonceSize;
+    type TagSize = U0;
+    type CiphertextOverhead = D::OutputSize;
+}
+
+impl<A, D> AeadInPlace for Ctx<A, D>
+where
+    A: NewAead + AeadInPlace + AeadComputeTag,
+    D: Digest,
+{
+    fn encrypt_in_place(
+        &self,
+        nonce: &Nonce<Self>,
+        associated_data: &[u8],
+        buffer: &mut dyn Buffer,
+    ) -> Result<(), Error> {
+        let tag = self
+            .inner
+            .encrypt_in_place_detached(nonce, associated_data, buffer.as_mut())?;
+        buffer.extend_from_slice(&self.committing_tag(nonce, associated_data, &tag))
+    }
+
+    fn encrypt_in_place_detached(
+        &self,
+        _nonce: &Nonce<Self>,
+        _associated_data: &[u8],
+        _buffer: &mut [u8],
+    ) -> Result<Tag<Self>, Error> {
+        Err(Error)
+    }
+
+    fn decrypt_in_place(
+        &self,
+        nonce: &Nonce<Self>,
+        associated_data: &[u8],
+        buffer: &mut dyn Buffer,
+    ) -> Result<(), Error> {
+        let tag_pos = buffer
+            .len()
+            .checked_sub(D::OutputSize::USIZE)
+            .ok_or(Error)?;
+        let (msg, committing_tag) = buffer.as_mut().split_at_mut(tag_pos);
+
+        let tag = self.inner.compute_tag(nonce, associated_data, msg)?;
+        let expected = self.committing_tag(nonce, associated_data, &tag);
+        if expected.ct_eq(committing_tag).unwrap_u8() == 0 {
+            return Err(Error);
+        }
+
+        self.inner
+            .decrypt_in_place_detached(nonce, associated_data, msg, &tag)?;
+        buffer.truncate(tag_pos);
+        Ok(())
+    }
+
+    fn decrypt_in_place_detached(
+        &self,
+        _nonce: &Nonce<Self>,
+        _associated_data: &[u8],
+        _buffer: &mut [u8],
+        _tag: &Tag<Self>,
+    ) -> Result<(), Error> {
+        Err(Error)
+    }
+}
+
+impl<A: NewAead + Clone, D> Clone for Ctx<A, D> {
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+            key: self.key.clone(),
+            digest: PhantomData,
+        }
+    }
+}
+
+impl<A: NewAead, D> Drop for Ctx<A, D> {
+    fn drop(&mut self) {
+        self.key.as_mut_slice().zeroize();
+    }
+}
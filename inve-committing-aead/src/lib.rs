@@ -0,0 +1,145 @@
+mod ctx;
+mod padding;
+mod utc;
+
+pub use aead::{self, AeadComputeTag, AeadCore, AeadInPlace, Error, NewAead};
+pub use ctx::Ctx;
+pub use digest;
+pub use padding::{PaddingFix, PADDING_LEN};
+pub use utc::Utc;
+
+#[cfg(all(test, feature = "alloc"))]
+mod tests {
+    use super::*;
+    use aead::{generic_array::typenum::Unsigned, Aead, Key, Nonce, Payload};
+    use aes_gcm::Aes256Gcm;
+    use chacha20poly1305::ChaCha20Poly1305;
+    use digest::Digest;
+    use hex_literal::hex;
+    use sha2::Sha256;
+
+    const KEY: [u8; 32] = hex!("000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f");
+    const NONCE: [u8; 12] = hex!("a0a1a2a3a4a5a6a7a8a9aaab");
+    const AAD: &[u8] = b"associated data";
+    const MSG: &[u8] = b"a message spanning more than a single block";
+
+    fn payload(msg: &[u8]) -> Payload<'_, '_> {
+        Payload { msg, aad: AAD }
+    }
+
+    macro_rules! committing_test {
+        ($name:ident, $aead:ty) => {
+            mod $name {
+                use super::*;
+
+                fn check<C: NewAead + AeadInPlace>() {
+                    let cipher = C::new(Key::<C>::from_slice(&KEY));
+                    let nonce = Nonce::<C>::from_slice(&NONCE);
+                    let overhead = C::TagSize::USIZE + C::CiphertextOverhead::USIZE;
+
+                    for len in [0, 1, 16, MSG.len()] {
+                        let ct = cipher.encrypt(nonce, payload(&MSG[..len])).unwrap();
+                        assert_eq!(ct.len(), len + overhead);
+                        assert_eq!(cipher.decrypt(nonce, payload(&ct)).unwrap(), &MSG[..len]);
+
+                        for i in 0..ct.len() {
+                            let mut bad = ct.clone();
+                            bad[i] ^= 1;
+                            assert!(cipher.decrypt(nonce, payload(&bad)).is_err());
+                        }
+                        let bad_aad = Payload { msg: &ct, aad: b"" };
+                        assert!(cipher.decrypt(nonce, bad_aad).is_err());
+                        assert!(cipher.decrypt(nonce, &ct[..overhead - 1]).is_err());
+                    }
+
+                    // A rejected ciphertext is left as it was
+                    let mut buffer = cipher.encrypt(nonce, payload(MSG)).unwrap();
+                    buffer[1] ^= 1;
+                    let tampered = buffer.clone();
+                    assert!(cipher.decrypt_in_place(nonce, AAD, &mut buffer).is_err());
+                    assert_eq!(buffer, tampered);
+
+                    let other = C::new(&Key::<C>::default());
+                    let ct = cipher.encrypt(nonce, payload(MSG)).unwrap();
+                    assert!(other.decrypt(nonce, payload(&ct)).is_err());
+
+                    let mut buffer = MSG.to_vec();
+                    assert!(cipher
+                        .encrypt_in_place_detached(nonce, AAD, &mut buffer)
+                        .is_err());
+                }
+
+                #[test]
+                fn padding_fix() {
+                    check::<PaddingFix<$aead>>();
+
+                    let inner = <$aead>::new(Key::<$aead>::from_slice(&KEY));
+                    let cipher = PaddingFix::from(inner.clone());
+                    let nonce = Nonce::<$aead>::from_slice(&NONCE);
+
+                    let mut padded = [0; PADDING_LEN].to_vec();
+                    padded.extend_from_slice(MSG);
+                    let ct = cipher.encrypt(nonce, payload(MSG)).unwrap();
+                    assert_eq!(ct, inner.encrypt(nonce, payload(&padded)).unwrap());
+
+                    // Authentic under the underlying AEAD but without the padding
+                    padded[PADDING_LEN - 1] = 1;
+                    let ct = inner.encrypt(nonce, payload(&padded)).unwrap();
+                    assert!(cipher.decrypt(nonce, payload(&ct)).is_err());
+                }
+
+                #[test]
+                fn utc() {
+                    check::<Utc<$aead, Sha256>>();
+
+                    let cipher = Utc::<$aead, Sha256>::new(Key::<$aead>::from_slice(&KEY));
+                    let nonce = Nonce::<$aead>::from_slice(&NONCE);
+
+                    let subkey = Sha256::new()
+                        .chain_update([1, 0])
+                        .chain_update(KEY)
+                        .chain_update(NONCE)
+                        .finalize();
+                    let mut expected = <$aead>::new(&subkey).encrypt(nonce, payload(MSG)).unwrap();
+                    expected.extend_from_slice(
+                        &Sha256::new()
+                            .chain_update([0])
+                            .chain_update(KEY)
+                            .chain_update(NONCE)
+                            .finalize(),
+                    );
+                    assert_eq!(cipher.encrypt(nonce, payload(MSG)).unwrap(), expected);
+                }
+
+                #[test]
+                fn ctx() {
+                    check::<Ctx<$aead, Sha256>>();
+
+                    let inner = <$aead>::new(Key::<$aead>::from_slice(&KEY));
+                    let cipher = Ctx::<$aead, Sha256>::new(Key::<$aead>::from_slice(&KEY));
+                    let nonce = Nonce::<$aead>::from_slice(&NONCE);
+
+                    let mut expected = MSG.to_vec();
+                    let tag = inner
+                        .encrypt_in_place_detached(nonce, AAD, &mut expected)
+                        .unwrap();
+                    assert_eq!(inner.compute_tag(nonce, AAD, &expected).unwrap(), tag);
+
+                    expected.extend_from_slice(
+                        &Sha256::new()
+                            .chain_update(KEY)
+                            .chain_update(NONCE)
+                            .chain_update((AAD.len() as u64).to_le_bytes())
+                            .chain_update(AAD)
+                            .chain_update(tag)
+                            .finalize(),
+                    );
+                    assert_eq!(cipher.encrypt(nonce, payload(MSG)).unwrap(), expected);
+                }
+            }
+        };
+    }
+
+    committing_test!(aes256_gcm, Aes256Gcm);
+    committing_test!(chacha20_poly1305, ChaCha20Poly1305);
+}
@@ -0,0 +1,101 @@
+use aead::{
+    consts::U32, generic_array::typenum::Unsigned, AeadCore, AeadInPlace, Buffer, Error, Key,
+    NewAead, Nonce, Tag,
+};
+use subtle::ConstantTimeEq;
+
+type PaddingLen = U32;
+
+/// Number of zero bytes prepended to the plaintext by [`PaddingFix`].
+pub const PADDING_LEN: usize = PaddingLen::USIZE;
+
+/// The padding fix of Albertini et al.: the plaintext is prefixed with
+/// [`PADDING_LEN`] zero bytes which are checked after decryption, so that a
+/// ciphertext cannot be opened under two different keys.
+///
+/// The padding is carried in the ciphertext, so only the [`Buffer`] based
+/// methods are supported and the detached ones always fail.
+#[derive(Clone)]
+pub struct PaddingFix<A> {
+    inner: A,
+}
+
+impl<A> From<A> for PaddingFix<A> {
+    fn from(inner: A) -> Self {
+        Self { inner }
+    }
+}
+
+impl<A: NewAead> NewAead for PaddingFix<A> {
+    type KeySize = A::KeySize;
+
+    fn new(key: &Key<Self>) -> Self {
+        A::new(key).into()
+    }
+}
+
+impl<A: AeadCore> AeadCore for PaddingFix<A> {
+    type NonceSize = A::NonceSize;
+    type TagSize = A::TagSize;
+    type CiphertextOverhead = PaddingLen;
+}
+
+impl<A: AeadInPlace> AeadInPlace for PaddingFix<A> {
+    fn encrypt_in_place(
+        &self,
+        nonce: &Nonce<Self>,
+        associated_data: &[u8],
+        buffer: &mut dyn Buffer,
+    ) -> Result<(), Error> {
+        let msg_len = buffer.len();
+        buffer.extend_from_slice(&[0; PADDING_LEN])?;
+
+        let padded = buffer.as_mut();
+        padded.copy_within(..msg_len, PADDING_LEN);
+        padded[..PADDING_LEN].fill(0);
+
+        self.inner.encrypt_in_place(nonce, associated_data, buffer)
+    }
+
+    fn encrypt_in_place_detached(
+        &self,
+        _nonce: &Nonce<Self>,
+        _associated_data: &[u8],
+        _buffer: &mut [u8],
+    ) -> Result<Tag<Self>, Error> {
+        Err(Error)
+    }
+
+    fn decrypt_in_place(
+        &self,
+        nonce: &Nonce<Self>,
+        associated_data: &[u8],
+        buffer: &mut dyn Buffer,
+    ) -> Result<(), Error> {
+        self.inner
+            .decrypt_in_place(nonce, associated_data, buffer)?;
+
+        let padded = buffer.as_mut();
+        if padded.len() < PADDING_LEN
+            || padded[..PADDING_LEN].ct_eq(&[0; PADDING_LEN]).unwrap_u8() == 0
+        {
+            padded.fill(0);
+            return Err(Error);
+        }
+
+        let msg_len = padded.len() - PADDING_LEN;
+        padded.copy_within(PADDING_LEN.., 0);
+        buffer.truncate(msg_len);
+        Ok(())
+    }
+
+    fn decrypt_in_place_detached(
+        &self,
+        _nonce: &Nonce<Self>,
+        _associated_data: &[u8],
+        _buffer: &mut [u8],
+        _tag: &Tag<Self>,
+    ) -> Result<(), Error> {
+        Err(Error)
+    }
+}
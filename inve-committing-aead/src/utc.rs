@@ -0,0 +1,155 @@
+use aead::{
+    generic_array::typenum::Unsigned, AeadCore, AeadInPlace, Buffer, Error, Key, NewAead, Nonce,
+    Tag,
+};
+use core::marker::PhantomData;
+use digest::{Digest, Output, OutputSizeUser};
+use subtle::ConstantTimeEq;
+use zeroize::Zeroize;
+
+const COMMITMENT_PREFIX: u8 = 0;
+
+const SUBKEY_PREFIX: u8 = 1;
+
+/// The UtC transform of Bellare and Hoang: every message is encrypted under a
+/// subkey derived from the key and nonce, and a commitment to both is
+/// appended to the ciphertext.
+///
+/// The commitment and the subkey are both computed with the hash `D`, which
+/// must be collision resistant.
+///
+/// The commitment is carried in the ciphertext, so only the [`Buffer`] based
+/// methods are supported and the detached ones always fail.
+pub struct Utc<A: NewAead, D> {
+    key: Key<A>,
+    digest: PhantomData<D>,
+}
+
+impl<A, D> Utc<A, D>
+where
+    A: NewAead + AeadInPlace,
+    D: Digest,
+{
+    fn commitment(&self, nonce: &Nonce<A>) -> Output<D> {
+        D::new()
+            .chain_update([COMMITMENT_PREFIX])
+            .chain_update(&self.key)
+            .chain_update(nonce)
+            .finalize()
+    }
+
+    fn subkey_cipher(&self, nonce: &Nonce<A>) -> A {
+        let mut subkey = Key::<A>::default();
+        for (i, chunk) in subkey.chunks_mut(D::OutputSize::USIZE).enumerate() {
+            let mut block = D::new()
+                .chain_update([SUBKEY_PREFIX, i as u8])
+                .chain_update(&self.key)
+                .chain_update(nonce)
+                .finalize();
+            chunk.copy_from_slice(&block[..chunk.len()]);
+            block.as_mut_slice().zeroize();
+        }
+
+        let cipher = A::new(&subkey);
+        subkey.as_mut_slice().zeroize();
+        cipher
+    }
+}
+
+impl<A: NewAead, D> NewAead for Utc<A, D> {
+    type KeySize = A::KeySize;
+
+    fn new(key: &Key<Self>) -> Self {
+        Self {
+            key: key.clone(),
+            digest: PhantomData,
+        }
+    }
+}
+
+impl<A, D> AeadCore for Utc<A, D>
+where
+    A: NewAead + AeadCore,
+    D: OutputSizeUser,
+{
+    type NonceSize = A::NonceSize;
+    type TagSize = A::TagSize;
+    type CiphertextOverhead = D::OutputSize;
+}
+
+impl<A, D> AeadInPlace for Utc<A, D>
+where
+    A: NewAead + AeadInPlace,
+    D: Digest,
+{
+    fn encrypt_in_place(
+        &self,
+        nonce: &Nonce<Self>,
+        associated_data: &[u8],
+        buffer: &mut dyn Buffer,
+    ) -> Result<(), Error> {
+        self.subkey_cipher(nonce)
+            .encrypt_in_place(nonce, associated_data, buffer)?;
+        buffer.extend_from_slice(&self.commitment(nonce))
+    }
+
+    fn encrypt_in_place_detached(
+        &self,
+        _nonce: &Nonce<Self>,
+        _associated_data: &[u8],
+        _buffer: &mut [u8],
+    ) -> Result<Tag<Self>, Error> {
+        Err(Error)
+    }
+
+    fn decrypt_in_place(
+        &self,
+        nonce: &Nonce<Self>,
+        associated_data: &[u8],
+        buffer: &mut dyn Buffer,
+    ) -> Result<(), Error> {
+        let commitment_pos = buffer
+            .len()
+            .checked_sub(D::OutputSize::USIZE)
+            .ok_or(Error)?;
+        let commitment = &buffer.as_ref()[commitment_pos..];
+        if self.commitment(nonce).ct_eq(commitment).unwrap_u8() == 0 {
+            return Err(Error);
+        }
+
+        let tag_pos = commitment_pos.checked_sub(A::TagSize::USIZE).ok_or(Error)?;
+        let (msg, tag) = buffer.as_mut()[..commitment_pos].split_at_mut(tag_pos);
+        let tag = Tag::<A>::clone_from_slice(tag);
+        self.subkey_cipher(nonce)
+            .decrypt_in_place_detached(nonce, associated_data, msg, &tag)?;
+
+        // Only shorten the buffer once the message is authentic
+        buffer.truncate(tag_pos);
+        Ok(())
+    }
+
+    fn decrypt_in_place_detached(
+        &self,
+        _nonce: &Nonce<Self>,
+        _associated_data: &[u8],
+        _buffer: &mut [u8],
+        _tag: &Tag<Self>,
+    ) -> Result<(), Error> {
+        Err(Error)
+    }
+}
+
+impl<A: NewAead, D> Clone for Utc<A, D> {
+    fn clone(&self) -> Self {
+        Self {
+            key: self.key.clone(),
+            digest: PhantomData,
+        }
+    }
+}
+
+impl<A: NewAead, D> Drop for Utc<A, D> {
+    fn drop(&mut self) {
+        self.key.as_mut_slice().zeroize();
+    }
+}
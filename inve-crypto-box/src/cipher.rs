@@ -0,0 +1,65 @@
+use aead::{generic_array::GenericArray, Error};
+use poly1305::{universal_hash::NewUniversalHash, Poly1305};
+use salsa20::cipher::{StreamCipher, StreamCipherSeek};
+use zeroize::Zeroize;
+
+use crate::Tag;
+
+/// The NaCl `crypto_secretbox` construction: the first 32 bytes of the
+/// keystream are the Poly1305 key and the message is encrypted with the
+/// bytes which follow them.
+pub(crate) struct Cipher<C>
+where
+    C: StreamCipher + StreamCipherSeek,
+{
+    cipher: C,
+    mac: Poly1305,
+}
+
+impl<C> Cipher<C>
+where
+    C: StreamCipher + StreamCipherSeek,
+{
+    pub(crate) fn new(mut cipher: C) -> Self {
+        let mut mac_key = poly1305::Key::default();
+        cipher.apply_keystream(&mut mac_key);
+        let mac = Poly1305::new(GenericArray::from_slice(&mac_key));
+        mac_key.zeroize();
+
+        Self { cipher, mac }
+    }
+
+    pub(crate) fn encrypt_in_place_detached(
+        mut self,
+        associated_data: &[u8],
+        buffer: &mut [u8],
+    ) -> Result<Tag, Error> {
+        if !associated_data.is_empty() {
+            return Err(Error);
+        }
+
+        self.cipher.apply_keystream(buffer);
+        Ok(self.mac.compute_unpadded(buffer).into_bytes())
+    }
+
+    pub(crate) fn decrypt_in_place_detached(
+        mut self,
+        associated_data: &[u8],
+        buffer: &mut [u8],
+        tag: &Tag,
+    ) -> Result<(), Error> {
+        if !associated_data.is_empty() {
+            return Err(Error);
+        }
+
+        use subtle::ConstantTimeEq;
+        let expected_tag = self.mac.compute_unpadded(buffer).into_bytes();
+
+        if expected_tag.ct_eq(tag).unwrap_u8() == 1 {
+            self.cipher.apply_keystream(buffer);
+            Ok(())
+        } else {
+            Err(Error)
+        }
+    }
+}
@@ -0,0 +1,386 @@
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+mod cipher;
+
+#[cfg(feature = "alloc")]
+mod seal;
+
+pub use aead::{self, AeadCore, AeadInPlace, Error, NewAead};
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub use seal::SEAL_OVERHEAD;
+
+use self::cipher::Cipher;
+use aead::{
+    consts::{U0, U10, U16, U24, U32},
+    generic_array::GenericArray,
+    Buffer,
+};
+use chacha20::{hchacha, XChaCha20};
+use core::{fmt, marker::PhantomData};
+use salsa20::{
+    cipher::{KeyIvInit, StreamCipher, StreamCipherSeek},
+    hsalsa, XSalsa20,
+};
+use x25519::StaticSecret;
+use zeroize::Zeroize;
+
+#[cfg(feature = "rand_core")]
+use rand_core::{CryptoRng, RngCore};
+
+pub const KEY_SIZE: usize = 32;
+
+pub const NONCE_SIZE: usize = 24;
+
+pub const TAG_SIZE: usize = 16;
+
+pub type Key = GenericArray<u8, U32>;
+
+pub type Nonce = GenericArray<u8, U24>;
+
+pub type Tag = GenericArray<u8, U16>;
+
+/// `crypto_box_curve25519xsalsa20poly1305`, the default NaCl box.
+pub type SalsaBox = CryptoBox<XSalsa20>;
+
+/// `crypto_box_curve25519xchacha20poly1305` as provided by libsodium.
+pub type ChaChaBox = CryptoBox<XChaCha20>;
+
+/// X25519 public key.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct PublicKey([u8; KEY_SIZE]);
+
+impl PublicKey {
+    pub fn from_slice(slice: &[u8]) -> Result<Self, Error> {
+        slice.try_into().map(Self).map_err(|_| Error)
+    }
+
+    pub fn as_bytes(&self) -> &[u8; KEY_SIZE] {
+        &self.0
+    }
+}
+
+impl From<[u8; KEY_SIZE]> for PublicKey {
+    fn from(bytes: [u8; KEY_SIZE]) -> Self {
+        Self(bytes)
+    }
+}
+
+impl AsRef<[u8]> for PublicKey {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+/// X25519 secret key, stored unclamped as produced by `crypto_box_keypair`.
+#[derive(Clone)]
+pub struct SecretKey(StaticSecret);
+
+impl SecretKey {
+    #[cfg(feature = "rand_core")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "rand_core")))]
+    pub fn generate<T>(csprng: &mut T) -> Self
+    where
+        T: RngCore + CryptoRng,
+    {
+        Self(StaticSecret::random(csprng))
+    }
+
+    pub fn public_key(&self) -> PublicKey {
+        PublicKey(self.0.public_key().to_bytes())
+    }
+
+    pub fn as_bytes(&self) -> &[u8; KEY_SIZE] {
+        self.0.as_bytes()
+    }
+
+    pub fn to_bytes(&self) -> [u8; KEY_SIZE] {
+        self.0.to_bytes()
+    }
+}
+
+impl From<[u8; KEY_SIZE]> for SecretKey {
+    fn from(bytes: [u8; KEY_SIZE]) -> Self {
+        Self(StaticSecret::from(bytes))
+    }
+}
+
+impl fmt::Debug for SecretKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("SecretKey { ... }")
+    }
+}
+
+/// Stream cipher of a box together with the function deriving its key from
+/// the X25519 shared secret.
+pub trait BoxCipher:
+    KeyIvInit<KeySize = U32, IvSize = U24> + StreamCipher + StreamCipherSeek
+{
+    fn derive_key(shared_secret: &Key) -> Key;
+}
+
+impl BoxCipher for XSalsa20 {
+    fn derive_key(shared_secret: &Key) -> Key {
+        hsalsa::<U10>(shared_secret, &Default::default())
+    }
+}
+
+impl BoxCipher for XChaCha20 {
+    fn derive_key(shared_secret: &Key) -> Key {
+        hchacha::<U10>(shared_secret, &Default::default())
+    }
+}
+
+/// Public-key authenticated encryption between two X25519 key pairs.
+///
+/// [`CryptoBox::new`] performs the key agreement once (`crypto_box_beforenm`)
+/// and the resulting box can then be used for any number of messages. The
+/// key returned by [`CryptoBox::precompute`] can be stored and turned back
+/// into a box with [`NewAead::new`], which makes the box identical to
+/// `crypto_secretbox` under that key.
+///
+/// Ciphertexts are laid out as in libsodium's `_easy` API, with the tag in
+/// front of the encrypted message. Associated data is not supported.
+pub struct CryptoBox<C: BoxCipher> {
+    key: Key,
+    cipher: PhantomData<C>,
+}
+
+impl<C: BoxCipher> CryptoBox<C> {
+    /// Returns an error if `public_key` is a low-order point.
+    pub fn new(public_key: &PublicKey, secret_key: &SecretKey) -> Result<Self, Error> {
+        let mut key = Self::precompute(public_key, secret_key)?;
+        let cryptobox = <Self as NewAead>::new(&key);
+        key.as_mut_slice().zeroize();
+        Ok(cryptobox)
+    }
+
+    /// Shared key of the two parties, as computed by `crypto_box_beforenm`.
+    pub fn precompute(public_key: &PublicKey, secret_key: &SecretKey) -> Result<Key, Error> {
+        let shared_secret = secret_key
+            .0
+            .diffie_hellman(&x25519::PublicKey::from(public_key.0));
+        if !shared_secret.was_contributory() {
+            return Err(Error);
+        }
+        Ok(C::derive_key(Key::from_slice(shared_secret.as_bytes())))
+    }
+}
+
+impl<C: BoxCipher> NewAead for CryptoBox<C> {
+    type KeySize = U32;
+
+    fn new(key: &Key) -> Self {
+        Self {
+            key: *key,
+            cipher: PhantomData,
+        }
+    }
+}
+
+impl<C: BoxCipher> AeadCore for CryptoBox<C> {
+    type NonceSize = U24;
+    type TagSize = U16;
+    type CiphertextOverhead = U0;
+}
+
+impl<C: BoxCipher> AeadInPlace for CryptoBox<C> {
+    fn encrypt_in_place(
+        &self,
+        nonce: &Nonce,
+        associated_data: &[u8],
+        buffer: &mut dyn Buffer,
+    ) -> Result<(), Error> {
+        let pt_len = buffer.len();
+
+        buffer.extend_from_slice(Tag::default().as_slice())?;
+
+        buffer.as_mut().copy_within(..pt_len, TAG_SIZE);
+
+        let tag = self.encrypt_in_place_detached(
+            nonce,
+            associated_data,
+            &mut buffer.as_mut()[TAG_SIZE..],
+        )?;
+        buffer.as_mut()[..TAG_SIZE].copy_from_slice(tag.as_slice());
+        Ok(())
+    }
+
+    fn encrypt_in_place_detached(
+        &self,
+        nonce: &Nonce,
+        associated_data: &[u8],
+        buffer: &mut [u8],
+    ) -> Result<Tag, Error> {
+        Cipher::new(C::new(&self.key, nonce)).encrypt_in_place_detached(associated_data, buffer)
+    }
+
+    fn decrypt_in_place(
+        &self,
+        nonce: &Nonce,
+        associated_data: &[u8],
+        buffer: &mut dyn Buffer,
+    ) -> Result<(), Error> {
+        if buffer.len() < TAG_SIZE {
+            return Err(Error);
+        }
+
+        let tag = Tag::clone_from_slice(&buffer.as_ref()[..TAG_SIZE]);
+        self.decrypt_in_place_detached(
+            nonce,
+            associated_data,
+            &mut buffer.as_mut()[TAG_SIZE..],
+            &tag,
+        )?;
+
+        let pt_len = buffer.len() - TAG_SIZE;
+
+        buffer.as_mut().copy_within(TAG_SIZE.., 0);
+        buffer.truncate(pt_len);
+        Ok(())
+    }
+
+    fn decrypt_in_place_detached(
+        &self,
+        nonce: &Nonce,
+        associated_data: &[u8],
+        buffer: &mut [u8],
+        tag: &Tag,
+    ) -> Result<(), Error> {
+        Cipher::new(C::new(&self.key, nonce)).decrypt_in_place_detached(
+            associated_data,
+            buffer,
+            tag,
+        )
+    }
+}
+
+impl<C: BoxCipher> Clone for CryptoBox<C> {
+    fn clone(&self) -> Self {
+        Self {
+            key: self.key,
+            cipher: PhantomData,
+        }
+    }
+}
+
+impl<C: BoxCipher> Drop for CryptoBox<C> {
+    fn drop(&mut self) {
+        self.key.as_mut_slice().zeroize();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{AeadInPlace, ChaChaBox, NewAead, PublicKey, SalsaBox, SecretKey};
+    use aead::{dev::blobby::Blob3Iterator, dev::blobby::Blob5Iterator, Aead};
+    use hex_literal::hex;
+
+    // All vectors were generated with libsodium 1.0.18; the first row of
+    // each `_box` and `_beforenm` file uses the keys from NaCl's `box.c`.
+    macro_rules! box_test {
+        ($name:ident, $test_name:expr, $box:ty) => {
+            #[test]
+            fn $name() {
+                let data = include_bytes!(concat!("data/", $test_name, "_box.blb"));
+                for row in Blob5Iterator::new(data).unwrap() {
+                    let [sk, pk, nonce, msg, ct] = row.unwrap();
+                    let sk = SecretKey::from(<[u8; 32]>::try_from(sk).unwrap());
+                    let pk = PublicKey::from_slice(pk).unwrap();
+                    let cryptobox = <$box>::new(&pk, &sk).unwrap();
+                    let nonce = nonce.into();
+
+                    assert_eq!(cryptobox.encrypt(nonce, msg).unwrap(), ct);
+                    assert_eq!(cryptobox.decrypt(nonce, ct).unwrap(), msg);
+
+                    let mut bad_ct = ct.to_vec();
+                    *bad_ct.last_mut().unwrap() ^= 1;
+                    assert!(cryptobox.decrypt(nonce, &bad_ct[..]).is_err());
+                    assert!(cryptobox
+                        .encrypt_in_place_detached(nonce, b"aad", &mut [0u8; 4])
+                        .is_err());
+                }
+
+                let data = include_bytes!(concat!("data/", $test_name, "_beforenm.blb"));
+                for row in Blob3Iterator::new(data).unwrap() {
+                    let [sk, pk, key] = row.unwrap();
+                    let sk = SecretKey::from(<[u8; 32]>::try_from(sk).unwrap());
+                    let pk = PublicKey::from_slice(pk).unwrap();
+                    assert_eq!(&<$box>::precompute(&pk, &sk).unwrap()[..], key);
+                }
+            }
+        };
+    }
+
+    macro_rules! seal_test {
+        ($name:ident, $test_name:expr, $box:ty) => {
+            #[test]
+            fn $name() {
+                use chacha20::ChaCha8Rng;
+                use rand_core::SeedableRng;
+
+                let mut rng = ChaCha8Rng::seed_from_u64(22);
+                let data = include_bytes!(concat!("data/", $test_name, "_seal.blb"));
+                for row in Blob3Iterator::new(data).unwrap() {
+                    let [sk, msg, sealed] = row.unwrap();
+                    let sk = SecretKey::from(<[u8; 32]>::try_from(sk).unwrap());
+                    assert_eq!(<$box>::seal_open(&sk, sealed).unwrap(), msg);
+
+                    let resealed = <$box>::seal(&mut rng, &sk.public_key(), msg).unwrap();
+                    assert_eq!(resealed.len(), sealed.len());
+                    assert_eq!(<$box>::seal_open(&sk, &resealed).unwrap(), msg);
+
+                    let mut bad = sealed.to_vec();
+                    bad[0] ^= 1;
+                    assert!(<$box>::seal_open(&sk, &bad).is_err());
+                    assert!(<$box>::seal_open(&sk, &sealed[..47]).is_err());
+                }
+            }
+        };
+    }
+
+    box_test!(salsa_box, "salsa", SalsaBox);
+    box_test!(chacha_box, "chacha", ChaChaBox);
+    seal_test!(salsa_seal, "salsa", SalsaBox);
+    seal_test!(chacha_seal, "chacha", ChaChaBox);
+
+    #[test]
+    fn nacl_keypair() {
+        let alice = SecretKey::from(hex!(
+            "77076d0a7318a57d3c16c17251b26645df4c2f87ebc0992ab177fba51db92c2a"
+        ));
+        let bob = SecretKey::from(hex!(
+            "5dab087e624a8a4b79e17f8b83800ee66f3bb1292618b6fd1c2f8b27ff88e0eb"
+        ));
+        assert_eq!(
+            alice.public_key().as_bytes(),
+            &hex!("8520f0098930a754748b7ddcb43ef75a0dbf3a0d26381af4eba4a98eaa9b4e6a")
+        );
+
+        let alice_key = SalsaBox::precompute(&bob.public_key(), &alice).unwrap();
+        let bob_key = SalsaBox::precompute(&alice.public_key(), &bob).unwrap();
+        assert_eq!(alice_key, bob_key);
+
+        let nonce = Default::default();
+        let ct = SalsaBox::new(&bob.public_key(), &alice)
+            .unwrap()
+            .encrypt(&nonce, &b"message"[..])
+            .unwrap();
+        let precomputed = <SalsaBox as NewAead>::new(&bob_key);
+        assert_eq!(precomputed.decrypt(&nonce, &ct[..]).unwrap(), b"message");
+    }
+
+    #[test]
+    fn low_order_public_key() {
+        let sk = SecretKey::from([1; 32]);
+        let mut one = [0; 32];
+        one[0] = 1;
+        for pk in [[0; 32], one] {
+            let pk = PublicKey::from(pk);
+            assert!(SalsaBox::new(&pk, &sk).is_err());
+            assert!(ChaChaBox::precompute(&pk, &sk).is_err());
+        }
+    }
+}
@@ -0,0 +1,76 @@
+use crate::{
+    AeadInPlace, BoxCipher, CryptoBox, Error, Nonce, PublicKey, SecretKey, Tag, KEY_SIZE, TAG_SIZE,
+};
+use aead::consts::U24;
+use alloc::vec::Vec;
+use blake2::{digest::Digest, Blake2b};
+
+#[cfg(feature = "rand_core")]
+use rand_core::{CryptoRng, RngCore};
+
+/// Length added to a message by [`CryptoBox::seal`]: the ephemeral public
+/// key followed by the tag.
+pub const SEAL_OVERHEAD: usize = KEY_SIZE + TAG_SIZE;
+
+impl<C: BoxCipher> CryptoBox<C> {
+    /// Anonymously encrypt `plaintext` to `public_key` (`crypto_box_seal`).
+    ///
+    /// The message is boxed with a fresh ephemeral key pair, whose public
+    /// key is prepended to the ciphertext, under the nonce
+    /// `BLAKE2b-192(ephemeral_pk || public_key)`.
+    #[cfg(feature = "rand_core")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "rand_core")))]
+    pub fn seal<T>(
+        csprng: &mut T,
+        public_key: &PublicKey,
+        plaintext: &[u8],
+    ) -> Result<Vec<u8>, Error>
+    where
+        T: RngCore + CryptoRng,
+    {
+        let ephemeral_sk = SecretKey::generate(csprng);
+        let ephemeral_pk = ephemeral_sk.public_key();
+        let nonce = seal_nonce(&ephemeral_pk, public_key);
+
+        let mut out = Vec::with_capacity(SEAL_OVERHEAD + plaintext.len());
+        out.extend_from_slice(ephemeral_pk.as_bytes());
+        out.extend_from_slice(&Tag::default());
+        out.extend_from_slice(plaintext);
+
+        let tag = Self::new(public_key, &ephemeral_sk)?.encrypt_in_place_detached(
+            &nonce,
+            b"",
+            &mut out[SEAL_OVERHEAD..],
+        )?;
+        out[KEY_SIZE..SEAL_OVERHEAD].copy_from_slice(&tag);
+        Ok(out)
+    }
+
+    /// Decrypt a message produced by [`CryptoBox::seal`] (`crypto_box_seal_open`).
+    pub fn seal_open(secret_key: &SecretKey, ciphertext: &[u8]) -> Result<Vec<u8>, Error> {
+        if ciphertext.len() < SEAL_OVERHEAD {
+            return Err(Error);
+        }
+
+        let (ephemeral_pk, rest) = ciphertext.split_at(KEY_SIZE);
+        let (tag, msg) = rest.split_at(TAG_SIZE);
+        let ephemeral_pk = PublicKey::from_slice(ephemeral_pk)?;
+        let nonce = seal_nonce(&ephemeral_pk, &secret_key.public_key());
+
+        let mut buffer = Vec::from(msg);
+        Self::new(&ephemeral_pk, secret_key)?.decrypt_in_place_detached(
+            &nonce,
+            b"",
+            &mut buffer,
+            Tag::from_slice(tag),
+        )?;
+        Ok(buffer)
+    }
+}
+
+fn seal_nonce(ephemeral_pk: &PublicKey, recipient_pk: &PublicKey) -> Nonce {
+    Blake2b::<U24>::new()
+        .chain_update(ephemeral_pk)
+        .chain_update(recipient_pk)
+        .finalize()
+}
@@ -0,0 +1,56 @@
+pub(crate) mod affine;
+mod field;
+pub(crate) mod projective;
+pub(crate) mod scalar;
+pub(crate) mod util;
+
+use affine::AffinePoint;
+use field::{FieldElement, MODULUS};
+use projective::ProjectivePoint;
+use scalar::Scalar;
+
+pub(crate) const CURVE_EQUATION_A: FieldElement = FieldElement::ZERO
+    .subtract(&FieldElement::ONE)
+    .subtract(&FieldElement::ONE)
+    .subtract(&FieldElement::ONE);
+
+pub(crate) const CURVE_EQUATION_B: FieldElement = FieldElement([
+    0x90d2_3063_2bc0_dd42,
+    0x71cf_379a_e9b5_37ab,
+    0x5279_8150_5ea5_1c3c,
+    0x240f_e188_ba20_e2c8,
+]);
+
+#[cfg(test)]
+mod tests {
+    use super::{CURVE_EQUATION_A, CURVE_EQUATION_B};
+    use hex_literal::hex;
+
+    const CURVE_EQUATION_A_BYTES: &[u8] =
+        &hex!("FFFFFFFEFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFF00000000FFFFFFFFFFFFFFFC");
+
+    const CURVE_EQUATION_B_BYTES: &[u8] =
+        &hex!("28E9FA9E9D9F5E344D5A9E4BCF6509A7F39789F515AB8F92DDBCBD414D940E93");
+
+    #[test]
+    fn verify_constants() {
+        assert_eq!(
+            CURVE_EQUATION_A.to_bytes().as_slice(),
+            CURVE_EQUATION_A_BYTES
+        );
+        assert_eq!(
+            CURVE_EQUATION_B.to_bytes().as_slice(),
+            CURVE_EQUATION_B_BYTES
+        );
+    }
+
+    #[test]
+    fn generate_secret_key() {
+        use crate::SecretKey;
+        use elliptic_curve::rand_core::OsRng;
+
+        let key = SecretKey::random(&mut OsRng);
+
+        assert!(!key.to_be_bytes().iter().all(|b| *b == 0))
+    }
+}
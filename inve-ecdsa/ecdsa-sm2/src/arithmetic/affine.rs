@@ -0,0 +1,451 @@
+#![allow(clippy::op_ref)]
+
+use super::{FieldElement, ProjectivePoint, CURVE_EQUATION_A, CURVE_EQUATION_B, MODULUS};
+use crate::{CompressedPoint, EncodedPoint, FieldBytes, PublicKey, Scalar, Sm2};
+use core::ops::{Mul, Neg};
+use elliptic_curve::{
+    bigint::Encoding,
+    group::{prime::PrimeCurveAffine, GroupEncoding},
+    sec1::{self, FromEncodedPoint, ToCompactEncodedPoint, ToEncodedPoint},
+    subtle::{Choice, ConditionallySelectable, ConstantTimeEq, CtOption},
+    zeroize::DefaultIsZeroes,
+    AffineArithmetic, AffineXCoordinate, Curve, DecompactPoint, DecompressPoint, Error, Result,
+};
+
+#[cfg(feature = "serde")]
+use elliptic_curve::serde::{de, ser, Deserialize, Serialize};
+
+impl AffineArithmetic for Sm2 {
+    type AffinePoint = AffinePoint;
+}
+
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(docsrs, doc(cfg(feature = "arithmetic")))]
+pub struct AffinePoint {
+    pub(crate) x: FieldElement,
+
+    pub(crate) y: FieldElement,
+
+    pub(super) infinity: u8,
+}
+
+impl AffinePoint {
+    pub const IDENTITY: Self = Self {
+        x: FieldElement::ZERO,
+        y: FieldElement::ZERO,
+        infinity: 1,
+    };
+
+    pub const GENERATOR: Self = Self {
+        x: FieldElement([
+            0x715a_4589_334c_74c7,
+            0x8fe3_0bbf_f266_0be1,
+            0x5f99_0446_6a39_c994,
+            0x32c4_ae2c_1f19_8119,
+        ])
+        .to_montgomery(),
+        y: FieldElement([
+            0x02df_32e5_2139_f0a0,
+            0xd0a9_877c_c62a_4740,
+            0x59bd_cee3_6b69_2153,
+            0xbc37_36a2_f4f6_779c,
+        ])
+        .to_montgomery(),
+        infinity: 0,
+    };
+}
+
+impl PrimeCurveAffine for AffinePoint {
+    type Scalar = Scalar;
+    type Curve = ProjectivePoint;
+
+    fn identity() -> AffinePoint {
+        Self::IDENTITY
+    }
+
+    fn generator() -> AffinePoint {
+        Self::GENERATOR
+    }
+
+    fn is_identity(&self) -> Choice {
+        Choice::from(self.infinity)
+    }
+
+    fn to_curve(&self) -> ProjectivePoint {
+        ProjectivePoint::from(*self)
+    }
+}
+
+impl AffineXCoordinate<Sm2> for AffinePoint {
+    fn x(&self) -> FieldBytes {
+        self.x.to_bytes()
+    }
+}
+
+impl ConditionallySelectable for AffinePoint {
+    fn conditional_select(a: &AffinePoint, b: &AffinePoint, choice: Choice) -> AffinePoint {
+        AffinePoint {
+            x: FieldElement::conditional_select(&a.x, &b.x, choice),
+            y: FieldElement::conditional_select(&a.y, &b.y, choice),
+            infinity: u8::conditional_select(&a.infinity, &b.infinity, choice),
+        }
+    }
+}
+
+impl ConstantTimeEq for AffinePoint {
+    fn ct_eq(&self, other: &AffinePoint) -> Choice {
+        self.x.ct_eq(&other.x) & self.y.ct_eq(&other.y) & self.infinity.ct_eq(&other.infinity)
+    }
+}
+
+impl Default for AffinePoint {
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+
+impl DefaultIsZeroes for AffinePoint {}
+
+impl Eq for AffinePoint {}
+
+impl PartialEq for AffinePoint {
+    fn eq(&self, other: &AffinePoint) -> bool {
+        self.ct_eq(other).into()
+    }
+}
+
+impl Mul<Scalar> for AffinePoint {
+    type Output = ProjectivePoint;
+
+    fn mul(self, scalar: Scalar) -> ProjectivePoint {
+        ProjectivePoint::from(self) * scalar
+    }
+}
+
+impl Mul<&Scalar> for AffinePoint {
+    type Output = ProjectivePoint;
+
+    fn mul(self, scalar: &Scalar) -> ProjectivePoint {
+        ProjectivePoint::from(self) * scalar
+    }
+}
+
+impl Neg for AffinePoint {
+    type Output = AffinePoint;
+
+    fn neg(self) -> Self::Output {
+        AffinePoint {
+            x: self.x,
+            y: -self.y,
+            infinity: self.infinity,
+        }
+    }
+}
+
+impl DecompressPoint<Sm2> for AffinePoint {
+    fn decompress(x_bytes: &FieldBytes, y_is_odd: Choice) -> CtOption<Self> {
+        FieldElement::from_bytes(x_bytes).and_then(|x| {
+            let alpha = x * &x * &x + &(CURVE_EQUATION_A * &x) + &CURVE_EQUATION_B;
+            let beta = alpha.sqrt();
+
+            beta.map(|beta| {
+                let y = FieldElement::conditional_select(
+                    &(MODULUS - &beta),
+                    &beta,
+                    beta.is_odd().ct_eq(&y_is_odd),
+                );
+
+                Self { x, y, infinity: 0 }
+            })
+        })
+    }
+}
+
+impl GroupEncoding for AffinePoint {
+    type Repr = CompressedPoint;
+
+    fn from_bytes(bytes: &Self::Repr) -> CtOption<Self> {
+        EncodedPoint::from_bytes(bytes)
+            .map(|point| CtOption::new(point, Choice::from(1)))
+            .unwrap_or_else(|_| {
+                let is_identity = bytes.ct_eq(&Self::Repr::default());
+                CtOption::new(EncodedPoint::identity(), is_identity)
+            })
+            .and_then(|point| Self::from_encoded_point(&point))
+    }
+
+    fn from_bytes_unchecked(bytes: &Self::Repr) -> CtOption<Self> {
+        Self::from_bytes(bytes)
+    }
+
+    fn to_bytes(&self) -> Self::Repr {
+        let encoded = self.to_encoded_point(true);
+        let mut result = CompressedPoint::default();
+        result[..encoded.len()].copy_from_slice(encoded.as_bytes());
+        result
+    }
+}
+
+impl DecompactPoint<Sm2> for AffinePoint {
+    fn decompact(x_bytes: &FieldBytes) -> CtOption<Self> {
+        FieldElement::from_bytes(x_bytes).and_then(|x| {
+            let montgomery_y = (x * &x * &x + &(CURVE_EQUATION_A * &x) + &CURVE_EQUATION_B).sqrt();
+            montgomery_y.map(|montgomery_y| {
+                let y = montgomery_y.to_canonical();
+                let p_y = MODULUS.subtract(&y);
+                let (_, borrow) = p_y.informed_subtract(&y);
+                let recovered_y = if borrow == 0 { y } else { p_y };
+                AffinePoint {
+                    x,
+                    y: recovered_y.to_montgomery(),
+                    infinity: 0,
+                }
+            })
+        })
+    }
+}
+
+impl FromEncodedPoint<Sm2> for AffinePoint {
+    fn from_encoded_point(encoded_point: &EncodedPoint) -> CtOption<Self> {
+        match encoded_point.coordinates() {
+            sec1::Coordinates::Identity => CtOption::new(Self::identity(), 1.into()),
+            sec1::Coordinates::Compact { x } => AffinePoint::decompact(x),
+            sec1::Coordinates::Compressed { x, y_is_odd } => {
+                AffinePoint::decompress(x, Choice::from(y_is_odd as u8))
+            }
+            sec1::Coordinates::Uncompressed { x, y } => {
+                let x = FieldElement::from_bytes(x);
+                let y = FieldElement::from_bytes(y);
+
+                x.and_then(|x| {
+                    y.and_then(|y| {
+                        let lhs = y * &y;
+                        let rhs = x * &x * &x + &(CURVE_EQUATION_A * &x) + &CURVE_EQUATION_B;
+                        let point = AffinePoint { x, y, infinity: 0 };
+                        CtOption::new(point, lhs.ct_eq(&rhs))
+                    })
+                })
+            }
+        }
+    }
+}
+
+impl ToEncodedPoint<Sm2> for AffinePoint {
+    fn to_encoded_point(&self, compress: bool) -> EncodedPoint {
+        EncodedPoint::conditional_select(
+            &EncodedPoint::from_affine_coordinates(
+                &self.x.to_bytes(),
+                &self.y.to_bytes(),
+                compress,
+            ),
+            &EncodedPoint::identity(),
+            self.is_identity(),
+        )
+    }
+}
+
+impl ToCompactEncodedPoint<Sm2> for AffinePoint {
+    fn to_compact_encoded_point(&self) -> CtOption<EncodedPoint> {
+        let y = self.y.to_canonical();
+        let (p_y, borrow) = MODULUS.informed_subtract(&y);
+        assert_eq!(borrow, 0);
+        let (_, borrow) = p_y.informed_subtract(&y);
+
+        let mut bytes = CompressedPoint::default();
+        bytes[0] = sec1::Tag::Compact.into();
+        bytes[1..(<Sm2 as Curve>::UInt::BYTE_SIZE + 1)].copy_from_slice(&self.x.to_bytes());
+        CtOption::new(
+            EncodedPoint::from_bytes(bytes).expect("compact key"),
+            borrow.ct_eq(&0),
+        )
+    }
+}
+
+impl TryFrom<EncodedPoint> for AffinePoint {
+    type Error = Error;
+
+    fn try_from(point: EncodedPoint) -> Result<AffinePoint> {
+        AffinePoint::try_from(&point)
+    }
+}
+
+impl TryFrom<&EncodedPoint> for AffinePoint {
+    type Error = Error;
+
+    fn try_from(point: &EncodedPoint) -> Result<AffinePoint> {
+        Option::from(AffinePoint::from_encoded_point(point)).ok_or(Error)
+    }
+}
+
+impl From<AffinePoint> for EncodedPoint {
+    fn from(affine_point: AffinePoint) -> EncodedPoint {
+        affine_point.to_encoded_point(false)
+    }
+}
+
+impl From<PublicKey> for AffinePoint {
+    fn from(public_key: PublicKey) -> AffinePoint {
+        *public_key.as_affine()
+    }
+}
+
+impl From<&PublicKey> for AffinePoint {
+    fn from(public_key: &PublicKey) -> AffinePoint {
+        AffinePoint::from(*public_key)
+    }
+}
+
+impl TryFrom<AffinePoint> for PublicKey {
+    type Error = Error;
+
+    fn try_from(affine_point: AffinePoint) -> Result<PublicKey> {
+        PublicKey::from_affine(affine_point)
+    }
+}
+
+impl TryFrom<&AffinePoint> for PublicKey {
+    type Error = Error;
+
+    fn try_from(affine_point: &AffinePoint) -> Result<PublicKey> {
+        PublicKey::try_from(*affine_point)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+impl Serialize for AffinePoint {
+    fn serialize<S>(&self, serializer: S) -> core::result::Result<S::Ok, S::Error>
+    where
+        S: ser::Serializer,
+    {
+        self.to_encoded_point(true).serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for AffinePoint {
+    fn deserialize<D>(deserializer: D) -> core::result::Result<Self, D::Error>
+    where
+        D: de::Deserializer<'de>,
+    {
+        EncodedPoint::deserialize(deserializer)?
+            .try_into()
+            .map_err(de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::AffinePoint;
+    use crate::EncodedPoint;
+    use elliptic_curve::{
+        group::{prime::PrimeCurveAffine, GroupEncoding},
+        sec1::{FromEncodedPoint, ToCompactEncodedPoint, ToEncodedPoint},
+    };
+    use hex_literal::hex;
+
+    const UNCOMPRESSED_BASEPOINT: &[u8] = &hex!(
+        "0432C4AE2C1F1981195F9904466A39C9948FE30BBFF2660BE1715A4589334C74C7
+         BC3736A2F4F6779C59BDCEE36B692153D0A9877CC62A474002DF32E52139F0A0"
+    );
+
+    const COMPRESSED_BASEPOINT: &[u8] =
+        &hex!("0232C4AE2C1F1981195F9904466A39C9948FE30BBFF2660BE1715A4589334C74C7");
+
+    const COMPACT_BASEPOINT: &[u8] =
+        &hex!("0556cefd60d7c87c000d58ef57fa73ba4d9c0dfa08c08a7331495c2e1da3f2bd52");
+
+    const UNCOMPACT_BASEPOINT: &[u8] = &hex!(
+        "0456cefd60d7c87c000d58ef57fa73ba4d9c0dfa08c08a7331495c2e1da3f2bd52
+         31b7e7e6cc8189f668535ce0f8eaf1bd6de84c182f6c8e716f780d3a970a23c3"
+    );
+
+    #[test]
+    fn uncompressed_round_trip() {
+        let pubkey = EncodedPoint::from_bytes(UNCOMPRESSED_BASEPOINT).unwrap();
+        let point = AffinePoint::from_encoded_point(&pubkey).unwrap();
+        assert_eq!(point, AffinePoint::generator());
+
+        let res: EncodedPoint = point.into();
+        assert_eq!(res, pubkey);
+    }
+
+    #[test]
+    fn compressed_round_trip() {
+        let pubkey = EncodedPoint::from_bytes(COMPRESSED_BASEPOINT).unwrap();
+        let point = AffinePoint::from_encoded_point(&pubkey).unwrap();
+        assert_eq!(point, AffinePoint::generator());
+
+        let res: EncodedPoint = point.to_encoded_point(true);
+        assert_eq!(res, pubkey);
+    }
+
+    #[test]
+    fn uncompressed_to_compressed() {
+        let encoded = EncodedPoint::from_bytes(UNCOMPRESSED_BASEPOINT).unwrap();
+
+        let res = AffinePoint::from_encoded_point(&encoded)
+            .unwrap()
+            .to_encoded_point(true);
+
+        assert_eq!(res.as_bytes(), COMPRESSED_BASEPOINT);
+    }
+
+    #[test]
+    fn compressed_to_uncompressed() {
+        let encoded = EncodedPoint::from_bytes(COMPRESSED_BASEPOINT).unwrap();
+
+        let res = AffinePoint::from_encoded_point(&encoded)
+            .unwrap()
+            .to_encoded_point(false);
+
+        assert_eq!(res.as_bytes(), UNCOMPRESSED_BASEPOINT);
+    }
+
+    #[test]
+    fn affine_negation() {
+        let basepoint = AffinePoint::generator();
+        assert_eq!(-(-basepoint), basepoint);
+    }
+
+    #[test]
+    fn compact_round_trip() {
+        let pubkey = EncodedPoint::from_bytes(COMPACT_BASEPOINT).unwrap();
+        assert!(pubkey.is_compact());
+
+        let point = AffinePoint::from_encoded_point(&pubkey).unwrap();
+        let res = point.to_compact_encoded_point().unwrap();
+        assert_eq!(res, pubkey)
+    }
+
+    #[test]
+    fn uncompact_to_compact() {
+        let pubkey = EncodedPoint::from_bytes(UNCOMPACT_BASEPOINT).unwrap();
+        assert!(!pubkey.is_compact());
+
+        let point = AffinePoint::from_encoded_point(&pubkey).unwrap();
+        let res = point.to_compact_encoded_point().unwrap();
+        assert_eq!(res.as_bytes(), COMPACT_BASEPOINT)
+    }
+
+    #[test]
+    fn compact_to_uncompact() {
+        let pubkey = EncodedPoint::from_bytes(COMPACT_BASEPOINT).unwrap();
+        assert!(pubkey.is_compact());
+
+        let point = AffinePoint::from_encoded_point(&pubkey).unwrap();
+        let res = point.to_encoded_point(false);
+        assert_eq!(res.as_bytes(), UNCOMPACT_BASEPOINT);
+    }
+
+    #[test]
+    fn identity_encoding() {
+        assert_eq!([0; 33], AffinePoint::IDENTITY.to_bytes().as_slice());
+        assert!(bool::from(
+            AffinePoint::from_bytes(&AffinePoint::IDENTITY.to_bytes())
+                .unwrap()
+                .is_identity()
+        ))
+    }
+}
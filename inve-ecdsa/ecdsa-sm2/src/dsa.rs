@@ -0,0 +1,288 @@
+//! SM2 digital signature algorithm (GB/T 32918.2).
+//!
+//! Messages are hashed together with the signer's identity hash `ZA`, so
+//! both keys carry the distinguishing identifier used by the signer.
+
+pub use ecdsa_core::signature::{self, Error};
+
+use crate::{
+    hash::hash_za, FieldBytes, NonZeroScalar, ProjectivePoint, PublicKey, Scalar, SecretKey, Sm2,
+    U256,
+};
+use elliptic_curve::{
+    group::{
+        ff::{Field, PrimeField},
+        prime::PrimeCurveAffine,
+    },
+    ops::{LinearCombination, Reduce},
+    rand_core::{CryptoRng, RngCore},
+    sec1::ToEncodedPoint,
+    zeroize::Zeroize,
+    AffineXCoordinate,
+};
+use rfc6979::HmacDrbg;
+use signature::{RandomizedSigner, Signer, Verifier};
+use sm3::{Digest, Sm3};
+
+pub type Signature = ecdsa_core::Signature<Sm2>;
+
+pub type DerSignature = ecdsa_core::der::Signature<Sm2>;
+
+/// Distinguishing identifier used when none has been agreed upon.
+pub const DEFAULT_DIST_ID: &str = "1234567812345678";
+
+#[derive(Clone)]
+pub struct SigningKey {
+    secret_scalar: NonZeroScalar,
+    verifying_key: VerifyingKey,
+}
+
+impl SigningKey {
+    pub fn new(distid: &str, secret_key: &SecretKey) -> Result<Self, Error> {
+        Self::from_nonzero_scalar(distid, secret_key.to_nonzero_scalar())
+    }
+
+    pub fn from_bytes(distid: &str, bytes: &[u8]) -> Result<Self, Error> {
+        let secret_key = SecretKey::from_be_bytes(bytes).map_err(|_| Error::new())?;
+        Self::new(distid, &secret_key)
+    }
+
+    pub fn from_nonzero_scalar(distid: &str, secret_scalar: NonZeroScalar) -> Result<Self, Error> {
+        // `1 + d` is inverted while signing.
+        if *secret_scalar == -Scalar::ONE {
+            return Err(Error::new());
+        }
+
+        let public_key = PublicKey::from_secret_scalar(&secret_scalar);
+        let verifying_key = VerifyingKey::new(distid, public_key)?;
+        Ok(Self {
+            secret_scalar,
+            verifying_key,
+        })
+    }
+
+    pub fn verifying_key(&self) -> &VerifyingKey {
+        &self.verifying_key
+    }
+
+    pub fn to_bytes(&self) -> FieldBytes {
+        self.secret_scalar.to_repr()
+    }
+
+    fn sign_with_drbg(&self, msg: &[u8], data: &[u8]) -> Result<Signature, Error> {
+        let e = self.verifying_key.hash_message(msg);
+        let mut x = self.to_bytes();
+        let mut drbg = HmacDrbg::<Sm3>::new(&x, &e, data);
+        x.zeroize();
+
+        loop {
+            let mut bytes = FieldBytes::default();
+            drbg.fill_bytes(&mut bytes);
+            let k = Option::<NonZeroScalar>::from(NonZeroScalar::from_repr(bytes));
+            bytes.zeroize();
+
+            if let Some(signature) = k.and_then(|k| self.sign_prehash_with_k(&e, &k)) {
+                return Ok(signature);
+            }
+        }
+    }
+
+    /// Sign the hash `e = SM3(ZA || M)` using the ephemeral scalar `k`,
+    /// returning `None` if `k` has to be rejected.
+    pub(crate) fn sign_prehash_with_k(
+        &self,
+        e: &FieldBytes,
+        k: &NonZeroScalar,
+    ) -> Option<Signature> {
+        let e = <Scalar as Reduce<U256>>::from_be_bytes_reduced(*e);
+        let x1 = (ProjectivePoint::GENERATOR * **k).to_affine().x();
+        let r = e + <Scalar as Reduce<U256>>::from_be_bytes_reduced(x1);
+        if bool::from(r.is_zero()) || bool::from((r + **k).is_zero()) {
+            return None;
+        }
+
+        let d = *self.secret_scalar;
+        let s = (Scalar::ONE + d).invert().unwrap() * (**k - r * d);
+        Signature::from_scalars(r, s).ok()
+    }
+}
+
+impl Signer<Signature> for SigningKey {
+    fn try_sign(&self, msg: &[u8]) -> Result<Signature, Error> {
+        self.sign_with_drbg(msg, &[])
+    }
+}
+
+impl RandomizedSigner<Signature> for SigningKey {
+    fn try_sign_with_rng(
+        &self,
+        mut rng: impl CryptoRng + RngCore,
+        msg: &[u8],
+    ) -> Result<Signature, Error> {
+        let mut added_entropy = FieldBytes::default();
+        rng.fill_bytes(&mut added_entropy);
+        self.sign_with_drbg(msg, &added_entropy)
+    }
+}
+
+impl From<&SigningKey> for VerifyingKey {
+    fn from(signing_key: &SigningKey) -> VerifyingKey {
+        signing_key.verifying_key.clone()
+    }
+}
+
+impl Drop for SigningKey {
+    fn drop(&mut self) {
+        self.secret_scalar.zeroize();
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct VerifyingKey {
+    public_key: PublicKey,
+    za: [u8; 32],
+}
+
+impl VerifyingKey {
+    pub fn new(distid: &str, public_key: PublicKey) -> Result<Self, Error> {
+        let za = hash_za(distid, &public_key).map_err(|_| Error::new())?;
+        Ok(Self { public_key, za })
+    }
+
+    pub fn from_sec1_bytes(distid: &str, bytes: &[u8]) -> Result<Self, Error> {
+        let public_key = PublicKey::from_sec1_bytes(bytes).map_err(|_| Error::new())?;
+        Self::new(distid, public_key)
+    }
+
+    pub fn public_key(&self) -> &PublicKey {
+        &self.public_key
+    }
+
+    pub fn to_encoded_point(&self, compress: bool) -> crate::EncodedPoint {
+        self.public_key.to_encoded_point(compress)
+    }
+
+    fn hash_message(&self, msg: &[u8]) -> FieldBytes {
+        Sm3::new()
+            .chain_update(self.za)
+            .chain_update(msg)
+            .finalize()
+    }
+}
+
+impl Verifier<Signature> for VerifyingKey {
+    fn verify(&self, msg: &[u8], signature: &Signature) -> Result<(), Error> {
+        let (r, s) = signature.split_scalars();
+        let t = *r + *s;
+        if bool::from(t.is_zero()) {
+            return Err(Error::new());
+        }
+
+        let e = <Scalar as Reduce<U256>>::from_be_bytes_reduced(self.hash_message(msg));
+        let point = ProjectivePoint::lincomb(
+            &ProjectivePoint::GENERATOR,
+            &s,
+            &self.public_key.to_projective(),
+            &t,
+        )
+        .to_affine();
+        let x1 = <Scalar as Reduce<U256>>::from_be_bytes_reduced(point.x());
+
+        if !bool::from(point.is_identity()) && e + x1 == *r {
+            Ok(())
+        } else {
+            Err(Error::new())
+        }
+    }
+}
+
+impl From<VerifyingKey> for PublicKey {
+    fn from(verifying_key: VerifyingKey) -> PublicKey {
+        verifying_key.public_key
+    }
+}
+
+impl PartialEq for VerifyingKey {
+    fn eq(&self, other: &Self) -> bool {
+        self.public_key == other.public_key && self.za == other.za
+    }
+}
+
+impl Eq for VerifyingKey {}
+
+#[cfg(test)]
+mod tests {
+    use super::{SigningKey, VerifyingKey, DEFAULT_DIST_ID};
+    use crate::{FieldBytes, NonZeroScalar};
+    use ecdsa_core::signature::{RandomizedSigner, Signer, Verifier};
+    use elliptic_curve::rand_core::OsRng;
+    use hex_literal::hex;
+
+    const SECRET: [u8; 32] =
+        hex!("3945208F7B2144B13F36E38AC6D39F95889393692860B51A42FB81EF4DF7C5B8");
+
+    #[test]
+    fn public_key() {
+        let signing_key = SigningKey::from_bytes(DEFAULT_DIST_ID, &SECRET).unwrap();
+        assert_eq!(
+            signing_key
+                .verifying_key()
+                .to_encoded_point(false)
+                .as_bytes(),
+            hex!(
+                "0409F9DF311E5421A150DD7D161E4BC5C672179FAD1833FC076BB08FF356F35020
+                 CCEA490CE26775A52DC6EA718CC1AA600AED05FBF35E084A6632F6072DA9AD13"
+            )
+        );
+    }
+
+    #[test]
+    fn gbt_32918_vector() {
+        let signing_key = SigningKey::from_bytes(DEFAULT_DIST_ID, &SECRET).unwrap();
+        let verifying_key = signing_key.verifying_key();
+        assert_eq!(
+            verifying_key.za,
+            hex!("B2E14C5C79C6DF5B85F4FE7ED8DB7A262B9DA7E07CCB0EA9F4747B8CCDA8A4F3")
+        );
+
+        let e = verifying_key.hash_message(b"message digest");
+        assert_eq!(
+            e,
+            FieldBytes::from(hex!(
+                "F0B43E94BA45ACCAACE692ED534382EB17E6AB5A19CE7B31F4486FDFC0D28640"
+            ))
+        );
+
+        let k = NonZeroScalar::from_repr(
+            hex!("59276E27D506861A16680F3AD9C02DCCEF3CC1FA3CDBE4CE6D54B80DEAC1BC21").into(),
+        )
+        .unwrap();
+        let signature = signing_key.sign_prehash_with_k(&e, &k).unwrap();
+        assert_eq!(
+            signature.as_ref(),
+            hex!(
+                "F5A03B0648D2C4630EEAC513E1BB81A15944DA3827D5B74143AC7EACEEE720B3
+                 B1B6AA29DF212FD8763182BC0D421CA1BB9038FD1F7F42D4840B69C485BBC1AA"
+            )
+        );
+        assert!(verifying_key.verify(b"message digest", &signature).is_ok());
+    }
+
+    #[test]
+    fn sign_verify() {
+        let secret_key = crate::SecretKey::random(&mut OsRng);
+        let signing_key = SigningKey::new("ALICE123@YAHOO.COM", &secret_key).unwrap();
+        let verifying_key = VerifyingKey::from(&signing_key);
+
+        let signature = signing_key.sign(b"deterministic");
+        assert_eq!(signature, signing_key.sign(b"deterministic"));
+        assert!(verifying_key.verify(b"deterministic", &signature).is_ok());
+        assert!(verifying_key.verify(b"other message", &signature).is_err());
+
+        let signature = signing_key.sign_with_rng(&mut OsRng, b"randomized");
+        assert!(verifying_key.verify(b"randomized", &signature).is_ok());
+
+        let other_id = VerifyingKey::new(DEFAULT_DIST_ID, secret_key.public_key()).unwrap();
+        assert!(other_id.verify(b"randomized", &signature).is_err());
+    }
+}
@@ -0,0 +1,41 @@
+use crate::{
+    arithmetic::{CURVE_EQUATION_A, CURVE_EQUATION_B},
+    AffinePoint, PublicKey,
+};
+use elliptic_curve::{sec1::ToEncodedPoint, Error, Result};
+use sm3::{Digest, Sm3};
+
+/// Identity hash `Z = SM3(ENTL || ID || a || b || xG || yG || xA || yA)`,
+/// binding a distinguishing identifier to a public key.
+pub(crate) fn hash_za(distid: &str, public_key: &PublicKey) -> Result<[u8; 32]> {
+    let entl: u16 = distid
+        .len()
+        .checked_mul(8)
+        .and_then(|bits| bits.try_into().ok())
+        .ok_or(Error)?;
+    let generator = AffinePoint::GENERATOR.to_encoded_point(false);
+    let public_key = public_key.to_encoded_point(false);
+
+    let za = Sm3::new()
+        .chain_update(entl.to_be_bytes())
+        .chain_update(distid)
+        .chain_update(CURVE_EQUATION_A.to_bytes())
+        .chain_update(CURVE_EQUATION_B.to_bytes())
+        .chain_update(&generator.as_bytes()[1..])
+        .chain_update(&public_key.as_bytes()[1..])
+        .finalize();
+    Ok(za.into())
+}
+
+/// Key derivation function from GB/T 32918.4, fills `out` with
+/// `SM3(Z || ct)` for a big endian 32-bit counter starting at 1.
+pub(crate) fn kdf(z: &[&[u8]], out: &mut [u8]) {
+    for (ct, chunk) in (1u32..).zip(out.chunks_mut(32)) {
+        let mut hasher = Sm3::new();
+        for part in z {
+            hasher.update(part);
+        }
+        hasher.update(ct.to_be_bytes());
+        chunk.copy_from_slice(&hasher.finalize()[..chunk.len()]);
+    }
+}
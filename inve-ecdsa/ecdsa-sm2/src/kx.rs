@@ -0,0 +1,323 @@
+//! SM2 key exchange (GB/T 32918.3).
+//!
+//! Each party holds a long-term [`StaticKey`] and generates a fresh
+//! [`EphemeralKey`] per session. After exchanging ephemeral public keys,
+//! [`StaticKey::agree`] derives the shared key along with a
+//! [`Confirmation`] which the parties can use to prove they derived the
+//! same key.
+
+use crate::{
+    hash::{hash_za, kdf},
+    FieldBytes, NonZeroScalar, PublicKey, Scalar, SecretKey,
+};
+use elliptic_curve::{
+    group::ff::PrimeField,
+    rand_core::{CryptoRng, RngCore},
+    sec1::ToEncodedPoint,
+    subtle::ConstantTimeEq,
+    zeroize::Zeroize,
+    AffineXCoordinate, Error, Result,
+};
+use sm3::{Digest, Sm3};
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Role {
+    Initiator,
+    Responder,
+}
+
+#[derive(Clone)]
+pub struct StaticKey {
+    secret_scalar: NonZeroScalar,
+    peer_key: PeerKey,
+}
+
+impl StaticKey {
+    pub fn new(distid: &str, secret_key: &SecretKey) -> Result<Self> {
+        let secret_scalar = secret_key.to_nonzero_scalar();
+        let peer_key = PeerKey::new(distid, secret_key.public_key())?;
+        Ok(Self {
+            secret_scalar,
+            peer_key,
+        })
+    }
+
+    pub fn public_key(&self) -> &PublicKey {
+        &self.peer_key.public_key
+    }
+
+    /// Public part of this key, as seen by the other party.
+    pub fn peer_key(&self) -> &PeerKey {
+        &self.peer_key
+    }
+
+    /// Derive `shared_key.len()` bytes of key material shared with `peer`.
+    pub fn agree(
+        &self,
+        role: Role,
+        ephemeral: &EphemeralKey,
+        peer: &PeerKey,
+        peer_ephemeral: &PublicKey,
+        shared_key: &mut [u8],
+    ) -> Result<Confirmation> {
+        let mut t =
+            *self.secret_scalar + reduced_x(&ephemeral.public_key) * *ephemeral.secret_scalar;
+        let point = peer.public_key.to_projective()
+            + peer_ephemeral.to_projective() * reduced_x(peer_ephemeral);
+        let v = (point * t).to_affine().to_encoded_point(false);
+        t.zeroize();
+        let (xv, yv) = match (v.x(), v.y()) {
+            (Some(x), Some(y)) => (x, y),
+            _ => return Err(Error),
+        };
+
+        let (za, zb, ra, rb) = match role {
+            Role::Initiator => (
+                &self.peer_key.za,
+                &peer.za,
+                &ephemeral.public_key,
+                peer_ephemeral,
+            ),
+            Role::Responder => (
+                &peer.za,
+                &self.peer_key.za,
+                peer_ephemeral,
+                &ephemeral.public_key,
+            ),
+        };
+        kdf(&[xv, yv, za, zb], shared_key);
+
+        let inner = Sm3::new()
+            .chain_update(xv)
+            .chain_update(za)
+            .chain_update(zb)
+            .chain_update(&ra.to_encoded_point(false).as_bytes()[1..])
+            .chain_update(&rb.to_encoded_point(false).as_bytes()[1..])
+            .finalize();
+        let tag = |prefix: u8| -> [u8; 32] {
+            Sm3::new()
+                .chain_update([prefix])
+                .chain_update(yv)
+                .chain_update(inner)
+                .finalize()
+                .into()
+        };
+        let (s_initiator, s_responder) = (tag(0x03), tag(0x02));
+
+        Ok(match role {
+            Role::Initiator => Confirmation {
+                local: s_initiator,
+                remote: s_responder,
+            },
+            Role::Responder => Confirmation {
+                local: s_responder,
+                remote: s_initiator,
+            },
+        })
+    }
+}
+
+impl Drop for StaticKey {
+    fn drop(&mut self) {
+        self.secret_scalar.zeroize();
+    }
+}
+
+/// Public key of the other party along with its distinguishing identifier.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PeerKey {
+    public_key: PublicKey,
+    za: [u8; 32],
+}
+
+impl PeerKey {
+    pub fn new(distid: &str, public_key: PublicKey) -> Result<Self> {
+        let za = hash_za(distid, &public_key)?;
+        Ok(Self { public_key, za })
+    }
+
+    pub fn public_key(&self) -> &PublicKey {
+        &self.public_key
+    }
+}
+
+/// Per-session key pair `(r, R)`.
+#[derive(Clone)]
+pub struct EphemeralKey {
+    secret_scalar: NonZeroScalar,
+    public_key: PublicKey,
+}
+
+impl EphemeralKey {
+    pub fn random(rng: impl CryptoRng + RngCore) -> Self {
+        Self::from_nonzero_scalar(NonZeroScalar::random(rng))
+    }
+
+    pub(crate) fn from_nonzero_scalar(secret_scalar: NonZeroScalar) -> Self {
+        let public_key = PublicKey::from_secret_scalar(&secret_scalar);
+        Self {
+            secret_scalar,
+            public_key,
+        }
+    }
+
+    pub fn public_key(&self) -> &PublicKey {
+        &self.public_key
+    }
+}
+
+impl Drop for EphemeralKey {
+    fn drop(&mut self) {
+        self.secret_scalar.zeroize();
+    }
+}
+
+/// Optional key confirmation values (`S_A`/`S_B` in the standard).
+#[derive(Clone, Debug)]
+pub struct Confirmation {
+    local: [u8; 32],
+    remote: [u8; 32],
+}
+
+impl Confirmation {
+    /// Value to send to the other party.
+    pub fn local(&self) -> &[u8; 32] {
+        &self.local
+    }
+
+    /// Check the value received from the other party.
+    pub fn verify(&self, remote: &[u8]) -> Result<()> {
+        if bool::from(self.remote.ct_eq(remote)) {
+            Ok(())
+        } else {
+            Err(Error)
+        }
+    }
+}
+
+/// `x̄ = 2^127 + (x mod 2^127)` for the x-coordinate of `point`.
+fn reduced_x(point: &PublicKey) -> Scalar {
+    let x = point.as_affine().x();
+    let mut bytes = FieldBytes::default();
+    bytes[16..].copy_from_slice(&x[16..]);
+    bytes[16] |= 0x80;
+    Scalar::from_repr(bytes).unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{EphemeralKey, PeerKey, Role, StaticKey};
+    use crate::{NonZeroScalar, SecretKey};
+    use elliptic_curve::rand_core::OsRng;
+    use hex_literal::hex;
+
+    const DIST_ID: &str = "1234567812345678";
+
+    #[test]
+    fn gbt_32918_vector() {
+        let da = SecretKey::from_be_bytes(&hex!(
+            "81EB26E941BB5AF16DF116495F90695272AE2CD63D6C4AE1678418BE48230029"
+        ))
+        .unwrap();
+        let db = SecretKey::from_be_bytes(&hex!(
+            "785129917D45A9EA5437A59356B82338EAADDA6CEB199088F14AE10DEFA229B5"
+        ))
+        .unwrap();
+        let ra = EphemeralKey::from_nonzero_scalar(
+            NonZeroScalar::from_repr(
+                hex!("D4DE15474DB74D06491C440D305E012400990F3E390C7E87153C12DB2EA60BB3").into(),
+            )
+            .unwrap(),
+        );
+        let rb = EphemeralKey::from_nonzero_scalar(
+            NonZeroScalar::from_repr(
+                hex!("7E07124814B309489125EAED101113164EBF0F3458C5BD88335C1F9D596243D6").into(),
+            )
+            .unwrap(),
+        );
+
+        let alice = StaticKey::new(DIST_ID, &da).unwrap();
+        let bob = StaticKey::new(DIST_ID, &db).unwrap();
+        assert_eq!(
+            alice.peer_key().za,
+            hex!("3B85A57179E11E7E513AA622991F2CA74D1807A0BD4D4B38F90987A17AC245B1")
+        );
+        assert_eq!(
+            bob.peer_key().za,
+            hex!("79C988D63229D97EF19FE02CA1056E01E6A7411ED24694AA8F834F4A4AB022F7")
+        );
+
+        let mut kb = [0; 16];
+        let cb = bob
+            .agree(
+                Role::Responder,
+                &rb,
+                alice.peer_key(),
+                ra.public_key(),
+                &mut kb,
+            )
+            .unwrap();
+        let mut ka = [0; 16];
+        let ca = alice
+            .agree(
+                Role::Initiator,
+                &ra,
+                bob.peer_key(),
+                rb.public_key(),
+                &mut ka,
+            )
+            .unwrap();
+
+        assert_eq!(ka, hex!("6C89347354DE2484C60B4AB1FDE4C6E5"));
+        assert_eq!(kb, ka);
+        assert_eq!(
+            cb.local(),
+            &hex!("D3A0FE15DEE185CEAE907A6B595CC32A266ED7B3367E9983A896DC32FA20F8EB")
+        );
+        assert_eq!(
+            ca.local(),
+            &hex!("18C7894B3816DF16CF07B05C5EC0BEF5D655D58F779CC1B400A4F3884644DB88")
+        );
+        assert!(ca.verify(cb.local()).is_ok());
+        assert!(cb.verify(ca.local()).is_ok());
+        assert!(ca.verify(ca.local()).is_err());
+    }
+
+    #[test]
+    fn agree_random() {
+        let alice = StaticKey::new("alice", &SecretKey::random(&mut OsRng)).unwrap();
+        let bob = StaticKey::new("bob", &SecretKey::random(&mut OsRng)).unwrap();
+        let ra = EphemeralKey::random(&mut OsRng);
+        let rb = EphemeralKey::random(&mut OsRng);
+
+        let mut ka = [0; 48];
+        let ca = alice
+            .agree(
+                Role::Initiator,
+                &ra,
+                bob.peer_key(),
+                rb.public_key(),
+                &mut ka,
+            )
+            .unwrap();
+
+        let mut kb = [0; 48];
+        let cb = bob
+            .agree(
+                Role::Responder,
+                &rb,
+                alice.peer_key(),
+                ra.public_key(),
+                &mut kb,
+            )
+            .unwrap();
+        assert_eq!(ka, kb);
+        assert!(cb.verify(ca.local()).is_ok());
+
+        let eve = PeerKey::new("eve", *alice.public_key()).unwrap();
+        let mut ke = [0; 48];
+        bob.agree(Role::Responder, &rb, &eve, ra.public_key(), &mut ke)
+            .unwrap();
+        assert_ne!(ka, ke);
+    }
+}
@@ -0,0 +1,86 @@
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+#[cfg(feature = "arithmetic")]
+mod arithmetic;
+
+#[cfg(feature = "dsa")]
+#[cfg_attr(docsrs, doc(cfg(feature = "dsa")))]
+pub mod dsa;
+
+#[cfg(feature = "sm3")]
+mod hash;
+
+#[cfg(feature = "kx")]
+#[cfg_attr(docsrs, doc(cfg(feature = "kx")))]
+pub mod kx;
+
+#[cfg(feature = "pke")]
+#[cfg_attr(docsrs, doc(cfg(feature = "pke")))]
+pub mod pke;
+
+#[cfg(any(feature = "test-vectors", test))]
+#[cfg_attr(docsrs, doc(cfg(feature = "test-vectors")))]
+pub mod test_vectors;
+
+pub use elliptic_curve::{self, bigint::U256};
+
+#[cfg(feature = "arithmetic")]
+pub use arithmetic::{
+    affine::AffinePoint,
+    projective::ProjectivePoint,
+    scalar::{blinded::BlindedScalar, Scalar},
+};
+
+#[cfg(feature = "pkcs8")]
+#[cfg_attr(docsrs, doc(cfg(feature = "pkcs8")))]
+pub use elliptic_curve::pkcs8;
+
+use elliptic_curve::{consts::U33, generic_array::GenericArray};
+
+/// SM2 elliptic curve, as specified in GB/T 32918.5-2017.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, PartialOrd, Ord)]
+pub struct Sm2;
+
+impl elliptic_curve::Curve for Sm2 {
+    type UInt = U256;
+
+    const ORDER: U256 =
+        U256::from_be_hex("fffffffeffffffffffffffffffffffff7203df6b21c6052b53bbf40939d54123");
+}
+
+impl elliptic_curve::PrimeCurve for Sm2 {}
+
+impl elliptic_curve::PointCompression for Sm2 {
+    const COMPRESS_POINTS: bool = false;
+}
+
+impl elliptic_curve::PointCompaction for Sm2 {
+    const COMPACT_POINTS: bool = false;
+}
+
+#[cfg(feature = "pkcs8")]
+impl elliptic_curve::AlgorithmParameters for Sm2 {
+    const OID: pkcs8::ObjectIdentifier = pkcs8::ObjectIdentifier::new("1.2.156.10197.1.301");
+}
+
+pub type CompressedPoint = GenericArray<u8, U33>;
+
+pub type FieldBytes = elliptic_curve::FieldBytes<Sm2>;
+
+pub type EncodedPoint = elliptic_curve::sec1::EncodedPoint<Sm2>;
+
+#[cfg(feature = "arithmetic")]
+pub type NonZeroScalar = elliptic_curve::NonZeroScalar<Sm2>;
+
+#[cfg(feature = "arithmetic")]
+pub type PublicKey = elliptic_curve::PublicKey<Sm2>;
+
+pub type SecretKey = elliptic_curve::SecretKey<Sm2>;
+
+#[cfg(not(feature = "arithmetic"))]
+impl elliptic_curve::sec1::ValidatePublicKey for Sm2 {}
+
+#[cfg(feature = "bits")]
+#[cfg_attr(docsrs, doc(cfg(feature = "bits")))]
+pub type ScalarBits = elliptic_curve::ScalarBits<Sm2>;
@@ -0,0 +1,257 @@
+//! SM2 public key encryption (GB/T 32918.4).
+//!
+//! Ciphertexts are `C1 || C3 || C2` by default, where `C1` is the
+//! uncompressed ephemeral point, `C3` the SM3 checksum and `C2` the
+//! masked message. The `C1 || C2 || C3` order of the 2010 draft is
+//! available through [`Mode::C1C2C3`].
+
+use crate::{
+    hash::kdf, AffinePoint, EncodedPoint, NonZeroScalar, ProjectivePoint, PublicKey, SecretKey,
+};
+use alloc::{vec, vec::Vec};
+use elliptic_curve::{
+    rand_core::{CryptoRng, RngCore},
+    sec1::{FromEncodedPoint, ToEncodedPoint},
+    subtle::ConstantTimeEq,
+    zeroize::Zeroize,
+    Error, Result,
+};
+use sm3::{Digest, Sm3};
+
+const POINT_LEN: usize = 65;
+const HASH_LEN: usize = 32;
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Mode {
+    C1C2C3,
+    C1C3C2,
+}
+
+impl Default for Mode {
+    fn default() -> Self {
+        Mode::C1C3C2
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct EncryptingKey {
+    public_key: PublicKey,
+    mode: Mode,
+}
+
+impl EncryptingKey {
+    pub fn new(public_key: PublicKey) -> Self {
+        Self::new_with_mode(public_key, Mode::default())
+    }
+
+    pub fn new_with_mode(public_key: PublicKey, mode: Mode) -> Self {
+        Self { public_key, mode }
+    }
+
+    pub fn from_sec1_bytes(bytes: &[u8]) -> Result<Self> {
+        PublicKey::from_sec1_bytes(bytes).map(Self::new)
+    }
+
+    pub fn public_key(&self) -> &PublicKey {
+        &self.public_key
+    }
+
+    pub fn mode(&self) -> Mode {
+        self.mode
+    }
+
+    pub fn encrypt(&self, mut rng: impl CryptoRng + RngCore, msg: &[u8]) -> Vec<u8> {
+        loop {
+            let k = NonZeroScalar::random(&mut rng);
+            if let Some(ciphertext) = self.encrypt_with_k(&k, msg) {
+                return ciphertext;
+            }
+        }
+    }
+
+    /// Encrypt `msg` using the ephemeral scalar `k`, returning `None` if the
+    /// derived key stream is all zeros and `k` has to be rejected.
+    pub(crate) fn encrypt_with_k(&self, k: &NonZeroScalar, msg: &[u8]) -> Option<Vec<u8>> {
+        let c1 = (ProjectivePoint::GENERATOR * **k)
+            .to_affine()
+            .to_encoded_point(false);
+        let shared = (self.public_key.to_projective() * **k)
+            .to_affine()
+            .to_encoded_point(false);
+
+        let mut c2 = mask(&shared, msg)?;
+        let c3 = checksum(&shared, msg);
+
+        let mut ciphertext = Vec::with_capacity(POINT_LEN + HASH_LEN + msg.len());
+        ciphertext.extend_from_slice(c1.as_bytes());
+        match self.mode {
+            Mode::C1C2C3 => {
+                ciphertext.extend_from_slice(&c2);
+                ciphertext.extend_from_slice(&c3);
+            }
+            Mode::C1C3C2 => {
+                ciphertext.extend_from_slice(&c3);
+                ciphertext.extend_from_slice(&c2);
+            }
+        }
+        c2.zeroize();
+        Some(ciphertext)
+    }
+}
+
+#[derive(Clone)]
+pub struct DecryptingKey {
+    secret_scalar: NonZeroScalar,
+    encrypting_key: EncryptingKey,
+}
+
+impl DecryptingKey {
+    pub fn new(secret_key: &SecretKey) -> Self {
+        Self::new_with_mode(secret_key, Mode::default())
+    }
+
+    pub fn new_with_mode(secret_key: &SecretKey, mode: Mode) -> Self {
+        Self::from_nonzero_scalar(secret_key.to_nonzero_scalar(), mode)
+    }
+
+    pub fn from_nonzero_scalar(secret_scalar: NonZeroScalar, mode: Mode) -> Self {
+        let public_key = PublicKey::from_secret_scalar(&secret_scalar);
+        Self {
+            secret_scalar,
+            encrypting_key: EncryptingKey::new_with_mode(public_key, mode),
+        }
+    }
+
+    pub fn encrypting_key(&self) -> &EncryptingKey {
+        &self.encrypting_key
+    }
+
+    pub fn decrypt(&self, ciphertext: &[u8]) -> Result<Vec<u8>> {
+        if ciphertext.len() < POINT_LEN + HASH_LEN || ciphertext[0] != 0x04 {
+            return Err(Error);
+        }
+
+        let (c1, rest) = ciphertext.split_at(POINT_LEN);
+        let (c2, c3) = match self.encrypting_key.mode {
+            Mode::C1C2C3 => {
+                let (c2, c3) = rest.split_at(rest.len() - HASH_LEN);
+                (c2, c3)
+            }
+            Mode::C1C3C2 => {
+                let (c3, c2) = rest.split_at(HASH_LEN);
+                (c2, c3)
+            }
+        };
+
+        let c1 = EncodedPoint::from_bytes(c1).map_err(|_| Error)?;
+        let c1 = Option::<AffinePoint>::from(AffinePoint::from_encoded_point(&c1)).ok_or(Error)?;
+        let shared = (ProjectivePoint::from(c1) * *self.secret_scalar)
+            .to_affine()
+            .to_encoded_point(false);
+
+        let mut msg = mask(&shared, c2).ok_or(Error)?;
+        if bool::from(checksum(&shared, &msg).ct_eq(c3)) {
+            Ok(msg)
+        } else {
+            msg.zeroize();
+            Err(Error)
+        }
+    }
+}
+
+impl From<&DecryptingKey> for EncryptingKey {
+    fn from(decrypting_key: &DecryptingKey) -> EncryptingKey {
+        decrypting_key.encrypting_key.clone()
+    }
+}
+
+impl Drop for DecryptingKey {
+    fn drop(&mut self) {
+        self.secret_scalar.zeroize();
+    }
+}
+
+/// XOR `data` with `KDF(x2 || y2)`, rejecting an all-zero key stream.
+fn mask(shared: &EncodedPoint, data: &[u8]) -> Option<Vec<u8>> {
+    let mut out = vec![0; data.len()];
+    kdf(&[&shared.as_bytes()[1..]], &mut out);
+    if !data.is_empty() && out.iter().all(|&b| b == 0) {
+        return None;
+    }
+
+    for (o, d) in out.iter_mut().zip(data) {
+        *o ^= d;
+    }
+    Some(out)
+}
+
+fn checksum(shared: &EncodedPoint, msg: &[u8]) -> [u8; HASH_LEN] {
+    Sm3::new()
+        .chain_update(shared.x().unwrap())
+        .chain_update(msg)
+        .chain_update(shared.y().unwrap())
+        .finalize()
+        .into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{DecryptingKey, EncryptingKey, Mode};
+    use crate::{NonZeroScalar, SecretKey};
+    use elliptic_curve::rand_core::OsRng;
+    use hex_literal::hex;
+
+    const SECRET: [u8; 32] =
+        hex!("3945208F7B2144B13F36E38AC6D39F95889393692860B51A42FB81EF4DF7C5B8");
+
+    const K: [u8; 32] = hex!("59276E27D506861A16680F3AD9C02DCCEF3CC1FA3CDBE4CE6D54B80DEAC1BC21");
+
+    const MSG: &[u8] = b"encryption standard";
+
+    const C1: [u8; 65] = hex!(
+        "0404EBFC718E8D1798620432268E77FEB6415E2EDE0E073C0F4F640ECD2E149A73
+             E858F9D81E5430A57B36DAAB8F950A3C64E6EE6A63094D99283AFF767E124DF0"
+    );
+
+    const C2: [u8; 19] = hex!("21886CA989CA9C7D58087307CA93092D651EFA");
+
+    const C3: [u8; 32] = hex!("59983C18F809E262923C53AEC295D30383B54E39D609D160AFCB1908D0BD8766");
+
+    #[test]
+    fn gbt_32918_vector() {
+        let secret_key = SecretKey::from_be_bytes(&SECRET).unwrap();
+        let k = NonZeroScalar::from_repr(K.into()).unwrap();
+
+        for (mode, expected) in [
+            (Mode::C1C3C2, [&C1[..], &C3, &C2].concat()),
+            (Mode::C1C2C3, [&C1[..], &C2, &C3].concat()),
+        ] {
+            let decrypting_key = DecryptingKey::new_with_mode(&secret_key, mode);
+            let encrypting_key = EncryptingKey::from(&decrypting_key);
+
+            let ciphertext = encrypting_key.encrypt_with_k(&k, MSG).unwrap();
+            assert_eq!(ciphertext, expected);
+            assert_eq!(decrypting_key.decrypt(&ciphertext).unwrap(), MSG);
+        }
+    }
+
+    #[test]
+    fn encrypt_decrypt() {
+        let secret_key = SecretKey::random(&mut OsRng);
+        let decrypting_key = DecryptingKey::new(&secret_key);
+        let encrypting_key = EncryptingKey::new(secret_key.public_key());
+
+        for len in [0, 1, 31, 32, 33, 100] {
+            let msg = vec![0x5a; len];
+            let mut ciphertext = encrypting_key.encrypt(&mut OsRng, &msg);
+            assert_eq!(ciphertext.len(), 65 + 32 + len);
+            assert_eq!(decrypting_key.decrypt(&ciphertext).unwrap(), msg);
+
+            let last = ciphertext.len() - 1;
+            ciphertext[last] ^= 1;
+            assert!(decrypting_key.decrypt(&ciphertext).is_err());
+        }
+
+        assert!(decrypting_key.decrypt(&[0x04; 96]).is_err());
+    }
+}
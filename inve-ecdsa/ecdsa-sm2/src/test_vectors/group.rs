@@ -0,0 +1,247 @@
+use hex_literal::hex;
+
+pub const ADD_TEST_VECTORS: &[([u8; 32], [u8; 32])] = &[
+    (
+        hex!("32C4AE2C1F1981195F9904466A39C9948FE30BBFF2660BE1715A4589334C74C7"),
+        hex!("BC3736A2F4F6779C59BDCEE36B692153D0A9877CC62A474002DF32E52139F0A0"),
+    ),
+    (
+        hex!("56CEFD60D7C87C000D58EF57FA73BA4D9C0DFA08C08A7331495C2E1DA3F2BD52"),
+        hex!("31B7E7E6CC8189F668535CE0F8EAF1BD6DE84C182F6C8E716F780D3A970A23C3"),
+    ),
+    (
+        hex!("A97F7CD4B3C993B4BE2DAA8CDB41E24CA13F6BD945302244E26918F1D0509EBF"),
+        hex!("530B5DD88C688EF5CCC5CEC08A72150F7C400EE5CD045292AAACDD037458F6E6"),
+    ),
+    (
+        hex!("C239507105C683242A81052FF641ED69009A084AD5CC937DB21646CD34A0CED5"),
+        hex!("B1BF7EC4080F3C8735F1294AC0DB19686BEE2E96AB8C71FB7A253666CB66E009"),
+    ),
+    (
+        hex!("C749061668652E26040E008FDD5EB77A344A417B7FCE19DBA575DA57CC372A9E"),
+        hex!("F2DF5DB2D144E9454504C622B51CF38F5006206EB579FF7DA6976EFF5FBE6480"),
+    ),
+    (
+        hex!("0927AFB57D93483BBB17C93E71F22A3105FF8856A66016892C8B1A1A3C4B0D30"),
+        hex!("150C6B1AB4D1FC7EAC1C0EF6EBF2664581ADF1F0855A064DD572103000088F63"),
+    ),
+    (
+        hex!("DDF092555409C19DFDBE86A75C139906A80198337744EE78CD27E384D9FCAF15"),
+        hex!("847D18FFB38E87065CD6B6E9C12D2922037937707D6A49A2223B949657E52BC1"),
+    ),
+    (
+        hex!("B9C3FAEB4B1610713DB4333D4E860E64D4EA35D60C1C29BB675D822DED0BB916"),
+        hex!("C519B309ECF7269C2491D2DE9ACCF2BE0366A8A03024B3E03C286DA2CFD31A3E"),
+    ),
+    (
+        hex!("A27233F3A59595080B4A2444A46A74C5FE8D59CB43619E4F173472A58CCA247E"),
+        hex!("379E72F63722C924768F7689B210F45FC3A8433140D1EBCA85227940922C02E9"),
+    ),
+    (
+        hex!("D3F94862519621C121666061F65C3E32B2D0D065CD219E3284A04814DB522756"),
+        hex!("4B9030CF676F6A742EBD57D146DCA428F6B743F64D1482D147D46FB2BAB82A14"),
+    ),
+    (
+        hex!("04B3CB10C9C6D8E27C1AAB770F67F543125DCDD589C2FF82668C74D78CE20ACE"),
+        hex!("63516355287E39FE4918E5C02E2B0B930C94816E63C4BC72739A8FD805174A4B"),
+    ),
+    (
+        hex!("BFC2DF6BB17F971AD89058CA059BE46E5F7B516C5BDADDAD9C9F042D0279270A"),
+        hex!("B145513F59636106CEF696B67939BD7B73C9FC6FD150AAAAB5FBF87B64AF3C26"),
+    ),
+    (
+        hex!("952072D6FF9C65BDFA804513275F58AA7BEA65C6421E189E2B834F1D509B9CD0"),
+        hex!("E6BB9804458BB70F1A473F8D9748F23858D1434AE934BA7503891E105E009B00"),
+    ),
+    (
+        hex!("83B4A4DE96A4D70F4AAF81826982D748EF22EA28BE9D44DE0A44248A36BB0A07"),
+        hex!("E481C0D9EE8A98D4EEB6D6C6D7E74F8E3E707C8A438529492E663CD4373A2F24"),
+    ),
+    (
+        hex!("F73B839F13912C1A3291676C38D393243B424F35F0ECCE4C461B1BBCB80F829C"),
+        hex!("32EC7722695DC7CF5EE9FAB985C12455DC2E788FB170AA144C3533771DB0955E"),
+    ),
+    (
+        hex!("35648233F554AE51BBCE44EF5DB3E419EA133CD248A93E2555645BBC8704FB68"),
+        hex!("04D7AC60F6D975EF6117BCA9CE885DD6154B1870A6A651664411A9A30ECA2046"),
+    ),
+    (
+        hex!("DD18AA4AEC26EAC41C993F01115C57F7BF5113AB85B5ED4436969F8B77125E6A"),
+        hex!("161E5851969DA8223361493F76FAC50F6CCE6001D7C0B853E0180D54D4AE2221"),
+    ),
+    (
+        hex!("69FDF8A436FD8E5D221EA8657781CE71A3448557A24554DB427E33AE8ED9C317"),
+        hex!("BAA856D1CAD68AF36B0A9658B33B347BEB7B1AD657C155F571E35709341FDCAB"),
+    ),
+    (
+        hex!("A68B2EC49D1921D0E2E0586BB5DC9419F7C96C55940A47261323F252E493952A"),
+        hex!("96F361A23B3DEB99933EF442FCA7A734B95F2DE69200269EFD4520A63F5CC585"),
+    ),
+    (
+        hex!("08314765E44847EB7575E3CB2FA9092DABBB1EA1417F958D0588FDD88ABDEE94"),
+        hex!("DF922344FA592A986B443AB4360953787DE3A53937E8A75F5EEB0E99C2EB61E9"),
+    ),
+];
+
+pub const MUL_TEST_VECTORS: &[([u8; 32], [u8; 32], [u8; 32])] = &[
+    (
+        hex!("000000000000000000000000000000000000000000000000018EBBB95EED0E13"),
+        hex!("046E082A9DB1E461594E9249A0BB98343C84E67097F3404DA9CFFB2ABDA885C6"),
+        hex!("533A16D93B5AFF9A7BB4E45CC5C353EF0B96551B4CC124564E3C8FCB1193BC63"),
+    ),
+    (
+        hex!("0000000000000000000000000000000000159D893D4CDD747246CDCA43590E13"),
+        hex!("B57A7986550CA2BC6E55277E9C820633C848C9B03678062144A77F1BC22C4CDC"),
+        hex!("787F3C823827336AD593504C959378C5012381B6EAA2FD0B6C9714F7097BEA60"),
+    ),
+    (
+        hex!("41FFC1FFFFFE01FFFC0003FFFE0007C001FFF00003FFF07FFE0007C000000003"),
+        hex!("E2A6C0B1566F7696CE86B056560D924BEB78B89AF7AEBD103370BBFD24E48C7B"),
+        hex!("B145CCC1C14D1B7A320F407C66622E26242801A5559513FAA9765ADDEC761AF3"),
+    ),
+    (
+        hex!("7FFFFFC03FFFC003FFFFFC007FFF00000000070000100000000E00FFFFFFF3FF"),
+        hex!("0C9B2EBD8B8CDB65CC8B7D42D36C00D940B1180ABD94D74C5116C26C4D5CFEF7"),
+        hex!("AAE8FF8EE619123A3BBB4234B23DAE00954D22BDE18FA6AB61319EC0E1743862"),
+    ),
+    (
+        hex!("0000FFFFF01FFFF8FFFFC00FFFFFFFFFC000000FFFFFC007FFFFFC000FFFE3FF"),
+        hex!("131983AD77FDA958C4C64C7412FC1DB33CAD2922CF18282885DC9A2FCDC81636"),
+        hex!("6CCC338105C4A8CE305C637364DE9B4B19A52AC3B8AC321F031B2B5594DAEED6"),
+    ),
+    (
+        hex!("4000008000FFFFFC000003F00000FFFFFFFF800003800F8000E0000E000000FF"),
+        hex!("D55BCBD818DAAD165E7672494EA05CA728BABB7942C14CF00BBB09DFED0FD065"),
+        hex!("DF13B47F1CB37AF2CC4A099FBB0C2165FC6A905402A261580880B5EF13E51156"),
+    ),
+    (
+        hex!("003FFFFFF0001F80000003F80003FFFFC0000000000FFE0000007FF818000F80"),
+        hex!("B364011879EEAB0CB5D61685DB253B959F6F54EB2C6732A8651A54856FC56752"),
+        hex!("FC4C5462D5D289E4509A136ED0DEF746C94F7DCE576DF11F218847B730433C0A"),
+    ),
+    (
+        hex!("000001C000000000001001F803FFFFFF80000000000007FF0000000000000000"),
+        hex!("01BE93F6F640B1544C213F7931CA0481FE1FF9E10CE18E8E9E9F9A1EB0EC53BB"),
+        hex!("7B2549FF4E3D0660A77765A24771FE6DF16F7CD24CB132693E322D73C3DEB0DF"),
+    ),
+    (
+        hex!("7FC0007FFFFFFC0003FFFFFFFFFFFFFE00003FFFFF07FFFFFFFFFFFFC007FFFF"),
+        hex!("070342090A5FD6926A8D1458122B2A505B5BD8A52AEFE45AB742E72D5D2A03A4"),
+        hex!("61B4B3326ADECAEEAB3011B7C8C4C2D4593CD28435277E4C152A5522850E3835"),
+    ),
+    (
+        hex!("7FFFFC03FF807FFFE0001FFFFF800FFF800001FFFF0001FFFFFE001FFFC00000"),
+        hex!("451287927BF2383D9135C0CAE27C79F52C83850C62EED15853D4B0F204B85816"),
+        hex!("BE3BF74A56DF473919AAC7401A96222A6E495BB314F061B27EB222FF60B5A2A3"),
+    ),
+    (
+        hex!("00FFFFFFFE03FFFC07FFFC800070000FC0007FFC00000000000FFFE1FBFF81FF"),
+        hex!("2F7569FE2FB5D2C0C60F3978EC871FDCDA998B6681063FEE2091DCFB97D27A0E"),
+        hex!("57DB6CDE46557D569F2D0E74BDD0258F03549A8C2CEBE4F65D7ED286C77BAAEB"),
+    ),
+    (
+        hex!("01FFF81FC000000000FF801FFFC0F81F01FFF8001FC005FFFFFF800000FFFFFC"),
+        hex!("8B6218C9246039C5D7683F18C18D8E4FAF6FF0740EC09CC5B59F68B97981AFDA"),
+        hex!("83A72C1E37FC7D09B4620FA34CE21E61352015B2181406E8ED867813CBA0A860"),
+    ),
+    (
+        hex!("000000000000000100000000000000004AE31B42855199599FFDD6B9C28DE41A"),
+        hex!("B5D41BDA1994842D17C9D74F1A4B1C63D0D78AB89BB9A4AEE01B922F7BDA9B24"),
+        hex!("FA9EFE1B781CEB8575D2CABF30B87E20BC0C2CE2DCC264EE53613F0FA6373BCA"),
+    ),
+    (
+        hex!("000000000000000100000000000000004AE31B42855199599FFDD6B9C28DE41B"),
+        hex!("236D00376C32FBABBF22F234BF5AAC7702BC418F42A140D6FE232D2514AFCEE5"),
+        hex!("4E6D8FEDC860AAC419AA038A9BD7D83A60A1244A5BE6CCF57D1F87BDEFDA5CF7"),
+    ),
+    (
+        hex!("000000000000000100000000000000004AE31B42855199599FFDD6B9C28DE41C"),
+        hex!("BEE6044BA0964BAFA953EAFB5C4BF246E00328A92382D96A1B357C90EE7B662C"),
+        hex!("2A567041CBBFE989DF2B2E22372FDB3CB2CADB8E491EC8A390BA4262ADFB0102"),
+    ),
+    (
+        hex!("000000000000000100000000000000004AE31B42855199599FFDD6B9C28DE41D"),
+        hex!("FC479F57CC2B57BBD221C7630CE0D4504B3E9C4DA75DA1BB1FB6A082A99AECF4"),
+        hex!("5A83E0C85A3107436A38C3BB141E085FDDA251691600EF0B47E8A45CD09530F1"),
+    ),
+    (
+        hex!("000000000000000100000000000000004AE31B42855199599FFDD6B9C28DE41E"),
+        hex!("5DBFFE3C5DF1F6B4438827875DA47ABE3E259BBFAAF3B59D2B60F02B82742A52"),
+        hex!("0C87CC038F94A59757FE1E905F1A5200D0564A5A963465ED8FE148F4C8CB3A28"),
+    ),
+    (
+        hex!("000000000000000100000000000000004AE31B42855199599FFDD6B9C28DE41F"),
+        hex!("C5CC6650A0F46FA1FF6B791E88C2A51D2E315C1D4172D00C2CABE2E68402B820"),
+        hex!("B3278643373FB032C283E9F63C3B68BA25896A383A7628E88D05760946CE2478"),
+    ),
+    (
+        hex!("000000000000000100000000000000004AE31B42855199599FFDD6B9C28DE420"),
+        hex!("90B56EFC9B12C3E2E31E09864AC350F7EEFD9F17C6D360E0C685D2C84A292672"),
+        hex!("AD653F929F11A162A82AB9E94E9B2EA48639DF8815CCBCD53FD155FAE3116CD2"),
+    ),
+    (
+        hex!("000000000000000100000000000000004AE31B42855199599FFDD6B9C28DE421"),
+        hex!("F0477593077C19CB2BEE6E586C8D9FDC589E0FC9745FA15F5BB8EFB2AC1118C1"),
+        hex!("6ADEC1DF0BF1BFEF083B798C8B37BBD7981A7579188B7581CCF59B82B1F4D7E6"),
+    ),
+    (
+        hex!("000000000000000100000000000000004AE31B42855199599FFDD6B9C28DE422"),
+        hex!("1B48140B0CC58553BFA12A2F3AD97AE6CC968A13A68B972A15B605AEF0E34DD4"),
+        hex!("6A30780096FAA37A564AF5EDD1D109B5D0CD0616D8483B6B4B59B03FFFCB3DFE"),
+    ),
+    (
+        hex!("000000000000000100000000000000004AE31B42855199599FFDD6B9C28DE423"),
+        hex!("DB4A1FA138074DF150CAD81399B61ECC7622F8210420E14B1448008D40742DF0"),
+        hex!("451762512C756D8C6C6FF7381F6C130E91B54693A5C73C99978346A63A5C9963"),
+    ),
+    (
+        hex!("000000000000000100000000000000004AE31B42855199599FFDD6B9C28DE424"),
+        hex!("5951E7764220677E3EB1044040B21D15BA11255CC408DF231FA19DB9E1355C78"),
+        hex!("00981F6B2C162CE3978E5D40E3EEE4140632C989A3807903DC65D728C9DA3AEC"),
+    ),
+    (
+        hex!("000000000000000100000000000000004AE31B42855199599FFDD6B9C28DE425"),
+        hex!("BCF81FB66A71EDC9D9E1EEB1DEA47695A06A10D45D3D3407E87072820FA83AC2"),
+        hex!("96ABDB63ACFB875430E9B9D3B69E99F7F8EF7D09D031D86246526551D13A722F"),
+    ),
+    (
+        hex!("000000000000000100000000000000004AE31B42855199599FFDD6B9C28DE426"),
+        hex!("BA1C75371CED0E1FADEDD0D0AE42788085E6652EF4DE2DD9A43868E86A9982F8"),
+        hex!("8AC61E271CFB028C096C652CDAE55EE4D0C91A60559EB93046FC50278EE4651D"),
+    ),
+    (
+        hex!("000000000000000100000000000000004AE31B42855199599FFDD6B9C28DE427"),
+        hex!("3819CC989E97E11C7FDC1238BAC18E60B0CCAEBBBA131FCFE2E12EB3B1E328E4"),
+        hex!("C973D934C2D6E7E0E1E297FD818620895CB8E8E5FFF70F04F4FC2C418B712AEF"),
+    ),
+    (
+        hex!("000000000000000100000000000000004AE31B42855199599FFDD6B9C28DE428"),
+        hex!("BE7BA95F9CD2099E5C3D7C3829A0EE8E977F33DD002CACC2529E6F639312AEB6"),
+        hex!("2DB4C89DE351ABEE2AC2335E09808549547DB0142C39480BE8D2F583FF5E8828"),
+    ),
+    (
+        hex!("000000000000000100000000000000004AE31B42855199599FFDD6B9C28DE429"),
+        hex!("8E908D3F64C5F52A043CDF8D7ECAA9FFC31D84D393DD20C6077E648BC2281F04"),
+        hex!("C2AF12C33EE68740DAEDA598B1A6D1D3A494B1F21967D6BE5C0E3F3DA4372337"),
+    ),
+    (
+        hex!("000000000000000100000000000000004AE31B42855199599FFDD6B9C28DE42A"),
+        hex!("1315561EC7F799B4120FFD61804A9A7AF6834DC55F94EB1E57C6A207F78043CB"),
+        hex!("EE180BCC7284DAC4D013925E5813813E27C5F5C9CC749E9FC2F0DAF34106557A"),
+    ),
+    (
+        hex!("000000000000000100000000000000004AE31B42855199599FFDD6B9C28DE42B"),
+        hex!("4E61DEB53103B8E5528436289C7BF4D1FE9D35FE8E43B57156C169571EF2D4CC"),
+        hex!("967C02513F6820556ABABF775917914BE372C3A112EE4FEA8869B8105D130560"),
+    ),
+    (
+        hex!("000000000000000100000000000000004AE31B42855199599FFDD6B9C28DE42C"),
+        hex!("76622AE6BFFADDA880A4CEF09D02B10D3B0CE0AD7AB5858AEB4B2C9A7D2DFEBF"),
+        hex!("BAB4F56054CDDDFD95EB399A51E1FB13B2B7069C42EAEE8362891B0EE21759D6"),
+    ),
+    (
+        hex!("000000000000000100000000000000004AE31B42855199599FFDD6B9C28DE42D"),
+        hex!("53D4B8A8D201169420057DADD9B928CAD289032329E95FC8DB01203595388142"),
+        hex!("11F768A0D637DE6C77075FE9F2411F7C4420AAAA44230156E8814C92ADD15915"),
+    ),
+];
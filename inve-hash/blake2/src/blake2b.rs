@@ -0,0 +1,13 @@
+cfg_if::cfg_if! {
+    if #[cfg(feature = "force-soft")] {
+        mod soft;
+        pub(crate) use soft::compress;
+    } else if #[cfg(any(target_arch = "x86", target_arch = "x86_64"))] {
+        mod soft;
+        mod x86;
+        pub(crate) use x86::compress;
+    } else {
+        mod soft;
+        pub(crate) use soft::compress;
+    }
+}
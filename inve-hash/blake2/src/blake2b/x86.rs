@@ -0,0 +1,215 @@
+#[cfg(target_arch = "x86")]
+use core::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use core::arch::x86_64::*;
+
+use crate::consts::{BLAKE2B_IV, SIGMA};
+
+#[inline(always)]
+fn load_msg(block: &[u8; 128]) -> [i64; 16] {
+    let mut m = [0i64; 16];
+    for (w, chunk) in m.iter_mut().zip(block.chunks_exact(8)) {
+        *w = i64::from_le_bytes(chunk.try_into().unwrap());
+    }
+    m
+}
+
+macro_rules! g_avx2 {
+    ($a:ident, $b:ident, $c:ident, $d:ident, $mx:expr, $my:expr) => {{
+        let rot24 = _mm256_setr_epi8(
+            3, 4, 5, 6, 7, 0, 1, 2, 11, 12, 13, 14, 15, 8, 9, 10, 3, 4, 5, 6, 7, 0, 1, 2, 11, 12,
+            13, 14, 15, 8, 9, 10,
+        );
+        let rot16 = _mm256_setr_epi8(
+            2, 3, 4, 5, 6, 7, 0, 1, 10, 11, 12, 13, 14, 15, 8, 9, 2, 3, 4, 5, 6, 7, 0, 1, 10, 11,
+            12, 13, 14, 15, 8, 9,
+        );
+
+        $a = _mm256_add_epi64(_mm256_add_epi64($a, $b), $mx);
+        $d = _mm256_shuffle_epi32(_mm256_xor_si256($d, $a), 0xB1);
+        $c = _mm256_add_epi64($c, $d);
+        $b = _mm256_shuffle_epi8(_mm256_xor_si256($b, $c), rot24);
+        $a = _mm256_add_epi64(_mm256_add_epi64($a, $b), $my);
+        $d = _mm256_shuffle_epi8(_mm256_xor_si256($d, $a), rot16);
+        $c = _mm256_add_epi64($c, $d);
+        $b = _mm256_xor_si256($b, $c);
+        $b = _mm256_or_si256(_mm256_srli_epi64($b, 63), _mm256_add_epi64($b, $b));
+    }};
+}
+
+#[target_feature(enable = "avx2")]
+unsafe fn compress_avx2(h: &mut [u64; 8], block: &[u8; 128], t: [u64; 2], f: [u64; 2]) {
+    let m = load_msg(block);
+    let h_ptr = h.as_mut_ptr() as *mut __m256i;
+    let iv_ptr = BLAKE2B_IV.as_ptr() as *const __m256i;
+
+    let mut a = _mm256_loadu_si256(h_ptr);
+    let mut b = _mm256_loadu_si256(h_ptr.add(1));
+    let mut c = _mm256_loadu_si256(iv_ptr);
+    let mut d = _mm256_xor_si256(
+        _mm256_loadu_si256(iv_ptr.add(1)),
+        _mm256_set_epi64x(f[1] as i64, f[0] as i64, t[1] as i64, t[0] as i64),
+    );
+
+    for s in SIGMA.iter() {
+        let mx = _mm256_set_epi64x(m[s[6]], m[s[4]], m[s[2]], m[s[0]]);
+        let my = _mm256_set_epi64x(m[s[7]], m[s[5]], m[s[3]], m[s[1]]);
+        g_avx2!(a, b, c, d, mx, my);
+
+        b = _mm256_permute4x64_epi64(b, 0x39);
+        c = _mm256_permute4x64_epi64(c, 0x4E);
+        d = _mm256_permute4x64_epi64(d, 0x93);
+
+        let mx = _mm256_set_epi64x(m[s[14]], m[s[12]], m[s[10]], m[s[8]]);
+        let my = _mm256_set_epi64x(m[s[15]], m[s[13]], m[s[11]], m[s[9]]);
+        g_avx2!(a, b, c, d, mx, my);
+
+        b = _mm256_permute4x64_epi64(b, 0x93);
+        c = _mm256_permute4x64_epi64(c, 0x4E);
+        d = _mm256_permute4x64_epi64(d, 0x39);
+    }
+
+    let lo = _mm256_xor_si256(_mm256_loadu_si256(h_ptr), _mm256_xor_si256(a, c));
+    let hi = _mm256_xor_si256(_mm256_loadu_si256(h_ptr.add(1)), _mm256_xor_si256(b, d));
+    _mm256_storeu_si256(h_ptr, lo);
+    _mm256_storeu_si256(h_ptr.add(1), hi);
+}
+
+macro_rules! g_sse41 {
+    ($a:ident, $b:ident, $c:ident, $d:ident, $mx:expr, $my:expr) => {{
+        let rot24 = _mm_setr_epi8(3, 4, 5, 6, 7, 0, 1, 2, 11, 12, 13, 14, 15, 8, 9, 10);
+        let rot16 = _mm_setr_epi8(2, 3, 4, 5, 6, 7, 0, 1, 10, 11, 12, 13, 14, 15, 8, 9);
+
+        $a = _mm_add_epi64(_mm_add_epi64($a, $b), $mx);
+        $d = _mm_shuffle_epi32(_mm_xor_si128($d, $a), 0xB1);
+        $c = _mm_add_epi64($c, $d);
+        $b = _mm_shuffle_epi8(_mm_xor_si128($b, $c), rot24);
+        $a = _mm_add_epi64(_mm_add_epi64($a, $b), $my);
+        $d = _mm_shuffle_epi8(_mm_xor_si128($d, $a), rot16);
+        $c = _mm_add_epi64($c, $d);
+        $b = _mm_xor_si128($b, $c);
+        $b = _mm_or_si128(_mm_srli_epi64($b, 63), _mm_add_epi64($b, $b));
+    }};
+}
+
+#[target_feature(enable = "sse2,ssse3,sse4.1")]
+unsafe fn compress_sse41(h: &mut [u64; 8], block: &[u8; 128], t: [u64; 2], f: [u64; 2]) {
+    let m = load_msg(block);
+    let h_ptr = h.as_mut_ptr() as *mut __m128i;
+    let iv_ptr = BLAKE2B_IV.as_ptr() as *const __m128i;
+
+    // Each row of the 4x4 state is split into a low and a high half.
+    let mut a0 = _mm_loadu_si128(h_ptr);
+    let mut a1 = _mm_loadu_si128(h_ptr.add(1));
+    let mut b0 = _mm_loadu_si128(h_ptr.add(2));
+    let mut b1 = _mm_loadu_si128(h_ptr.add(3));
+    let mut c0 = _mm_loadu_si128(iv_ptr);
+    let mut c1 = _mm_loadu_si128(iv_ptr.add(1));
+    let mut d0 = _mm_xor_si128(
+        _mm_loadu_si128(iv_ptr.add(2)),
+        _mm_set_epi64x(t[1] as i64, t[0] as i64),
+    );
+    let mut d1 = _mm_xor_si128(
+        _mm_loadu_si128(iv_ptr.add(3)),
+        _mm_set_epi64x(f[1] as i64, f[0] as i64),
+    );
+
+    for s in SIGMA.iter() {
+        g_sse41!(
+            a0,
+            b0,
+            c0,
+            d0,
+            _mm_set_epi64x(m[s[2]], m[s[0]]),
+            _mm_set_epi64x(m[s[3]], m[s[1]])
+        );
+        g_sse41!(
+            a1,
+            b1,
+            c1,
+            d1,
+            _mm_set_epi64x(m[s[6]], m[s[4]]),
+            _mm_set_epi64x(m[s[7]], m[s[5]])
+        );
+
+        let (t0, t1) = (b0, b1);
+        b0 = _mm_alignr_epi8(t1, t0, 8);
+        b1 = _mm_alignr_epi8(t0, t1, 8);
+        core::mem::swap(&mut c0, &mut c1);
+        let (t0, t1) = (d0, d1);
+        d0 = _mm_alignr_epi8(t0, t1, 8);
+        d1 = _mm_alignr_epi8(t1, t0, 8);
+
+        g_sse41!(
+            a0,
+            b0,
+            c0,
+            d0,
+            _mm_set_epi64x(m[s[10]], m[s[8]]),
+            _mm_set_epi64x(m[s[11]], m[s[9]])
+        );
+        g_sse41!(
+            a1,
+            b1,
+            c1,
+            d1,
+            _mm_set_epi64x(m[s[14]], m[s[12]]),
+            _mm_set_epi64x(m[s[15]], m[s[13]])
+        );
+
+        let (t0, t1) = (b0, b1);
+        b0 = _mm_alignr_epi8(t0, t1, 8);
+        b1 = _mm_alignr_epi8(t1, t0, 8);
+        core::mem::swap(&mut c0, &mut c1);
+        let (t0, t1) = (d0, d1);
+        d0 = _mm_alignr_epi8(t1, t0, 8);
+        d1 = _mm_alignr_epi8(t0, t1, 8);
+    }
+
+    let rows = [
+        _mm_xor_si128(a0, c0),
+        _mm_xor_si128(a1, c1),
+        _mm_xor_si128(b0, d0),
+        _mm_xor_si128(b1, d1),
+    ];
+    for (i, row) in rows.iter().enumerate() {
+        let p = h_ptr.add(i);
+        _mm_storeu_si128(p, _mm_xor_si128(_mm_loadu_si128(p), *row));
+    }
+}
+
+cpufeatures::new!(avx2_cpuid, "avx2");
+cpufeatures::new!(sse41_cpuid, "sse2", "ssse3", "sse4.1");
+
+pub fn compress(h: &mut [u64; 8], block: &[u8; 128], t: [u64; 2], f: [u64; 2]) {
+    if avx2_cpuid::get() {
+        unsafe { compress_avx2(h, block, t, f) }
+    } else if sse41_cpuid::get() {
+        unsafe { compress_sse41(h, block, t, f) }
+    } else {
+        super::soft::compress(h, block, t, f);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::check_compress;
+
+    const T: [u64; 2] = [0x0123_4567_89ab_cdef, 42];
+
+    #[test]
+    fn backends_match_soft() {
+        let soft = super::super::soft::compress;
+        if sse41_cpuid::get() {
+            check_compress(BLAKE2B_IV, T, u64::MAX, soft, |h, b, t, f| unsafe {
+                compress_sse41(h, b, t, f)
+            });
+        }
+        if avx2_cpuid::get() {
+            check_compress(BLAKE2B_IV, T, u64::MAX, soft, |h, b, t, f| unsafe {
+                compress_avx2(h, b, t, f)
+            });
+        }
+    }
+}
@@ -0,0 +1,108 @@
+#[cfg(target_arch = "x86")]
+use core::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use core::arch::x86_64::*;
+
+use crate::consts::{BLAKE2S_IV, SIGMA};
+
+macro_rules! g {
+    ($a:ident, $b:ident, $c:ident, $d:ident, $mx:expr, $my:expr) => {{
+        let rot16 = _mm_setr_epi8(2, 3, 0, 1, 6, 7, 4, 5, 10, 11, 8, 9, 14, 15, 12, 13);
+        let rot8 = _mm_setr_epi8(1, 2, 3, 0, 5, 6, 7, 4, 9, 10, 11, 8, 13, 14, 15, 12);
+
+        $a = _mm_add_epi32(_mm_add_epi32($a, $b), $mx);
+        $d = _mm_shuffle_epi8(_mm_xor_si128($d, $a), rot16);
+        $c = _mm_add_epi32($c, $d);
+        $b = _mm_xor_si128($b, $c);
+        $b = _mm_or_si128(_mm_srli_epi32($b, 12), _mm_slli_epi32($b, 20));
+        $a = _mm_add_epi32(_mm_add_epi32($a, $b), $my);
+        $d = _mm_shuffle_epi8(_mm_xor_si128($d, $a), rot8);
+        $c = _mm_add_epi32($c, $d);
+        $b = _mm_xor_si128($b, $c);
+        $b = _mm_or_si128(_mm_srli_epi32($b, 7), _mm_slli_epi32($b, 25));
+    }};
+}
+
+// A 4x32-bit row fits into a single XMM register, so wider vectors would
+// only pay off when hashing several independent messages at once.
+#[target_feature(enable = "sse2,ssse3,sse4.1")]
+unsafe fn compress_sse41(h: &mut [u32; 8], block: &[u8; 64], t: [u32; 2], f: [u32; 2]) {
+    let mut m = [0i32; 16];
+    for (w, chunk) in m.iter_mut().zip(block.chunks_exact(4)) {
+        *w = i32::from_le_bytes(chunk.try_into().unwrap());
+    }
+
+    let h_ptr = h.as_mut_ptr() as *mut __m128i;
+    let iv_ptr = BLAKE2S_IV.as_ptr() as *const __m128i;
+
+    let mut a = _mm_loadu_si128(h_ptr);
+    let mut b = _mm_loadu_si128(h_ptr.add(1));
+    let mut c = _mm_loadu_si128(iv_ptr);
+    let mut d = _mm_xor_si128(
+        _mm_loadu_si128(iv_ptr.add(1)),
+        _mm_set_epi32(f[1] as i32, f[0] as i32, t[1] as i32, t[0] as i32),
+    );
+
+    for s in SIGMA[..10].iter() {
+        g!(
+            a,
+            b,
+            c,
+            d,
+            _mm_set_epi32(m[s[6]], m[s[4]], m[s[2]], m[s[0]]),
+            _mm_set_epi32(m[s[7]], m[s[5]], m[s[3]], m[s[1]])
+        );
+
+        b = _mm_shuffle_epi32(b, 0x39);
+        c = _mm_shuffle_epi32(c, 0x4E);
+        d = _mm_shuffle_epi32(d, 0x93);
+
+        g!(
+            a,
+            b,
+            c,
+            d,
+            _mm_set_epi32(m[s[14]], m[s[12]], m[s[10]], m[s[8]]),
+            _mm_set_epi32(m[s[15]], m[s[13]], m[s[11]], m[s[9]])
+        );
+
+        b = _mm_shuffle_epi32(b, 0x93);
+        c = _mm_shuffle_epi32(c, 0x4E);
+        d = _mm_shuffle_epi32(d, 0x39);
+    }
+
+    let lo = _mm_xor_si128(_mm_loadu_si128(h_ptr), _mm_xor_si128(a, c));
+    let hi = _mm_xor_si128(_mm_loadu_si128(h_ptr.add(1)), _mm_xor_si128(b, d));
+    _mm_storeu_si128(h_ptr, lo);
+    _mm_storeu_si128(h_ptr.add(1), hi);
+}
+
+cpufeatures::new!(sse41_cpuid, "sse2", "ssse3", "sse4.1");
+
+pub fn compress(h: &mut [u32; 8], block: &[u8; 64], t: [u32; 2], f: [u32; 2]) {
+    if sse41_cpuid::get() {
+        unsafe { compress_sse41(h, block, t, f) }
+    } else {
+        super::soft::compress(h, block, t, f);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::check_compress;
+
+    #[test]
+    fn backends_match_soft() {
+        if sse41_cpuid::get() {
+            let soft = super::super::soft::compress;
+            check_compress(
+                BLAKE2S_IV,
+                [0x89ab_cdef, 42],
+                u32::MAX,
+                soft,
+                |h, b, t, f| unsafe { compress_sse41(h, b, t, f) },
+            );
+        }
+    }
+}
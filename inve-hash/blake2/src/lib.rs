@@ -0,0 +1,234 @@
+#[cfg(feature = "std")]
+extern crate std;
+
+pub use digest::{self, Digest};
+
+use core::{convert::TryInto, fmt, marker::PhantomData, ops::Div};
+use digest::{
+    block_buffer::{Lazy, LazyBuffer},
+    consts::{U128, U32, U4, U64},
+    core_api::{
+        AlgorithmName, Block, BlockSizeUser, Buffer, BufferKindUser, CoreWrapper,
+        CtVariableCoreWrapper, OutputSizeUser, RtVariableCoreWrapper, TruncSide, UpdateCore,
+        VariableOutputCore,
+    },
+    crypto_common::{InvalidLength, Key, KeyInit, KeySizeUser},
+    generic_array::{ArrayLength, GenericArray},
+    typenum::{IsLessOrEqual, LeEq, NonZero, Unsigned},
+    FixedOutput, HashMarker, InvalidOutputSize, MacMarker, Output, Update,
+};
+#[cfg(feature = "reset")]
+use digest::{FixedOutputReset, Reset};
+
+mod blake2b;
+mod blake2s;
+#[rustfmt::skip]
+mod consts;
+
+#[macro_use]
+mod macros;
+
+use consts::{BLAKE2B_IV, BLAKE2S_IV};
+
+blake2_impl!(
+    Blake2bVarCore,
+    "Blake2b",
+    u64,
+    U64,
+    U128,
+    BLAKE2B_IV,
+    blake2b::compress,
+);
+
+pub type Blake2bVar = RtVariableCoreWrapper<Blake2bVarCore>;
+pub type Blake2bCore<OutSize> = CtVariableCoreWrapper<Blake2bVarCore, OutSize>;
+pub type Blake2b<OutSize> = CoreWrapper<Blake2bCore<OutSize>>;
+pub type Blake2b512 = Blake2b<U64>;
+
+blake2_mac_impl!(Blake2bMac, Blake2bVarCore, U64);
+
+pub type Blake2bMac512 = Blake2bMac<U64>;
+
+blake2_impl!(
+    Blake2sVarCore,
+    "Blake2s",
+    u32,
+    U32,
+    U64,
+    BLAKE2S_IV,
+    blake2s::compress,
+);
+
+pub type Blake2sVar = RtVariableCoreWrapper<Blake2sVarCore>;
+pub type Blake2sCore<OutSize> = CtVariableCoreWrapper<Blake2sVarCore, OutSize>;
+pub type Blake2s<OutSize> = CoreWrapper<Blake2sCore<OutSize>>;
+pub type Blake2s256 = Blake2s<U32>;
+
+blake2_mac_impl!(Blake2sMac, Blake2sVarCore, U32);
+
+pub type Blake2sMac256 = Blake2sMac<U32>;
+
+#[cfg(test)]
+mod tests {
+    use super::{Blake2bMac, Blake2bMac512, Blake2sMac, Blake2sMac256};
+    use digest::{consts::U16, new_test, FixedOutput, Mac};
+    use hex_literal::hex;
+
+    #[cfg(feature = "reset")]
+    use digest::dev::{fixed_reset_test as fixed_fn, variable_reset_test as variable_fn};
+    #[cfg(not(feature = "reset"))]
+    use digest::dev::{fixed_test as fixed_fn, variable_test as variable_fn};
+
+    #[cfg(not(feature = "reset"))]
+    use digest::new_mac_test as new_mac_test_fn;
+    #[cfg(feature = "reset")]
+    use digest::new_resettable_mac_test as new_mac_test_fn;
+
+    new_test!(blake2b_fixed, "blake2b/fixed", super::Blake2b512, fixed_fn);
+    new_test!(
+        blake2b_variable,
+        "blake2b/variable",
+        super::Blake2bVar,
+        variable_fn
+    );
+    new_test!(
+        blake2s_variable,
+        "blake2s/variable",
+        super::Blake2sVar,
+        variable_fn
+    );
+
+    new_mac_test_fn!(blake2b_mac, "blake2b/mac", super::Blake2bMac512);
+    new_mac_test_fn!(blake2s_mac, "blake2s/mac", super::Blake2sMac256);
+
+    #[test]
+    #[rustfmt::skip]
+    fn blake2s_persona() {
+        let key = hex!("
+            000102030405060708090a0b0c0d0e0f
+            101112131415161718191a1b1c1d1e1f
+        ");
+        let ctx = Blake2sMac256::new_with_salt_and_personal(&key, &[], b"personal").unwrap();
+        assert_eq!(
+            ctx.finalize_fixed()[..],
+            hex!("
+                25a4ee63b594aed3f88a971e1877ef70
+                99534f9097291f88fb86c79b5e70d022
+            ")[..],
+        );
+    }
+
+    #[test]
+    #[rustfmt::skip]
+    fn blake2b_persona() {
+        let key = hex!("
+            000102030405060708090a0b0c0d0e0f
+            101112131415161718191a1b1c1d1e1f
+        ");
+        let ctx = Blake2bMac512::new_with_salt_and_personal(&key, &[], b"personal").unwrap();
+        assert_eq!(
+            ctx.finalize_fixed()[..],
+            hex!("
+                03de3b295dcfc3b25b05abb09bc95fe3
+                e9ff3073638badc68101d1e42019d077
+                1dd07525a3aae8318e92c5e5d967ba92
+                e4810d0021d7bf3b49da0b4b4a8a4e1f
+            ")[..],
+        );
+    }
+
+    #[test]
+    fn salt_and_persona() {
+        let mut ctx = Blake2bMac::<digest::consts::U32>::new_with_salt_and_personal(
+            b"key",
+            b"saltsaltsaltsalt",
+            b"personal",
+        )
+        .unwrap();
+        ctx.update(b"abc");
+        assert_eq!(
+            ctx.finalize_fixed()[..],
+            hex!("9b628b88e80ace928dc8f8cc177552ad8ca276bd222a8dd0edc1126c0f965067")[..],
+        );
+
+        let mut ctx =
+            Blake2sMac::<U16>::new_with_salt_and_personal(b"key", b"saltsalt", b"person").unwrap();
+        ctx.update(b"abc");
+        assert_eq!(
+            ctx.finalize_fixed()[..],
+            hex!("6a36661229698db407e405f5ab1d28b8")[..],
+        );
+    }
+
+    #[test]
+    fn unkeyed_with_salt() {
+        let data: Vec<u8> = (0..=255u8).cycle().take(1280).collect();
+
+        let mut ctx = Blake2bMac512::new_with_salt_and_personal(&[], b"salt", &[]).unwrap();
+        ctx.update(&data);
+        assert_eq!(
+            ctx.finalize_fixed()[..],
+            hex!(
+                "74f6c31fd1940070bb49d8e4cf99495a71acd85bfdd53862e3e196372285d432
+                 4aa117987ef704a717a67352f5b8c32bd504adf3c0728765265e84ce02a98700"
+            )[..],
+        );
+
+        let mut ctx = Blake2sMac256::new_with_salt_and_personal(&[], &[], b"me").unwrap();
+        ctx.update(&data);
+        assert_eq!(
+            ctx.finalize_fixed()[..],
+            hex!("6cbe96117690f10cd5f5c4fbd1cce135bd2d671ed2add93378145d213321a752")[..],
+        );
+    }
+
+    #[test]
+    fn mac_new_from_slice() {
+        fn run<T: Mac + digest::KeyInit + Clone>(key: &[u8]) {
+            const DATA: &[u8] = &[42; 300];
+            let res1 = <T as Mac>::new(digest::generic_array::GenericArray::from_slice(key))
+                .chain_update(DATA)
+                .finalize()
+                .into_bytes();
+            let res2 = <T as Mac>::new_from_slice(key)
+                .unwrap()
+                .chain_update(DATA)
+                .finalize()
+                .into_bytes();
+            assert_eq!(res1, res2);
+        }
+
+        run::<Blake2sMac256>(&[0x42; 32]);
+        run::<Blake2bMac512>(&[0x42; 64]);
+    }
+
+    type Compress<W, const B: usize> = fn(&mut [W; 8], &[u8; B], [W; 2], [W; 2]);
+
+    /// Checks that `backend` computes the same compression as `soft` over a
+    /// fixed block for every combination of finalization flags.
+    #[allow(dead_code)] // unused when only the portable backend is built
+    pub(crate) fn check_compress<W, const B: usize>(
+        iv: [W; 8],
+        t: [W; 2],
+        max: W,
+        soft: Compress<W, B>,
+        backend: impl Fn(&mut [W; 8], &[u8; B], [W; 2], [W; 2]),
+    ) where
+        W: Copy + Default + PartialEq + core::fmt::Debug,
+    {
+        let mut block = [0u8; B];
+        for (i, b) in block.iter_mut().enumerate() {
+            *b = (i as u8).wrapping_mul(73).wrapping_add(5);
+        }
+
+        let zero = W::default();
+        for f in [[zero, zero], [max, zero], [max, max]] {
+            let mut expected = iv;
+            soft(&mut expected, &block, t, f);
+
+            let mut h = iv;
+            backend(&mut h, &block, t, f);
+            assert_eq!(h, expected);
+        }
+    }
+}
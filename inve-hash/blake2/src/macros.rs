@@ -0,0 +1,317 @@
+macro_rules! blake2_impl {
+    (
+        $name:ident, $alg_name:expr, $word:ident, $bytes:ident, $block_size:ident,
+        $IV:expr, $compress:path $(,)?
+    ) => {
+        #[derive(Clone)]
+        pub struct $name {
+            h: [$word; 8],
+            t: u64,
+            #[cfg(feature = "reset")]
+            h0: [$word; 8],
+        }
+
+        impl $name {
+            pub fn new_with_params(
+                salt: &[u8],
+                persona: &[u8],
+                key_size: usize,
+                output_size: usize,
+            ) -> Self {
+                assert!(key_size <= $bytes::to_usize());
+                assert!(output_size <= $bytes::to_usize());
+
+                let length = $bytes::to_usize() / 4;
+                assert!(salt.len() <= length);
+                assert!(persona.len() <= length);
+
+                let mut p = [0 as $word; 8];
+                p[0] = 0x0101_0000 ^ ((key_size as $word) << 8) ^ (output_size as $word);
+
+                let mut padded = GenericArray::<u8, <$bytes as Div<U4>>::Output>::default();
+                padded[..salt.len()].copy_from_slice(salt);
+                p[4] = $word::from_le_bytes(padded[..length / 2].try_into().unwrap());
+                p[5] = $word::from_le_bytes(padded[length / 2..].try_into().unwrap());
+
+                let mut padded = GenericArray::<u8, <$bytes as Div<U4>>::Output>::default();
+                padded[..persona.len()].copy_from_slice(persona);
+                p[6] = $word::from_le_bytes(padded[..length / 2].try_into().unwrap());
+                p[7] = $word::from_le_bytes(padded[length / 2..].try_into().unwrap());
+
+                let mut h = $IV;
+                for (h, p) in h.iter_mut().zip(p.iter()) {
+                    *h ^= p;
+                }
+                $name {
+                    #[cfg(feature = "reset")]
+                    h0: h,
+                    h,
+                    t: 0,
+                }
+            }
+
+            fn finalize_with_flag(
+                &mut self,
+                final_block: &GenericArray<u8, $block_size>,
+                flag: $word,
+                out: &mut Output<Self>,
+            ) {
+                self.compress(final_block, !0, flag);
+                for (chunk, v) in out
+                    .chunks_exact_mut(core::mem::size_of::<$word>())
+                    .zip(self.h.iter())
+                {
+                    chunk.copy_from_slice(&v.to_le_bytes());
+                }
+            }
+
+            #[inline(always)]
+            fn compress(&mut self, block: &Block<Self>, f0: $word, f1: $word) {
+                let t = [self.t as $word, ((self.t as u128) >> $word::BITS) as $word];
+                $compress(
+                    &mut self.h,
+                    block.as_slice().try_into().unwrap(),
+                    t,
+                    [f0, f1],
+                );
+            }
+        }
+
+        impl HashMarker for $name {}
+
+        impl BlockSizeUser for $name {
+            type BlockSize = $block_size;
+        }
+
+        impl BufferKindUser for $name {
+            type BufferKind = Lazy;
+        }
+
+        impl UpdateCore for $name {
+            #[inline]
+            fn update_blocks(&mut self, blocks: &[Block<Self>]) {
+                for block in blocks {
+                    self.t += block.len() as u64;
+                    self.compress(block, 0, 0);
+                }
+            }
+        }
+
+        impl OutputSizeUser for $name {
+            type OutputSize = $bytes;
+        }
+
+        impl VariableOutputCore for $name {
+            const TRUNC_SIDE: TruncSide = TruncSide::Left;
+
+            #[inline]
+            fn new(output_size: usize) -> Result<Self, InvalidOutputSize> {
+                if output_size > Self::OutputSize::USIZE {
+                    return Err(InvalidOutputSize);
+                }
+                Ok(Self::new_with_params(&[], &[], 0, output_size))
+            }
+
+            #[inline]
+            fn finalize_variable_core(
+                &mut self,
+                buffer: &mut Buffer<Self>,
+                out: &mut Output<Self>,
+            ) {
+                self.t += buffer.get_pos() as u64;
+                let block = buffer.pad_with_zeros();
+                self.finalize_with_flag(block, 0, out);
+            }
+        }
+
+        #[cfg(feature = "reset")]
+        impl Reset for $name {
+            fn reset(&mut self) {
+                self.h = self.h0;
+                self.t = 0;
+            }
+        }
+
+        impl AlgorithmName for $name {
+            #[inline]
+            fn write_alg_name(f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str($alg_name)
+            }
+        }
+
+        impl fmt::Debug for $name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str(concat!(stringify!($name), " { ... }"))
+            }
+        }
+    };
+}
+
+macro_rules! blake2_mac_impl {
+    (
+        $name:ident, $hash:ty, $max_size:ty $(,)?
+    ) => {
+        #[derive(Clone)]
+        pub struct $name<OutSize>
+        where
+            OutSize: ArrayLength<u8> + IsLessOrEqual<$max_size>,
+            LeEq<OutSize, $max_size>: NonZero,
+        {
+            core: $hash,
+            buffer: LazyBuffer<<$hash as BlockSizeUser>::BlockSize>,
+            #[cfg(feature = "reset")]
+            key_buffer: LazyBuffer<<$hash as BlockSizeUser>::BlockSize>,
+            _out: PhantomData<OutSize>,
+        }
+
+        impl<OutSize> $name<OutSize>
+        where
+            OutSize: ArrayLength<u8> + IsLessOrEqual<$max_size>,
+            LeEq<OutSize, $max_size>: NonZero,
+        {
+            #[inline]
+            pub fn new_with_salt_and_personal(
+                key: &[u8],
+                salt: &[u8],
+                persona: &[u8],
+            ) -> Result<Self, InvalidLength> {
+                let kl = key.len();
+                let bs = <$hash as BlockSizeUser>::BlockSize::USIZE;
+                let qbs = bs / 4;
+                if kl > bs || salt.len() > qbs || persona.len() > qbs {
+                    return Err(InvalidLength);
+                }
+                Ok(Self {
+                    core: <$hash>::new_with_params(salt, persona, key.len(), OutSize::USIZE),
+                    #[cfg(feature = "reset")]
+                    key_buffer: Self::key_buffer(key),
+                    buffer: Self::key_buffer(key),
+                    _out: PhantomData,
+                })
+            }
+
+            // An empty key means no key block is prepended (RFC 7693, Section 3.3).
+            fn key_buffer(key: &[u8]) -> LazyBuffer<<$hash as BlockSizeUser>::BlockSize> {
+                if key.is_empty() {
+                    return LazyBuffer::default();
+                }
+                let mut padded_key = Block::<$hash>::default();
+                padded_key[..key.len()].copy_from_slice(key);
+                LazyBuffer::new(&padded_key)
+            }
+        }
+
+        impl<OutSize> KeySizeUser for $name<OutSize>
+        where
+            OutSize: ArrayLength<u8> + IsLessOrEqual<$max_size>,
+            LeEq<OutSize, $max_size>: NonZero,
+        {
+            type KeySize = $max_size;
+        }
+
+        impl<OutSize> KeyInit for $name<OutSize>
+        where
+            OutSize: ArrayLength<u8> + IsLessOrEqual<$max_size>,
+            LeEq<OutSize, $max_size>: NonZero,
+        {
+            #[inline]
+            fn new(key: &Key<Self>) -> Self {
+                Self::new_from_slice(key).expect("Key has correct length")
+            }
+
+            #[inline]
+            fn new_from_slice(key: &[u8]) -> Result<Self, InvalidLength> {
+                let kl = key.len();
+                if kl > <Self as KeySizeUser>::KeySize::USIZE {
+                    return Err(InvalidLength);
+                }
+                Ok(Self {
+                    core: <$hash>::new_with_params(&[], &[], key.len(), OutSize::USIZE),
+                    #[cfg(feature = "reset")]
+                    key_buffer: Self::key_buffer(key),
+                    buffer: Self::key_buffer(key),
+                    _out: PhantomData,
+                })
+            }
+        }
+
+        impl<OutSize> Update for $name<OutSize>
+        where
+            OutSize: ArrayLength<u8> + IsLessOrEqual<$max_size>,
+            LeEq<OutSize, $max_size>: NonZero,
+        {
+            #[inline]
+            fn update(&mut self, input: &[u8]) {
+                let Self { core, buffer, .. } = self;
+                buffer.digest_blocks(input, |blocks| core.update_blocks(blocks));
+            }
+        }
+
+        impl<OutSize> OutputSizeUser for $name<OutSize>
+        where
+            OutSize: ArrayLength<u8> + IsLessOrEqual<$max_size> + 'static,
+            LeEq<OutSize, $max_size>: NonZero,
+        {
+            type OutputSize = OutSize;
+        }
+
+        impl<OutSize> FixedOutput for $name<OutSize>
+        where
+            OutSize: ArrayLength<u8> + IsLessOrEqual<$max_size> + 'static,
+            LeEq<OutSize, $max_size>: NonZero,
+        {
+            #[inline]
+            fn finalize_into(mut self, out: &mut Output<Self>) {
+                let Self { core, buffer, .. } = &mut self;
+                let mut full_res = Default::default();
+                core.finalize_variable_core(buffer, &mut full_res);
+                out.copy_from_slice(&full_res[..OutSize::USIZE]);
+            }
+        }
+
+        #[cfg(feature = "reset")]
+        impl<OutSize> Reset for $name<OutSize>
+        where
+            OutSize: ArrayLength<u8> + IsLessOrEqual<$max_size>,
+            LeEq<OutSize, $max_size>: NonZero,
+        {
+            fn reset(&mut self) {
+                self.core.reset();
+                self.buffer = self.key_buffer.clone();
+            }
+        }
+
+        #[cfg(feature = "reset")]
+        impl<OutSize> FixedOutputReset for $name<OutSize>
+        where
+            OutSize: ArrayLength<u8> + IsLessOrEqual<$max_size>,
+            LeEq<OutSize, $max_size>: NonZero,
+        {
+            #[inline]
+            fn finalize_into_reset(&mut self, out: &mut Output<Self>) {
+                let Self { core, buffer, .. } = self;
+                let mut full_res = Default::default();
+                core.finalize_variable_core(buffer, &mut full_res);
+                out.copy_from_slice(&full_res[..OutSize::USIZE]);
+                self.reset();
+            }
+        }
+
+        impl<OutSize> MacMarker for $name<OutSize>
+        where
+            OutSize: ArrayLength<u8> + IsLessOrEqual<$max_size>,
+            LeEq<OutSize, $max_size>: NonZero,
+        {
+        }
+
+        impl<OutSize> fmt::Debug for $name<OutSize>
+        where
+            OutSize: ArrayLength<u8> + IsLessOrEqual<$max_size>,
+            LeEq<OutSize, $max_size>: NonZero,
+        {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(f, "{}{} {{ ... }}", stringify!($name), OutSize::USIZE)
+            }
+        }
+    };
+}
@@ -0,0 +1,227 @@
+use crate::{CVWords, IncrementCounter, BLOCK_LEN};
+use core::convert::TryInto;
+
+mod soft;
+
+cfg_if::cfg_if! {
+    if #[cfg(all(
+        any(target_arch = "x86", target_arch = "x86_64"),
+        not(feature = "force-soft"),
+    ))] {
+        mod avx2;
+        mod sse41;
+
+        cpufeatures::new!(avx2_cpuid, "avx2");
+        cpufeatures::new!(sse41_cpuid, "sse4.1");
+
+        pub(crate) const MAX_SIMD_DEGREE: usize = avx2::DEGREE;
+        pub(crate) const MAX_SIMD_DEGREE_OR_2: usize = avx2::DEGREE;
+    } else {
+        pub(crate) const MAX_SIMD_DEGREE: usize = 1;
+        pub(crate) const MAX_SIMD_DEGREE_OR_2: usize = 2;
+    }
+}
+
+/// Compression backend, selected once at runtime.
+#[derive(Clone, Copy, Debug)]
+pub(crate) enum Backend {
+    Soft,
+    #[cfg(all(
+        any(target_arch = "x86", target_arch = "x86_64"),
+        not(feature = "force-soft"),
+    ))]
+    Sse41,
+    #[cfg(all(
+        any(target_arch = "x86", target_arch = "x86_64"),
+        not(feature = "force-soft"),
+    ))]
+    Avx2,
+}
+
+impl Backend {
+    pub(crate) fn detect() -> Self {
+        #[cfg(all(
+            any(target_arch = "x86", target_arch = "x86_64"),
+            not(feature = "force-soft"),
+        ))]
+        {
+            if avx2_cpuid::get() {
+                return Backend::Avx2;
+            }
+            if sse41_cpuid::get() {
+                return Backend::Sse41;
+            }
+        }
+        Backend::Soft
+    }
+
+    /// All backends the CPU supports, including the soft one.
+    #[cfg(test)]
+    pub(crate) fn available() -> impl Iterator<Item = Self> {
+        #[allow(unused_mut)]
+        let mut backends = [Some(Backend::Soft), None, None];
+        #[cfg(all(
+            any(target_arch = "x86", target_arch = "x86_64"),
+            not(feature = "force-soft"),
+        ))]
+        {
+            if sse41_cpuid::get() {
+                backends[1] = Some(Backend::Sse41);
+            }
+            if avx2_cpuid::get() {
+                backends[2] = Some(Backend::Avx2);
+            }
+        }
+        backends.into_iter().flatten()
+    }
+
+    pub(crate) fn simd_degree(&self) -> usize {
+        match self {
+            Backend::Soft => 1,
+            #[cfg(all(
+                any(target_arch = "x86", target_arch = "x86_64"),
+                not(feature = "force-soft"),
+            ))]
+            Backend::Sse41 => sse41::DEGREE,
+            #[cfg(all(
+                any(target_arch = "x86", target_arch = "x86_64"),
+                not(feature = "force-soft"),
+            ))]
+            Backend::Avx2 => avx2::DEGREE,
+        }
+    }
+
+    pub(crate) fn compress_in_place(
+        &self,
+        cv: &mut CVWords,
+        block: &[u8; BLOCK_LEN],
+        block_len: u8,
+        counter: u64,
+        flags: u8,
+    ) {
+        match self {
+            Backend::Soft => soft::compress_in_place(cv, block, block_len, counter, flags),
+            #[cfg(all(
+                any(target_arch = "x86", target_arch = "x86_64"),
+                not(feature = "force-soft"),
+            ))]
+            Backend::Sse41 | Backend::Avx2 => unsafe {
+                sse41::compress_in_place(cv, block, block_len, counter, flags)
+            },
+        }
+    }
+
+    pub(crate) fn compress_xof(
+        &self,
+        cv: &CVWords,
+        block: &[u8; BLOCK_LEN],
+        block_len: u8,
+        counter: u64,
+        flags: u8,
+    ) -> [u8; 64] {
+        match self {
+            Backend::Soft => soft::compress_xof(cv, block, block_len, counter, flags),
+            #[cfg(all(
+                any(target_arch = "x86", target_arch = "x86_64"),
+                not(feature = "force-soft"),
+            ))]
+            Backend::Sse41 | Backend::Avx2 => unsafe {
+                sse41::compress_xof(cv, block, block_len, counter, flags)
+            },
+        }
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) fn hash_many<const N: usize>(
+        &self,
+        inputs: &[&[u8; N]],
+        key: &CVWords,
+        counter: u64,
+        increment_counter: IncrementCounter,
+        flags: u8,
+        flags_start: u8,
+        flags_end: u8,
+        out: &mut [u8],
+    ) {
+        match self {
+            Backend::Soft => soft::hash_many(
+                inputs,
+                key,
+                counter,
+                increment_counter,
+                flags,
+                flags_start,
+                flags_end,
+                out,
+            ),
+            #[cfg(all(
+                any(target_arch = "x86", target_arch = "x86_64"),
+                not(feature = "force-soft"),
+            ))]
+            Backend::Sse41 => unsafe {
+                sse41::hash_many(
+                    inputs,
+                    key,
+                    counter,
+                    increment_counter,
+                    flags,
+                    flags_start,
+                    flags_end,
+                    out,
+                )
+            },
+            #[cfg(all(
+                any(target_arch = "x86", target_arch = "x86_64"),
+                not(feature = "force-soft"),
+            ))]
+            Backend::Avx2 => unsafe {
+                avx2::hash_many(
+                    inputs,
+                    key,
+                    counter,
+                    increment_counter,
+                    flags,
+                    flags_start,
+                    flags_end,
+                    out,
+                )
+            },
+        }
+    }
+}
+
+#[inline(always)]
+pub(crate) fn words_from_le_bytes_32(bytes: &[u8; 32]) -> [u32; 8] {
+    let mut out = [0; 8];
+    for (o, chunk) in out.iter_mut().zip(bytes.chunks_exact(4)) {
+        *o = u32::from_le_bytes(chunk.try_into().unwrap());
+    }
+    out
+}
+
+#[inline(always)]
+pub(crate) fn words_from_le_bytes_64(bytes: &[u8; 64]) -> [u32; 16] {
+    let mut out = [0; 16];
+    for (o, chunk) in out.iter_mut().zip(bytes.chunks_exact(4)) {
+        *o = u32::from_le_bytes(chunk.try_into().unwrap());
+    }
+    out
+}
+
+#[inline(always)]
+pub(crate) fn le_bytes_from_words_32(words: &[u32; 8]) -> [u8; 32] {
+    let mut out = [0; 32];
+    for (chunk, w) in out.chunks_exact_mut(4).zip(words.iter()) {
+        chunk.copy_from_slice(&w.to_le_bytes());
+    }
+    out
+}
+
+#[inline(always)]
+pub(crate) fn le_bytes_from_words_64(words: &[u32; 16]) -> [u8; 64] {
+    let mut out = [0; 64];
+    for (chunk, w) in out.chunks_exact_mut(4).zip(words.iter()) {
+        chunk.copy_from_slice(&w.to_le_bytes());
+    }
+    out
+}
@@ -0,0 +1,878 @@
+#[cfg(feature = "std")]
+extern crate std;
+
+pub use digest::{self, Digest};
+
+use core::{cmp, convert::TryInto, fmt};
+use digest::{
+    consts::{U32, U64},
+    core_api::{AlgorithmName, BlockSizeUser},
+    crypto_common::{Key, KeyInit, KeySizeUser},
+    ExtendableOutput, ExtendableOutputReset, FixedOutput, FixedOutputReset, HashMarker, MacMarker,
+    Output, OutputSizeUser, Reset, Update, XofReader,
+};
+
+mod compress;
+mod join;
+
+use compress::{
+    le_bytes_from_words_32, words_from_le_bytes_32, Backend, MAX_SIMD_DEGREE, MAX_SIMD_DEGREE_OR_2,
+};
+
+const OUT_LEN: usize = 32;
+const KEY_LEN: usize = 32;
+const BLOCK_LEN: usize = 64;
+const CHUNK_LEN: usize = 1024;
+// 2^54 * CHUNK_LEN = 2^64
+const MAX_DEPTH: usize = 54;
+
+type CVWords = [u32; 8];
+type CVBytes = [u8; 32];
+
+const IV: &CVWords = &[
+    0x6A09E667, 0xBB67AE85, 0x3C6EF372, 0xA54FF53A, 0x510E527F, 0x9B05688C, 0x1F83D9AB, 0x5BE0CD19,
+];
+
+const MSG_SCHEDULE: [[usize; 16]; 7] = [
+    [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15],
+    [2, 6, 3, 10, 7, 0, 4, 13, 1, 11, 12, 5, 9, 14, 15, 8],
+    [3, 4, 10, 12, 13, 2, 7, 14, 6, 5, 9, 0, 11, 15, 8, 1],
+    [10, 7, 12, 9, 14, 3, 13, 15, 4, 0, 11, 2, 5, 8, 1, 6],
+    [12, 13, 9, 11, 15, 10, 14, 8, 7, 2, 5, 3, 0, 1, 6, 4],
+    [9, 14, 11, 5, 8, 12, 15, 1, 13, 3, 0, 10, 2, 6, 4, 7],
+    [11, 15, 5, 0, 1, 9, 8, 6, 14, 10, 2, 12, 3, 4, 7, 13],
+];
+
+const CHUNK_START: u8 = 1 << 0;
+const CHUNK_END: u8 = 1 << 1;
+const PARENT: u8 = 1 << 2;
+const ROOT: u8 = 1 << 3;
+const KEYED_HASH: u8 = 1 << 4;
+const DERIVE_KEY_CONTEXT: u8 = 1 << 5;
+const DERIVE_KEY_MATERIAL: u8 = 1 << 6;
+
+#[inline]
+fn counter_low(counter: u64) -> u32 {
+    counter as u32
+}
+
+#[inline]
+fn counter_high(counter: u64) -> u32 {
+    (counter >> 32) as u32
+}
+
+#[derive(Clone, Copy)]
+enum IncrementCounter {
+    Yes,
+    No,
+}
+
+impl IncrementCounter {
+    #[inline]
+    fn yes(&self) -> bool {
+        matches!(self, IncrementCounter::Yes)
+    }
+}
+
+#[derive(Clone)]
+struct OutputNode {
+    input_chaining_value: CVWords,
+    block: [u8; BLOCK_LEN],
+    block_len: u8,
+    counter: u64,
+    flags: u8,
+    backend: Backend,
+}
+
+impl OutputNode {
+    fn chaining_value(&self) -> CVBytes {
+        let mut cv = self.input_chaining_value;
+        self.backend.compress_in_place(
+            &mut cv,
+            &self.block,
+            self.block_len,
+            self.counter,
+            self.flags,
+        );
+        le_bytes_from_words_32(&cv)
+    }
+
+    fn root_hash(&self) -> CVBytes {
+        debug_assert_eq!(self.counter, 0);
+        let mut cv = self.input_chaining_value;
+        self.backend
+            .compress_in_place(&mut cv, &self.block, self.block_len, 0, self.flags | ROOT);
+        le_bytes_from_words_32(&cv)
+    }
+
+    fn root_output_block(&self) -> [u8; BLOCK_LEN] {
+        self.backend.compress_xof(
+            &self.input_chaining_value,
+            &self.block,
+            self.block_len,
+            self.counter,
+            self.flags | ROOT,
+        )
+    }
+}
+
+#[derive(Clone)]
+struct ChunkState {
+    cv: CVWords,
+    chunk_counter: u64,
+    buf: [u8; BLOCK_LEN],
+    buf_len: u8,
+    blocks_compressed: u8,
+    flags: u8,
+    backend: Backend,
+}
+
+impl ChunkState {
+    fn new(key: &CVWords, chunk_counter: u64, flags: u8, backend: Backend) -> Self {
+        Self {
+            cv: *key,
+            chunk_counter,
+            buf: [0; BLOCK_LEN],
+            buf_len: 0,
+            blocks_compressed: 0,
+            flags,
+            backend,
+        }
+    }
+
+    fn len(&self) -> usize {
+        BLOCK_LEN * self.blocks_compressed as usize + self.buf_len as usize
+    }
+
+    fn fill_buf(&mut self, input: &mut &[u8]) {
+        let want = BLOCK_LEN - self.buf_len as usize;
+        let take = cmp::min(want, input.len());
+        self.buf[self.buf_len as usize..][..take].copy_from_slice(&input[..take]);
+        self.buf_len += take as u8;
+        *input = &input[take..];
+    }
+
+    fn start_flag(&self) -> u8 {
+        if self.blocks_compressed == 0 {
+            CHUNK_START
+        } else {
+            0
+        }
+    }
+
+    fn update(&mut self, mut input: &[u8]) -> &mut Self {
+        if self.buf_len > 0 {
+            self.fill_buf(&mut input);
+            if !input.is_empty() {
+                debug_assert_eq!(self.buf_len as usize, BLOCK_LEN);
+                let block_flags = self.flags | self.start_flag();
+                self.backend.compress_in_place(
+                    &mut self.cv,
+                    &self.buf,
+                    BLOCK_LEN as u8,
+                    self.chunk_counter,
+                    block_flags,
+                );
+                self.buf_len = 0;
+                self.buf = [0; BLOCK_LEN];
+                self.blocks_compressed += 1;
+            }
+        }
+
+        while input.len() > BLOCK_LEN {
+            debug_assert_eq!(self.buf_len, 0);
+            let block_flags = self.flags | self.start_flag();
+            self.backend.compress_in_place(
+                &mut self.cv,
+                input[..BLOCK_LEN].try_into().unwrap(),
+                BLOCK_LEN as u8,
+                self.chunk_counter,
+                block_flags,
+            );
+            self.blocks_compressed += 1;
+            input = &input[BLOCK_LEN..];
+        }
+
+        self.fill_buf(&mut input);
+        debug_assert!(input.is_empty());
+        debug_assert!(self.len() <= CHUNK_LEN);
+        self
+    }
+
+    fn output(&self) -> OutputNode {
+        let block_flags = self.flags | self.start_flag() | CHUNK_END;
+        OutputNode {
+            input_chaining_value: self.cv,
+            block: self.buf,
+            block_len: self.buf_len,
+            counter: self.chunk_counter,
+            flags: block_flags,
+            backend: self.backend,
+        }
+    }
+}
+
+fn largest_power_of_two_leq(n: usize) -> usize {
+    ((n / 2) + 1).next_power_of_two()
+}
+
+// Length of the left subtree: the largest power of two number of full
+// chunks that leaves at least one byte for the right subtree.
+fn left_len(content_len: usize) -> usize {
+    debug_assert!(content_len > CHUNK_LEN);
+    let full_chunks = (content_len - 1) / CHUNK_LEN;
+    largest_power_of_two_leq(full_chunks) * CHUNK_LEN
+}
+
+fn compress_chunks_parallel(
+    input: &[u8],
+    key: &CVWords,
+    chunk_counter: u64,
+    flags: u8,
+    backend: Backend,
+    out: &mut [u8],
+) -> usize {
+    debug_assert!(!input.is_empty(), "empty chunks below the root");
+    debug_assert!(input.len() <= MAX_SIMD_DEGREE * CHUNK_LEN);
+
+    let mut chunks_exact = input.chunks_exact(CHUNK_LEN);
+    let mut chunks_array: [&[u8; CHUNK_LEN]; MAX_SIMD_DEGREE] = [&[0; CHUNK_LEN]; MAX_SIMD_DEGREE];
+    let mut chunks_so_far = 0;
+    for (dst, chunk) in chunks_array.iter_mut().zip(&mut chunks_exact) {
+        *dst = chunk.try_into().unwrap();
+        chunks_so_far += 1;
+    }
+    backend.hash_many(
+        &chunks_array[..chunks_so_far],
+        key,
+        chunk_counter,
+        IncrementCounter::Yes,
+        flags,
+        CHUNK_START,
+        CHUNK_END,
+        out,
+    );
+
+    if !chunks_exact.remainder().is_empty() {
+        let counter = chunk_counter + chunks_so_far as u64;
+        let mut chunk_state = ChunkState::new(key, counter, flags, backend);
+        chunk_state.update(chunks_exact.remainder());
+        out[chunks_so_far * OUT_LEN..][..OUT_LEN]
+            .copy_from_slice(&chunk_state.output().chaining_value());
+        chunks_so_far + 1
+    } else {
+        chunks_so_far
+    }
+}
+
+fn compress_parents_parallel(
+    child_chaining_values: &[u8],
+    key: &CVWords,
+    flags: u8,
+    backend: Backend,
+    out: &mut [u8],
+) -> usize {
+    debug_assert_eq!(child_chaining_values.len() % OUT_LEN, 0);
+    let num_children = child_chaining_values.len() / OUT_LEN;
+    debug_assert!(num_children >= 2, "not enough children");
+    debug_assert!(num_children <= 2 * MAX_SIMD_DEGREE_OR_2, "too many");
+
+    let mut parents_exact = child_chaining_values.chunks_exact(BLOCK_LEN);
+    let mut parents_array: [&[u8; BLOCK_LEN]; MAX_SIMD_DEGREE_OR_2] =
+        [&[0; BLOCK_LEN]; MAX_SIMD_DEGREE_OR_2];
+    let mut parents_so_far = 0;
+    for (dst, parent) in parents_array.iter_mut().zip(&mut parents_exact) {
+        *dst = parent.try_into().unwrap();
+        parents_so_far += 1;
+    }
+    backend.hash_many(
+        &parents_array[..parents_so_far],
+        key,
+        0,
+        IncrementCounter::No,
+        flags | PARENT,
+        0,
+        0,
+        out,
+    );
+
+    // An odd child is carried up to the next level as-is.
+    if !parents_exact.remainder().is_empty() {
+        out[parents_so_far * OUT_LEN..][..OUT_LEN].copy_from_slice(parents_exact.remainder());
+        parents_so_far + 1
+    } else {
+        parents_so_far
+    }
+}
+
+// Hash a subtree with SIMD parallelism (and optionally multithreading),
+// returning the number of chaining values written to `out`. The result is
+// never fully reduced, so that the caller can keep all SIMD lanes busy.
+fn compress_subtree_wide<J: join::Join>(
+    input: &[u8],
+    key: &CVWords,
+    chunk_counter: u64,
+    flags: u8,
+    backend: Backend,
+    out: &mut [u8],
+) -> usize {
+    if input.len() <= backend.simd_degree() * CHUNK_LEN {
+        return compress_chunks_parallel(input, key, chunk_counter, flags, backend, out);
+    }
+
+    let (left, right) = input.split_at(left_len(input.len()));
+    let right_chunk_counter = chunk_counter + (left.len() / CHUNK_LEN) as u64;
+
+    let mut cv_array = [0; 2 * MAX_SIMD_DEGREE_OR_2 * OUT_LEN];
+    let degree = if left.len() == CHUNK_LEN {
+        debug_assert_eq!(backend.simd_degree(), 1);
+        1
+    } else {
+        cmp::max(backend.simd_degree(), 2)
+    };
+    let (left_out, right_out) = cv_array.split_at_mut(degree * OUT_LEN);
+
+    let (left_n, right_n) = J::join(
+        || compress_subtree_wide::<J>(left, key, chunk_counter, flags, backend, left_out),
+        || compress_subtree_wide::<J>(right, key, right_chunk_counter, flags, backend, right_out),
+    );
+
+    debug_assert_eq!(left_n, degree);
+    debug_assert!(right_n >= 1 && right_n <= left_n);
+    if left_n == 1 {
+        out[..2 * OUT_LEN].copy_from_slice(&cv_array[..2 * OUT_LEN]);
+        return 2;
+    }
+
+    let num_children = left_n + right_n;
+    compress_parents_parallel(
+        &cv_array[..num_children * OUT_LEN],
+        key,
+        flags,
+        backend,
+        out,
+    )
+}
+
+fn compress_subtree_to_parent_node<J: join::Join>(
+    input: &[u8],
+    key: &CVWords,
+    chunk_counter: u64,
+    flags: u8,
+    backend: Backend,
+) -> [u8; BLOCK_LEN] {
+    debug_assert!(input.len() > CHUNK_LEN);
+    let mut cv_array = [0; MAX_SIMD_DEGREE_OR_2 * OUT_LEN];
+    let mut num_cvs =
+        compress_subtree_wide::<J>(input, key, chunk_counter, flags, backend, &mut cv_array);
+    debug_assert!(num_cvs >= 2);
+
+    let mut out_array = [0; MAX_SIMD_DEGREE_OR_2 * OUT_LEN / 2];
+    while num_cvs > 2 {
+        let cv_slice = &cv_array[..num_cvs * OUT_LEN];
+        num_cvs = compress_parents_parallel(cv_slice, key, flags, backend, &mut out_array);
+        cv_array[..num_cvs * OUT_LEN].copy_from_slice(&out_array[..num_cvs * OUT_LEN]);
+    }
+    cv_array[..BLOCK_LEN].try_into().unwrap()
+}
+
+fn parent_node_output(
+    left_child: &CVBytes,
+    right_child: &CVBytes,
+    key: &CVWords,
+    flags: u8,
+    backend: Backend,
+) -> OutputNode {
+    let mut block = [0; BLOCK_LEN];
+    block[..OUT_LEN].copy_from_slice(left_child);
+    block[OUT_LEN..].copy_from_slice(right_child);
+    OutputNode {
+        input_chaining_value: *key,
+        block,
+        block_len: BLOCK_LEN as u8,
+        counter: 0,
+        flags: flags | PARENT,
+        backend,
+    }
+}
+
+/// Derive a 256-bit key from `key_material` in the domain described by
+/// the hardcoded, globally unique `context` string.
+pub fn derive_key(context: &str, key_material: &[u8]) -> [u8; OUT_LEN] {
+    let mut hasher = Blake3::new_derive_key(context);
+    Update::update(&mut hasher, key_material);
+    hasher.final_output().root_hash()
+}
+
+/// BLAKE3 hasher in the regular, keyed or key derivation mode.
+#[derive(Clone)]
+pub struct Blake3 {
+    key: CVWords,
+    chunk_state: ChunkState,
+    cv_stack: [CVBytes; MAX_DEPTH + 1],
+    cv_stack_len: u8,
+}
+
+impl Blake3 {
+    fn new_internal(key: &CVWords, flags: u8) -> Self {
+        Self {
+            key: *key,
+            chunk_state: ChunkState::new(key, 0, flags, Backend::detect()),
+            cv_stack: [[0; OUT_LEN]; MAX_DEPTH + 1],
+            cv_stack_len: 0,
+        }
+    }
+
+    /// Create a hasher in the default hashing mode.
+    pub fn new() -> Self {
+        Self::new_internal(IV, 0)
+    }
+
+    /// Create a hasher in the keyed hashing mode.
+    pub fn new_keyed(key: &[u8; KEY_LEN]) -> Self {
+        Self::new_internal(&words_from_le_bytes_32(key), KEYED_HASH)
+    }
+
+    /// Create a hasher in the key derivation mode, see [`derive_key`].
+    pub fn new_derive_key(context: &str) -> Self {
+        let mut context_hasher = Self::new_internal(IV, DERIVE_KEY_CONTEXT);
+        Update::update(&mut context_hasher, context.as_bytes());
+        let context_key = context_hasher.final_output().root_hash();
+        Self::new_internal(&words_from_le_bytes_32(&context_key), DERIVE_KEY_MATERIAL)
+    }
+
+    /// Absorb `input` hashing independent subtrees on the rayon thread
+    /// pool. Only worth it for inputs of at least 128 KiB or so.
+    #[cfg(feature = "parallel")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "parallel")))]
+    pub fn update_parallel(&mut self, input: &[u8]) {
+        self.update_with_join::<join::RayonJoin>(input);
+    }
+
+    /// Total number of bytes absorbed so far.
+    pub fn count(&self) -> u64 {
+        self.chunk_state.chunk_counter * CHUNK_LEN as u64 + self.chunk_state.len() as u64
+    }
+
+    fn push_stack(&mut self, cv: CVBytes) {
+        self.cv_stack[self.cv_stack_len as usize] = cv;
+        self.cv_stack_len += 1;
+    }
+
+    fn pop_stack(&mut self) -> CVBytes {
+        self.cv_stack_len -= 1;
+        self.cv_stack[self.cv_stack_len as usize]
+    }
+
+    // Merge completed subtrees, leaving one chaining value per set bit of
+    // `total_len`. Merging is deferred until more input arrives, because
+    // the last chunk on the stack might turn out to be the root.
+    fn merge_cv_stack(&mut self, total_len: u64) {
+        let post_merge_stack_len = total_len.count_ones() as u8;
+        while self.cv_stack_len > post_merge_stack_len {
+            let right_child = self.pop_stack();
+            let left_child = self.pop_stack();
+            let parent_output = parent_node_output(
+                &left_child,
+                &right_child,
+                &self.key,
+                self.chunk_state.flags,
+                self.chunk_state.backend,
+            );
+            self.push_stack(parent_output.chaining_value());
+        }
+    }
+
+    fn push_cv(&mut self, new_cv: &CVBytes, chunk_counter: u64) {
+        self.merge_cv_stack(chunk_counter);
+        self.push_stack(*new_cv);
+    }
+
+    fn update_with_join<J: join::Join>(&mut self, mut input: &[u8]) {
+        if self.chunk_state.len() > 0 {
+            let want = CHUNK_LEN - self.chunk_state.len();
+            let take = cmp::min(want, input.len());
+            self.chunk_state.update(&input[..take]);
+            input = &input[take..];
+            if input.is_empty() {
+                return;
+            }
+            debug_assert_eq!(self.chunk_state.len(), CHUNK_LEN);
+            let chunk_cv = self.chunk_state.output().chaining_value();
+            self.push_cv(&chunk_cv, self.chunk_state.chunk_counter);
+            self.chunk_state = ChunkState::new(
+                &self.key,
+                self.chunk_state.chunk_counter + 1,
+                self.chunk_state.flags,
+                self.chunk_state.backend,
+            );
+        }
+
+        // Hash whole subtrees at once while more than one chunk of input
+        // remains. Subtrees must be a power of two chunks and aligned to
+        // their own size within the overall input.
+        while input.len() > CHUNK_LEN {
+            debug_assert_eq!(self.chunk_state.len(), 0, "no partial chunk data");
+            let mut subtree_len = largest_power_of_two_leq(input.len());
+            let count_so_far = self.chunk_state.chunk_counter * CHUNK_LEN as u64;
+            while (subtree_len - 1) as u64 & count_so_far != 0 {
+                subtree_len /= 2;
+            }
+            let subtree_chunks = (subtree_len / CHUNK_LEN) as u64;
+            if subtree_len <= CHUNK_LEN {
+                debug_assert_eq!(subtree_len, CHUNK_LEN);
+                let cv = ChunkState::new(
+                    &self.key,
+                    self.chunk_state.chunk_counter,
+                    self.chunk_state.flags,
+                    self.chunk_state.backend,
+                )
+                .update(&input[..subtree_len])
+                .output()
+                .chaining_value();
+                self.push_cv(&cv, self.chunk_state.chunk_counter);
+            } else {
+                let cv_pair = compress_subtree_to_parent_node::<J>(
+                    &input[..subtree_len],
+                    &self.key,
+                    self.chunk_state.chunk_counter,
+                    self.chunk_state.flags,
+                    self.chunk_state.backend,
+                );
+                let (left_cv, right_cv) = cv_pair.split_at(OUT_LEN);
+                self.push_cv(left_cv.try_into().unwrap(), self.chunk_state.chunk_counter);
+                self.push_cv(
+                    right_cv.try_into().unwrap(),
+                    self.chunk_state.chunk_counter + (subtree_chunks / 2),
+                );
+            }
+            self.chunk_state.chunk_counter += subtree_chunks;
+            input = &input[subtree_len..];
+        }
+
+        debug_assert!(input.len() <= CHUNK_LEN);
+        if !input.is_empty() {
+            self.chunk_state.update(input);
+            self.merge_cv_stack(self.chunk_state.chunk_counter);
+        }
+    }
+
+    fn final_output(&self) -> OutputNode {
+        let stack = &self.cv_stack[..self.cv_stack_len as usize];
+        if stack.is_empty() {
+            debug_assert_eq!(self.chunk_state.chunk_counter, 0);
+            return self.chunk_state.output();
+        }
+
+        let mut num_cvs_remaining = stack.len();
+        let mut output = if self.chunk_state.len() > 0 {
+            self.chunk_state.output()
+        } else {
+            debug_assert!(stack.len() >= 2);
+            num_cvs_remaining -= 2;
+            parent_node_output(
+                &stack[num_cvs_remaining],
+                &stack[num_cvs_remaining + 1],
+                &self.key,
+                self.chunk_state.flags,
+                self.chunk_state.backend,
+            )
+        };
+        while num_cvs_remaining > 0 {
+            num_cvs_remaining -= 1;
+            output = parent_node_output(
+                &stack[num_cvs_remaining],
+                &output.chaining_value(),
+                &self.key,
+                self.chunk_state.flags,
+                self.chunk_state.backend,
+            );
+        }
+        output
+    }
+}
+
+impl Default for Blake3 {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl HashMarker for Blake3 {}
+
+impl MacMarker for Blake3 {}
+
+impl BlockSizeUser for Blake3 {
+    type BlockSize = U64;
+}
+
+impl OutputSizeUser for Blake3 {
+    type OutputSize = U32;
+}
+
+impl KeySizeUser for Blake3 {
+    type KeySize = U32;
+}
+
+impl KeyInit for Blake3 {
+    #[inline]
+    fn new(key: &Key<Self>) -> Self {
+        let key: [u8; KEY_LEN] = (*key).into();
+        Self::new_keyed(&key)
+    }
+}
+
+impl Update for Blake3 {
+    #[inline]
+    fn update(&mut self, input: &[u8]) {
+        self.update_with_join::<join::SerialJoin>(input);
+    }
+}
+
+impl FixedOutput for Blake3 {
+    #[inline]
+    fn finalize_into(self, out: &mut Output<Self>) {
+        out.copy_from_slice(&self.final_output().root_hash());
+    }
+}
+
+impl FixedOutputReset for Blake3 {
+    #[inline]
+    fn finalize_into_reset(&mut self, out: &mut Output<Self>) {
+        out.copy_from_slice(&self.final_output().root_hash());
+        Reset::reset(self);
+    }
+}
+
+impl ExtendableOutput for Blake3 {
+    type Reader = Blake3Reader;
+
+    #[inline]
+    fn finalize_xof(self) -> Self::Reader {
+        Blake3Reader::new(self.final_output())
+    }
+}
+
+impl ExtendableOutputReset for Blake3 {
+    #[inline]
+    fn finalize_xof_reset(&mut self) -> Self::Reader {
+        let reader = Blake3Reader::new(self.final_output());
+        Reset::reset(self);
+        reader
+    }
+}
+
+impl Reset for Blake3 {
+    #[inline]
+    fn reset(&mut self) {
+        self.chunk_state = ChunkState::new(
+            &self.key,
+            0,
+            self.chunk_state.flags,
+            self.chunk_state.backend,
+        );
+        self.cv_stack_len = 0;
+    }
+}
+
+impl AlgorithmName for Blake3 {
+    fn write_alg_name(f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("Blake3")
+    }
+}
+
+impl fmt::Debug for Blake3 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("Blake3 { ... }")
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::io::Write for Blake3 {
+    #[inline]
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        Update::update(self, buf);
+        Ok(buf.len())
+    }
+
+    #[inline]
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+/// BLAKE3 extendable output reader. Supports seeking to any position
+/// of the 2^64 byte output stream.
+#[derive(Clone)]
+pub struct Blake3Reader {
+    inner: OutputNode,
+    position_within_block: u8,
+}
+
+impl Blake3Reader {
+    fn new(inner: OutputNode) -> Self {
+        Self {
+            inner,
+            position_within_block: 0,
+        }
+    }
+
+    /// Current position in the output stream.
+    pub fn position(&self) -> u64 {
+        self.inner.counter * BLOCK_LEN as u64 + self.position_within_block as u64
+    }
+
+    /// Seek to the given position in the output stream.
+    pub fn set_position(&mut self, position: u64) {
+        self.position_within_block = (position % BLOCK_LEN as u64) as u8;
+        self.inner.counter = position / BLOCK_LEN as u64;
+    }
+}
+
+impl XofReader for Blake3Reader {
+    fn read(&mut self, mut buf: &mut [u8]) {
+        while !buf.is_empty() {
+            let block = self.inner.root_output_block();
+            let output_bytes = &block[self.position_within_block as usize..];
+            let take = cmp::min(buf.len(), output_bytes.len());
+            buf[..take].copy_from_slice(&output_bytes[..take]);
+            buf = &mut buf[take..];
+            self.position_within_block += take as u8;
+            if self.position_within_block == BLOCK_LEN as u8 {
+                self.inner.counter += 1;
+                self.position_within_block = 0;
+            }
+        }
+    }
+}
+
+impl fmt::Debug for Blake3Reader {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Blake3Reader")
+            .field("position", &self.position())
+            .finish()
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::io::Read for Blake3Reader {
+    #[inline]
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        XofReader::read(self, buf);
+        Ok(buf.len())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        cmp, derive_key, Backend, Blake3, ExtendableOutput, FixedOutput, Reset, TryInto, Update,
+        XofReader, CHUNK_LEN, KEY_LEN, OUT_LEN,
+    };
+    use digest::dev::blobby::Blob4Iterator;
+    use hex_literal::hex;
+
+    const TEST_KEY: &[u8; KEY_LEN] = b"whats the Elephant? It's a Bird!";
+    const TEST_CONTEXT: &str = "BLAKE3 2019-12-27 16:29:52 test vectors context";
+
+    fn input(len: usize) -> std::vec::Vec<u8> {
+        (0..len).map(|i| (i % 251) as u8).collect()
+    }
+
+    fn check(mut hasher: Blake3, input: &[u8], expected: &[u8]) {
+        let mut xof = [0; 131];
+        for split in [0, 1, 63, 64, 1023, 1024, 1025, 4096, 8193] {
+            let split = cmp::min(split, input.len());
+            let mut h = hasher.clone();
+            h.update(&input[..split]);
+            h.update(&input[split..]);
+            h.finalize_xof().read(&mut xof);
+            assert_eq!(&xof[..], expected);
+        }
+        hasher.update(input);
+        assert_eq!(hasher.finalize_fixed()[..], expected[..OUT_LEN]);
+    }
+
+    #[test]
+    fn blake3_vectors() {
+        let data = include_bytes!("data/blake3.blb");
+        for row in Blob4Iterator::new(data).unwrap() {
+            let [len, hash, keyed_hash, derived_key] = row.unwrap();
+            let input = input(u64::from_le_bytes(len.try_into().unwrap()) as usize);
+
+            check(Blake3::new(), &input, hash);
+            check(Blake3::new_keyed(TEST_KEY), &input, keyed_hash);
+            check(Blake3::new_derive_key(TEST_CONTEXT), &input, derived_key);
+            assert_eq!(derive_key(TEST_CONTEXT, &input), derived_key[..OUT_LEN]);
+
+            let mut mac = <Blake3 as digest::Mac>::new(TEST_KEY.into());
+            digest::Mac::update(&mut mac, &input);
+            digest::Mac::verify_slice(mac, &keyed_hash[..OUT_LEN]).unwrap();
+        }
+    }
+
+    #[test]
+    fn blake3_empty() {
+        assert_eq!(
+            Blake3::new().chain(b"").finalize_fixed()[..],
+            hex!("af1349b9f5f9a1a6a0404dea36dcc9499bcb25c9adc112b7cc9a93cae41f3262")
+        );
+        assert_eq!(
+            Blake3::new().chain(b"abc").finalize_fixed()[..],
+            hex!("6437b3ac38465133ffb63b75273a8db548c558465d79db03fd359c6cd5bd9d85")
+        );
+    }
+
+    #[test]
+    fn blake3_backends() {
+        let input = input(31 * CHUNK_LEN + 7);
+        let mut expected = Blake3::new();
+        expected.chunk_state.backend = Backend::Soft;
+        expected.update(&input);
+        let expected = expected.finalize_fixed();
+
+        assert_eq!(Blake3::new().chain(&input).finalize_fixed(), expected);
+        for backend in Backend::available() {
+            let mut h = Blake3::new();
+            h.chunk_state.backend = backend;
+            h.update(&input);
+            assert_eq!(h.finalize_fixed(), expected, "{:?}", backend);
+        }
+        #[cfg(feature = "parallel")]
+        {
+            let mut h = Blake3::new();
+            h.update_parallel(&input);
+            assert_eq!(h.finalize_fixed(), expected);
+        }
+    }
+
+    #[test]
+    fn blake3_xof_seek() {
+        let mut out = [0; 1000];
+        let mut reader = Blake3::new().chain(b"seek").finalize_xof();
+        reader.read(&mut out);
+        assert_eq!(reader.position(), 1000);
+
+        for pos in [0, 1, 63, 64, 65, 500, 999] {
+            let mut tail = [0; 100];
+            let n = cmp::min(100, 1000 - pos);
+            reader.set_position(pos as u64);
+            reader.read(&mut tail[..n]);
+            assert_eq!(tail[..n], out[pos..pos + n]);
+        }
+    }
+
+    #[test]
+    fn blake3_reset() {
+        let mut h = Blake3::new_keyed(TEST_KEY);
+        h.update(&input(5000));
+        Reset::reset(&mut h);
+        h.update(b"abc");
+        assert_eq!(h.count(), 3);
+        assert_eq!(
+            h.finalize_fixed(),
+            Blake3::new_keyed(TEST_KEY).chain(b"abc").finalize_fixed()
+        );
+    }
+}
@@ -1,6 +1,8 @@
-use crate::{Block, BlockSizeUser, Sha1Core};
+use crate::{dc::DetectionState, Block, BlockSizeUser, Sha1Core};
 use digest::typenum::Unsigned;
 
+mod dc;
+
 cfg_if::cfg_if! {
     if #[cfg(feature = "force-soft")] {
         mod soft;
@@ -32,3 +34,13 @@ pub fn compress(state: &mut [u32; 5], blocks: &[Block<Sha1Core>]) {
         unsafe { &*(blocks as *const _ as *const [[u8; BLOCK_SIZE]]) };
     compress_inner(state, blocks);
 }
+
+pub(crate) fn compress_dc(
+    state: &mut [u32; 5],
+    ctx: &mut DetectionState,
+    blocks: &[Block<Sha1Core>],
+) {
+    let blocks: &[[u8; BLOCK_SIZE]] =
+        unsafe { &*(blocks as *const _ as *const [[u8; BLOCK_SIZE]]) };
+    dc::compress(state, ctx, blocks);
+}
@@ -0,0 +1,229 @@
+use crate::{compress::compress_dc, Sha1Core, INITIAL_H, STATE_LEN};
+use core::{fmt, slice::from_ref};
+use digest::{
+    block_buffer::{BlockBuffer, Eager},
+    core_api::AlgorithmName,
+    typenum::{Unsigned, U20, U64},
+    FixedOutput, FixedOutputReset, HashMarker, Output, OutputSizeUser, Reset, Update,
+};
+
+/// SHA-1 hasher with counter-cryptanalysis collision detection.
+///
+/// Every compressed block is checked against the known disturbance vectors
+/// used by practical SHA-1 collision attacks (SHAttered, Shambles). Use
+/// [`Sha1Dc::finalize_checked`] to learn whether an attack was detected.
+#[derive(Clone)]
+pub struct Sha1Dc {
+    h: [u32; STATE_LEN],
+    block_len: u64,
+    buffer: BlockBuffer<U64, Eager>,
+    detection: DetectionState,
+}
+
+impl Sha1Dc {
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Produce the "safe hash" when a collision is detected: the offending
+    /// block is compressed three times, so colliding inputs no longer hash
+    /// to the same value. Disabled by default.
+    pub fn safe_hash(mut self, enabled: bool) -> Self {
+        self.detection.safe_hash = enabled;
+        self
+    }
+
+    /// Use unavoidable bit conditions to skip disturbance vectors which
+    /// can not apply to the current block. Enabled by default.
+    pub fn ubc_check(mut self, enabled: bool) -> Self {
+        self.detection.ubc_check = enabled;
+        self
+    }
+
+    /// Also detect collisions for reduced-round SHA-1. Only useful for
+    /// testing the detection code. Disabled by default.
+    pub fn reduced_round_collision(mut self, enabled: bool) -> Self {
+        self.detection.reduced_round_collision = enabled;
+        self
+    }
+
+    /// Finalize the hash, returning an error if a collision attack was
+    /// detected in any of the processed blocks.
+    pub fn finalize_checked(mut self) -> Result<Output<Self>, CollisionDetected> {
+        let mut out = Output::<Self>::default();
+        self.finalize_inner(&mut out);
+        if self.detection.found_collision {
+            Err(CollisionDetected { hash: out })
+        } else {
+            Ok(out)
+        }
+    }
+
+    fn finalize_inner(&mut self, out: &mut Output<Self>) {
+        let bs = U64::U64;
+        let bit_len = 8 * (self.buffer.get_pos() as u64 + bs * self.block_len);
+
+        let Self {
+            h,
+            buffer,
+            detection,
+            ..
+        } = self;
+        buffer.len64_padding_be(bit_len, |b| compress_dc(h, detection, from_ref(b)));
+        for (chunk, v) in out.chunks_exact_mut(4).zip(h.iter()) {
+            chunk.copy_from_slice(&v.to_be_bytes());
+        }
+    }
+}
+
+impl Default for Sha1Dc {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            h: INITIAL_H,
+            block_len: 0,
+            buffer: Default::default(),
+            detection: Default::default(),
+        }
+    }
+}
+
+impl HashMarker for Sha1Dc {}
+
+impl OutputSizeUser for Sha1Dc {
+    type OutputSize = U20;
+}
+
+impl Update for Sha1Dc {
+    #[inline]
+    fn update(&mut self, input: &[u8]) {
+        let Self {
+            h,
+            block_len,
+            buffer,
+            detection,
+        } = self;
+        buffer.digest_blocks(input, |blocks| {
+            *block_len += blocks.len() as u64;
+            compress_dc(h, detection, blocks);
+        });
+    }
+}
+
+impl FixedOutput for Sha1Dc {
+    #[inline]
+    fn finalize_into(mut self, out: &mut Output<Self>) {
+        self.finalize_inner(out);
+    }
+}
+
+impl FixedOutputReset for Sha1Dc {
+    #[inline]
+    fn finalize_into_reset(&mut self, out: &mut Output<Self>) {
+        self.finalize_inner(out);
+        Reset::reset(self);
+    }
+}
+
+impl Reset for Sha1Dc {
+    #[inline]
+    fn reset(&mut self) {
+        self.h = INITIAL_H;
+        self.block_len = 0;
+        self.buffer.reset();
+        self.detection.reset();
+    }
+}
+
+impl AlgorithmName for Sha1Dc {
+    fn write_alg_name(f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        Sha1Core::write_alg_name(f)
+    }
+}
+
+impl fmt::Debug for Sha1Dc {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("Sha1Dc { ... }")
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::io::Write for Sha1Dc {
+    #[inline]
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        Update::update(self, buf);
+        Ok(buf.len())
+    }
+
+    #[inline]
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+/// Error returned by [`Sha1Dc::finalize_checked`] when the input contains
+/// a block matching a known SHA-1 collision attack.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct CollisionDetected {
+    hash: Output<Sha1Dc>,
+}
+
+impl CollisionDetected {
+    /// Hash of the input. This is the "safe hash" if [`Sha1Dc::safe_hash`]
+    /// was enabled, and the plain SHA-1 value otherwise.
+    pub fn hash(&self) -> &Output<Sha1Dc> {
+        &self.hash
+    }
+}
+
+impl fmt::Display for CollisionDetected {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("SHA-1 collision attack detected")
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for CollisionDetected {}
+
+#[derive(Clone)]
+pub(crate) struct DetectionState {
+    pub(crate) safe_hash: bool,
+    pub(crate) ubc_check: bool,
+    pub(crate) reduced_round_collision: bool,
+    pub(crate) found_collision: bool,
+    pub(crate) ihv1: [u32; STATE_LEN],
+    pub(crate) ihv2: [u32; STATE_LEN],
+    pub(crate) m1: [u32; 80],
+    pub(crate) m2: [u32; 80],
+    pub(crate) state_58: [u32; STATE_LEN],
+    pub(crate) state_65: [u32; STATE_LEN],
+}
+
+impl DetectionState {
+    fn reset(&mut self) {
+        *self = Self {
+            safe_hash: self.safe_hash,
+            ubc_check: self.ubc_check,
+            reduced_round_collision: self.reduced_round_collision,
+            ..Default::default()
+        };
+    }
+}
+
+impl Default for DetectionState {
+    fn default() -> Self {
+        Self {
+            safe_hash: false,
+            ubc_check: true,
+            reduced_round_collision: false,
+            found_collision: false,
+            ihv1: [0; STATE_LEN],
+            ihv2: [0; STATE_LEN],
+            m1: [0; 80],
+            m2: [0; 80],
+            state_58: [0; STATE_LEN],
+            state_65: [0; STATE_LEN],
+        }
+    }
+}
@@ -1,24 +1,28 @@
 pub use digest::{self, Digest};
 
-use core::{fmt, slice::from_ref};
+use core::{convert::TryInto, fmt, slice::from_ref};
 use digest::{
     block_buffer::Eager,
     core_api::{
         AlgorithmName, Block, BlockSizeUser, Buffer, BufferKindUser, CoreWrapper, FixedOutputCore,
         OutputSizeUser, Reset, UpdateCore,
     },
-    typenum::{Unsigned, U20, U64},
+    crypto_common::hazmat::{DeserializeStateError, SerializableState, SerializedState},
+    typenum::{Unsigned, U20, U28, U64},
     HashMarker, Output,
 };
 
 mod compress;
+mod dc;
 
 #[cfg(feature = "compress")]
 pub use compress::compress;
 #[cfg(not(feature = "compress"))]
 use compress::compress;
+pub use dc::{CollisionDetected, Sha1Dc};
 
 const STATE_LEN: usize = 5;
+const INITIAL_H: [u32; STATE_LEN] = [0x67452301, 0xEFCDAB89, 0x98BADCFE, 0x10325476, 0xC3D2E1F0];
 
 #[derive(Clone)]
 pub struct Sha1Core {
@@ -66,7 +70,7 @@ impl Default for Sha1Core {
     #[inline]
     fn default() -> Self {
         Self {
-            h: [0x67452301, 0xEFCDAB89, 0x98BADCFE, 0x10325476, 0xC3D2E1F0],
+            h: INITIAL_H,
             block_len: 0,
         }
     }
@@ -79,6 +83,34 @@ impl Reset for Sha1Core {
     }
 }
 
+impl SerializableState for Sha1Core {
+    type SerializedStateSize = U28;
+
+    fn serialize(&self) -> SerializedState<Self> {
+        let mut out = SerializedState::<Self>::default();
+        let (h, block_len) = out.split_at_mut(4 * STATE_LEN);
+        for (chunk, v) in h.chunks_exact_mut(4).zip(self.h.iter()) {
+            chunk.copy_from_slice(&v.to_le_bytes());
+        }
+        block_len.copy_from_slice(&self.block_len.to_le_bytes());
+        out
+    }
+
+    fn deserialize(
+        serialized_state: &SerializedState<Self>,
+    ) -> Result<Self, DeserializeStateError> {
+        let (h_bytes, block_len) = serialized_state.split_at(4 * STATE_LEN);
+        let mut h = [0u32; STATE_LEN];
+        for (v, chunk) in h.iter_mut().zip(h_bytes.chunks_exact(4)) {
+            *v = u32::from_le_bytes(chunk.try_into().unwrap());
+        }
+        Ok(Self {
+            h,
+            block_len: u64::from_le_bytes(block_len.try_into().unwrap()),
+        })
+    }
+}
+
 impl AlgorithmName for Sha1Core {
     fn write_alg_name(f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.write_str("Sha1")
@@ -92,3 +124,64 @@ impl fmt::Debug for Sha1Core {
 }
 
 pub type Sha1 = CoreWrapper<Sha1Core>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use digest::dev::{blobby::Blob4Iterator, fixed_reset_test};
+    use hex_literal::hex;
+
+    digest::new_test!(sha1_main, "sha1", Sha1, fixed_reset_test);
+    digest::new_test!(sha1dc_main, "sha1", Sha1Dc, fixed_reset_test);
+
+    #[test]
+    fn sha1dc_no_collision() {
+        let data = [0x42; 1000];
+        let expected = Sha1::digest(data);
+        for ubc in [false, true] {
+            let mut h = Sha1Dc::new().ubc_check(ubc).safe_hash(true);
+            for chunk in data.chunks(77) {
+                h.update(chunk);
+            }
+            assert_eq!(h.finalize_checked().unwrap(), expected);
+        }
+        assert_eq!(
+            Sha1Dc::digest(b"abc")[..],
+            hex!("a9993e364706816aba3e25717850c26c9cd0d89d")
+        );
+    }
+
+    #[test]
+    fn sha1dc_collision() {
+        let data = include_bytes!("data/sha1dc.blb");
+        for (i, row) in Blob4Iterator::new(data).unwrap().enumerate() {
+            let [input, hash, safe_hash, reduced] = row.unwrap();
+            let reduced = reduced[0] == 1;
+
+            assert_eq!(Sha1::digest(input)[..], hash[..], "test #{}", i);
+
+            for ubc in [false, true] {
+                let new = || {
+                    Sha1Dc::new()
+                        .ubc_check(ubc)
+                        .reduced_round_collision(reduced)
+                };
+
+                let err = new().chain_update(input).finalize_checked().unwrap_err();
+                assert_eq!(err.hash()[..], hash[..], "test #{}", i);
+
+                let h = new().safe_hash(true).chain_update(input);
+                assert_eq!(h.clone().finalize()[..], safe_hash[..], "test #{}", i);
+                let err = h.finalize_checked().unwrap_err();
+                assert_eq!(err.hash()[..], safe_hash[..], "test #{}", i);
+            }
+
+            let mut h = Sha1Dc::new().reduced_round_collision(reduced);
+            h.update(input);
+            assert!(h.clone().finalize_checked().is_err());
+            Reset::reset(&mut h);
+            h.update(b"abc");
+            assert!(h.finalize_checked().is_ok());
+        }
+    }
+}
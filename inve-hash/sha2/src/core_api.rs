@@ -1,12 +1,13 @@
 use crate::{consts, sha256::compress256, sha512::compress512};
-use core::{fmt, slice::from_ref};
+use core::{convert::TryInto, fmt, slice::from_ref};
 use digest::{
     block_buffer::Eager,
     core_api::{
         AlgorithmName, Block, BlockSizeUser, Buffer, BufferKindUser, OutputSizeUser, TruncSide,
         UpdateCore, VariableOutputCore,
     },
-    typenum::{Unsigned, U128, U32, U64},
+    crypto_common::hazmat::{DeserializeStateError, SerializableState, SerializedState},
+    typenum::{Unsigned, U128, U32, U40, U64, U80},
     HashMarker, InvalidOutputSize, Output,
 };
 
@@ -64,6 +65,34 @@ impl VariableOutputCore for Sha256VarCore {
     }
 }
 
+impl SerializableState for Sha256VarCore {
+    type SerializedStateSize = U40;
+
+    fn serialize(&self) -> SerializedState<Self> {
+        let mut out = SerializedState::<Self>::default();
+        let (state, block_len) = out.split_at_mut(32);
+        for (chunk, v) in state.chunks_exact_mut(4).zip(self.state.iter()) {
+            chunk.copy_from_slice(&v.to_le_bytes());
+        }
+        block_len.copy_from_slice(&self.block_len.to_le_bytes());
+        out
+    }
+
+    fn deserialize(
+        serialized_state: &SerializedState<Self>,
+    ) -> Result<Self, DeserializeStateError> {
+        let (state_bytes, block_len) = serialized_state.split_at(32);
+        let mut state = consts::State256::default();
+        for (v, chunk) in state.iter_mut().zip(state_bytes.chunks_exact(4)) {
+            *v = u32::from_le_bytes(chunk.try_into().unwrap());
+        }
+        Ok(Self {
+            state,
+            block_len: u64::from_le_bytes(block_len.try_into().unwrap()),
+        })
+    }
+}
+
 impl AlgorithmName for Sha256VarCore {
     #[inline]
     fn write_alg_name(f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -78,6 +107,11 @@ impl fmt::Debug for Sha256VarCore {
     }
 }
 
+/// Core of the SHA-512 family.
+///
+/// Output sizes of 28, 32, 48 and 64 bytes use the standard initial values.
+/// Any other size below 64 bytes produces SHA-512/t as defined in FIPS 180-4,
+/// with the initial value generated from `t`.
 #[derive(Clone)]
 pub struct Sha512VarCore {
     state: consts::State512,
@@ -116,6 +150,7 @@ impl VariableOutputCore for Sha512VarCore {
             32 => consts::H512_256,
             48 => consts::H512_384,
             64 => consts::H512_512,
+            1..=63 => sha512_t_iv(8 * output_size),
             _ => return Err(InvalidOutputSize),
         };
         let block_len = 0;
@@ -134,6 +169,56 @@ impl VariableOutputCore for Sha512VarCore {
     }
 }
 
+pub(crate) fn sha512_t_iv(t: usize) -> consts::State512 {
+    let mut state = consts::H512_512;
+    for v in state.iter_mut() {
+        *v ^= 0xa5a5_a5a5_a5a5_a5a5;
+    }
+
+    let mut block = Block::<Sha512VarCore>::default();
+    block[..8].copy_from_slice(b"SHA-512/");
+    let mut pos = 8;
+    for div in [100, 10, 1] {
+        if t >= div || div == 1 {
+            block[pos] = b'0' + (t / div % 10) as u8;
+            pos += 1;
+        }
+    }
+    block[pos] = 0x80;
+    block[112..].copy_from_slice(&(8 * pos as u128).to_be_bytes());
+
+    compress512(&mut state, from_ref(&block));
+    state
+}
+
+impl SerializableState for Sha512VarCore {
+    type SerializedStateSize = U80;
+
+    fn serialize(&self) -> SerializedState<Self> {
+        let mut out = SerializedState::<Self>::default();
+        let (state, block_len) = out.split_at_mut(64);
+        for (chunk, v) in state.chunks_exact_mut(8).zip(self.state.iter()) {
+            chunk.copy_from_slice(&v.to_le_bytes());
+        }
+        block_len.copy_from_slice(&self.block_len.to_le_bytes());
+        out
+    }
+
+    fn deserialize(
+        serialized_state: &SerializedState<Self>,
+    ) -> Result<Self, DeserializeStateError> {
+        let (state_bytes, block_len) = serialized_state.split_at(64);
+        let mut state = consts::State512::default();
+        for (v, chunk) in state.iter_mut().zip(state_bytes.chunks_exact(8)) {
+            *v = u64::from_le_bytes(chunk.try_into().unwrap());
+        }
+        Ok(Self {
+            state,
+            block_len: u128::from_le_bytes(block_len.try_into().unwrap()),
+        })
+    }
+}
+
 impl AlgorithmName for Sha512VarCore {
     #[inline]
     fn write_alg_name(f: &mut fmt::Formatter<'_>) -> fmt::Result {
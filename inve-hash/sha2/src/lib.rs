@@ -8,6 +8,7 @@ use digest::{
 #[rustfmt::skip]
 mod consts;
 mod core_api;
+mod multi;
 mod sha256;
 mod sha512;
 
@@ -17,6 +18,9 @@ pub use sha256::compress256;
 pub use sha512::compress512;
 
 pub use core_api::{Sha256VarCore, Sha512VarCore};
+#[cfg(feature = "alloc")]
+pub use multi::sha256_digest_many;
+pub use multi::sha256_hash_pairs;
 
 pub type Sha224 = CoreWrapper<CtVariableCoreWrapper<Sha256VarCore, U28>>;
 pub type Sha256 = CoreWrapper<CtVariableCoreWrapper<Sha256VarCore, U32>>;
@@ -24,3 +28,132 @@ pub type Sha512_224 = CoreWrapper<CtVariableCoreWrapper<Sha512VarCore, U28>>;
 pub type Sha512_256 = CoreWrapper<CtVariableCoreWrapper<Sha512VarCore, U32>>;
 pub type Sha384 = CoreWrapper<CtVariableCoreWrapper<Sha512VarCore, U48>>;
 pub type Sha512 = CoreWrapper<CtVariableCoreWrapper<Sha512VarCore, U64>>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use digest::{
+        core_api::{RtVariableCoreWrapper, VariableOutputCore},
+        Update, VariableOutput,
+    };
+    use hex_literal::hex;
+
+    fn sha512_t(output_size: usize, data: &[u8]) -> [u8; 64] {
+        let mut h = RtVariableCoreWrapper::<Sha512VarCore>::new(output_size).unwrap();
+        h.update(data);
+        let mut out = [0u8; 64];
+        h.finalize_variable(&mut out[..output_size]).unwrap();
+        out
+    }
+
+    #[test]
+    fn sha512_t_standard() {
+        for (n, expected) in [
+            (28, Sha512_224::digest(b"abc").to_vec()),
+            (32, Sha512_256::digest(b"abc").to_vec()),
+        ] {
+            assert_eq!(&sha512_t(n, b"abc")[..n], &expected[..]);
+        }
+    }
+
+    #[test]
+    #[rustfmt::skip]
+    fn sha512_t_generated() {
+        assert_eq!(sha512_t(1, b"abc")[..1], hex!("c5"));
+        assert_eq!(
+            sha512_t(20, b"abc")[..20],
+            hex!("0a74fe1b43eecbea62182658da8a68b8acef25bf")
+        );
+        assert_eq!(
+            sha512_t(63, b"abc")[..63],
+            hex!("
+                8c43e4bf1cad93067af1ad632ba38bba0b5673bf0129f01a469224c2d981b8ec
+                aa301facf8e392f97efc5997885a1c90cefba70d81892f40267df4fd6fef9a
+            ")
+        );
+        assert!(Sha512VarCore::new(0).is_err());
+        assert!(Sha512VarCore::new(65).is_err());
+    }
+
+    #[test]
+    fn sha512_t_iv_matches_constants() {
+        assert_eq!(core_api::sha512_t_iv(224), consts::H512_224);
+        assert_eq!(core_api::sha512_t_iv(256), consts::H512_256);
+    }
+
+    #[test]
+    #[rustfmt::skip]
+    fn sha512_multi_block() {
+        let data = [0x61u8; 1000];
+        let mut h = Sha512::new();
+        for chunk in data.chunks(129) {
+            Digest::update(&mut h, chunk);
+        }
+        assert_eq!(
+            h.finalize()[..],
+            hex!("
+                67ba5535a46e3f86dbfbed8cbbaf0125c76ed549ff8b0b9e03e0c88cf90fa634
+                fa7b12b47d77b694de488ace8d9a65967dc96df599727d3292a8d9d447709c97
+            ")[..]
+        );
+    }
+
+    #[test]
+    fn sha256_many() {
+        let data: Vec<u8> = (0..1000u32).map(|i| i as u8).collect();
+        let msgs: Vec<&[u8]> = (0..300)
+            .map(|i| &data[i % 7..i % 7 + (i * 13) % 300])
+            .collect();
+        let expected: Vec<[u8; 32]> = msgs.iter().map(|m| Sha256::digest(m).into()).collect();
+
+        #[cfg(feature = "alloc")]
+        {
+            let res = sha256_digest_many(&msgs);
+            assert!(res
+                .iter()
+                .map(|o| <[u8; 32]>::from(*o))
+                .eq(expected.iter().copied()));
+            assert!(sha256_digest_many(&[]).is_empty());
+        }
+        for lanes in [1, 4, 8, 16] {
+            if let Some(res) = multi::digest_many_with(lanes, &msgs) {
+                assert_eq!(res, expected, "{} lanes", lanes);
+            }
+        }
+    }
+
+    #[test]
+    fn sha256_pairs() {
+        let pairs: Vec<[u8; 64]> = (0..37u8).map(|i| [i; 64]).collect();
+        let mut out = vec![[0u8; 32]; pairs.len()];
+        sha256_hash_pairs(&pairs, &mut out);
+        for (p, o) in pairs.iter().zip(out.iter()) {
+            assert_eq!(Sha256::digest(p)[..], o[..]);
+        }
+    }
+
+    /// Checks that `backend` computes the same compression as `soft` for
+    /// every number of blocks up to five.
+    #[allow(dead_code)] // unused when only the portable backend is built
+    pub(crate) fn check_compress<W, const B: usize>(
+        state: [W; 8],
+        soft: fn(&mut [W; 8], &[[u8; B]]),
+        backend: impl Fn(&mut [W; 8], &[[u8; B]]),
+    ) where
+        W: Copy + PartialEq + core::fmt::Debug,
+    {
+        let mut blocks = [[0u8; B]; 5];
+        for (i, b) in blocks.iter_mut().flatten().enumerate() {
+            *b = (i as u8).wrapping_mul(73).wrapping_add(5);
+        }
+
+        for n in 0..=blocks.len() {
+            let mut expected = state;
+            soft(&mut expected, &blocks[..n]);
+
+            let mut actual = state;
+            backend(&mut actual, &blocks[..n]);
+            assert_eq!(actual, expected);
+        }
+    }
+}
@@ -0,0 +1,134 @@
+use crate::{consts, sha256::compress256};
+use digest::generic_array::GenericArray;
+
+#[cfg(feature = "alloc")]
+use crate::Sha256;
+#[cfg(feature = "alloc")]
+use digest::Output;
+
+cfg_if::cfg_if! {
+    if #[cfg(all(
+        any(target_arch = "x86", target_arch = "x86_64"),
+        not(feature = "force-soft"),
+    ))] {
+        mod lanes;
+        mod x86;
+
+        use lanes::digest_lanes;
+
+        cpufeatures::new!(avx512_cpuid, "avx512f");
+        cpufeatures::new!(avx2_cpuid, "avx2");
+        cpufeatures::new!(sse2_cpuid, "sse2");
+    }
+}
+
+/// Compute SHA-256 digests of many independent messages.
+///
+/// Messages are interleaved over 16, 8 or 4 SIMD lanes (AVX-512, AVX2 or
+/// SSE2) depending on the CPU, falling back to hashing them one by one.
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub fn sha256_digest_many(messages: &[&[u8]]) -> Vec<Output<Sha256>> {
+    let mut out = vec![[0u8; 32]; messages.len()];
+    digest_many(messages.len(), |i| messages[i], &mut out);
+    out.into_iter().map(Output::<Sha256>::from).collect()
+}
+
+/// Compute `SHA-256(left || right)` for every 64-byte pair, e.g. for the
+/// internal nodes of a Merkle tree.
+///
+/// # Panics
+/// If `pairs` and `out` have different lengths.
+pub fn sha256_hash_pairs(pairs: &[[u8; 64]], out: &mut [[u8; 32]]) {
+    assert_eq!(pairs.len(), out.len());
+    digest_many(pairs.len(), |i| &pairs[i], out);
+}
+
+fn digest_many<'a>(n: usize, get: impl Fn(usize) -> &'a [u8], out: &mut [[u8; 32]]) {
+    #[cfg(all(
+        any(target_arch = "x86", target_arch = "x86_64"),
+        not(feature = "force-soft"),
+    ))]
+    {
+        if avx512_cpuid::get() {
+            return digest_lanes::<16>(n, get, out, x86::compress_avx512);
+        }
+        if avx2_cpuid::get() {
+            return digest_lanes::<8>(n, get, out, x86::compress_avx2);
+        }
+        if sse2_cpuid::get() {
+            return digest_lanes::<4>(n, get, out, x86::compress_sse2);
+        }
+    }
+    digest_soft(n, get, out)
+}
+
+fn digest_soft<'a>(n: usize, get: impl Fn(usize) -> &'a [u8], out: &mut [[u8; 32]]) {
+    for (i, o) in out.iter_mut().enumerate().take(n) {
+        let data = get(i);
+        let mut state = consts::H256_256;
+        for j in 0..block_count(data.len()) {
+            let block = message_block(data, j);
+            compress256(&mut state, &[GenericArray::from(block)]);
+        }
+        for (chunk, v) in o.chunks_exact_mut(4).zip(state.iter()) {
+            chunk.copy_from_slice(&v.to_be_bytes());
+        }
+    }
+}
+
+fn block_count(len: usize) -> usize {
+    (len + 8) / 64 + 1
+}
+
+/// Block `i` of the padded message.
+fn message_block(data: &[u8], i: usize) -> [u8; 64] {
+    let mut block = [0u8; 64];
+    let start = 64 * i;
+    if start + 64 <= data.len() {
+        block.copy_from_slice(&data[start..start + 64]);
+        return block;
+    }
+    if start <= data.len() {
+        let tail = &data[start..];
+        block[..tail.len()].copy_from_slice(tail);
+        block[tail.len()] = 0x80;
+    }
+    if i + 1 == block_count(data.len()) {
+        let bit_len = 8 * data.len() as u64;
+        block[56..].copy_from_slice(&bit_len.to_be_bytes());
+    }
+    block
+}
+
+#[cfg(test)]
+pub(crate) fn digest_many_with(lanes: usize, messages: &[&[u8]]) -> Option<Vec<[u8; 32]>> {
+    let mut out = vec![[0u8; 32]; messages.len()];
+    let get = |i| messages[i];
+    match lanes {
+        1 => digest_soft(messages.len(), get, &mut out),
+        #[cfg(all(
+            any(target_arch = "x86", target_arch = "x86_64"),
+            not(feature = "force-soft"),
+        ))]
+        4 if sse2_cpuid::get() => {
+            digest_lanes::<4>(messages.len(), get, &mut out, x86::compress_sse2)
+        }
+        #[cfg(all(
+            any(target_arch = "x86", target_arch = "x86_64"),
+            not(feature = "force-soft"),
+        ))]
+        8 if avx2_cpuid::get() => {
+            digest_lanes::<8>(messages.len(), get, &mut out, x86::compress_avx2)
+        }
+        #[cfg(all(
+            any(target_arch = "x86", target_arch = "x86_64"),
+            not(feature = "force-soft"),
+        ))]
+        16 if avx512_cpuid::get() => {
+            digest_lanes::<16>(messages.len(), get, &mut out, x86::compress_avx512)
+        }
+        _ => return None,
+    }
+    Some(out)
+}
@@ -0,0 +1,68 @@
+use super::{block_count, message_block};
+use crate::consts;
+use core::convert::TryInto;
+
+pub(super) type Compress<const N: usize> = unsafe fn(&mut [[u32; N]; 8], &[[u32; N]; 16]);
+
+#[derive(Clone, Copy)]
+struct Lane {
+    msg: usize,
+    block: usize,
+    blocks: usize,
+}
+
+/// Process messages in `N` lanes. A lane is refilled with the next message
+/// as soon as its current one is finished, so messages of different lengths
+/// keep all lanes busy.
+pub(super) fn digest_lanes<'a, const N: usize>(
+    n: usize,
+    get: impl Fn(usize) -> &'a [u8],
+    out: &mut [[u8; 32]],
+    compress: Compress<N>,
+) {
+    let mut lanes: [Option<Lane>; N] = [None; N];
+    let mut state = [[0u32; N]; 8];
+    let mut w = [[0u32; N]; 16];
+    let mut next = 0;
+
+    loop {
+        let mut active = false;
+        for (l, lane) in lanes.iter_mut().enumerate() {
+            if lane.is_none() && next < n {
+                *lane = Some(Lane {
+                    msg: next,
+                    block: 0,
+                    blocks: block_count(get(next).len()),
+                });
+                for (s, h) in state.iter_mut().zip(consts::H256_256.iter()) {
+                    s[l] = *h;
+                }
+                next += 1;
+            }
+            if let Some(lane) = lane {
+                let block = message_block(get(lane.msg), lane.block);
+                for (t, chunk) in w.iter_mut().zip(block.chunks_exact(4)) {
+                    t[l] = u32::from_be_bytes(chunk.try_into().unwrap());
+                }
+                active = true;
+            }
+        }
+        if !active {
+            break;
+        }
+
+        unsafe { compress(&mut state, &w) };
+
+        for (l, slot) in lanes.iter_mut().enumerate() {
+            if let Some(lane) = slot {
+                lane.block += 1;
+                if lane.block == lane.blocks {
+                    for (chunk, s) in out[lane.msg].chunks_exact_mut(4).zip(state.iter()) {
+                        chunk.copy_from_slice(&s[l].to_be_bytes());
+                    }
+                    *slot = None;
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,122 @@
+#![allow(clippy::many_single_char_names)]
+
+#[cfg(target_arch = "x86")]
+use core::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use core::arch::x86_64::*;
+
+use crate::consts::K32;
+
+macro_rules! impl_compress {
+    (
+        $name:ident, $feature:literal, $lanes:literal, $v:ty,
+        load = $load:ident, store = $store:ident, set1 = $set1:ident,
+        add = $add:ident, xor = $xor:ident, and = $and:ident, andnot = $andnot:ident,
+        or = $or:ident, srli = $srli:ident, slli = $slli:ident,
+    ) => {
+        #[target_feature(enable = $feature)]
+        pub(super) unsafe fn $name(state: &mut [[u32; $lanes]; 8], block: &[[u32; $lanes]; 16]) {
+            macro_rules! rotr {
+                ($x:expr, $n:literal) => {
+                    $or($srli($x, $n), $slli($x, 32 - $n))
+                };
+            }
+
+            let mut s = [$set1(0); 8];
+            for (s, v) in s.iter_mut().zip(state.iter()) {
+                *s = $load(v.as_ptr() as *const _);
+            }
+            let mut w = [$set1(0); 16];
+            for (w, v) in w.iter_mut().zip(block.iter()) {
+                *w = $load(v.as_ptr() as *const _);
+            }
+
+            let [mut a, mut b, mut c, mut d, mut e, mut f, mut g, mut h] = s;
+            for (i, &k) in K32.iter().enumerate() {
+                let wi = if i < 16 {
+                    w[i]
+                } else {
+                    let w15 = w[(i + 1) % 16];
+                    let w2 = w[(i + 14) % 16];
+                    let s0 = $xor($xor(rotr!(w15, 7), rotr!(w15, 18)), $srli(w15, 3));
+                    let s1 = $xor($xor(rotr!(w2, 17), rotr!(w2, 19)), $srli(w2, 10));
+                    let wi = $add($add(w[i % 16], s0), $add(w[(i + 9) % 16], s1));
+                    w[i % 16] = wi;
+                    wi
+                };
+
+                let s1 = $xor($xor(rotr!(e, 6), rotr!(e, 11)), rotr!(e, 25));
+                let ch = $xor($and(e, f), $andnot(e, g));
+                let t1 = $add($add($add(h, s1), $add(ch, $set1(k as i32))), wi);
+                let s0 = $xor($xor(rotr!(a, 2), rotr!(a, 13)), rotr!(a, 22));
+                let maj = $xor($xor($and(a, b), $and(a, c)), $and(b, c));
+                let t2 = $add(s0, maj);
+
+                h = g;
+                g = f;
+                f = e;
+                e = $add(d, t1);
+                d = c;
+                c = b;
+                b = a;
+                a = $add(t1, t2);
+            }
+
+            for (v, x) in state.iter_mut().zip([a, b, c, d, e, f, g, h].iter()) {
+                let x = $add($load(v.as_ptr() as *const _), *x);
+                $store(v.as_mut_ptr() as *mut _, x);
+            }
+        }
+    };
+}
+
+impl_compress!(
+    compress_sse2,
+    "sse2",
+    4,
+    __m128i,
+    load = _mm_loadu_si128,
+    store = _mm_storeu_si128,
+    set1 = _mm_set1_epi32,
+    add = _mm_add_epi32,
+    xor = _mm_xor_si128,
+    and = _mm_and_si128,
+    andnot = _mm_andnot_si128,
+    or = _mm_or_si128,
+    srli = _mm_srli_epi32,
+    slli = _mm_slli_epi32,
+);
+
+impl_compress!(
+    compress_avx2,
+    "avx2",
+    8,
+    __m256i,
+    load = _mm256_loadu_si256,
+    store = _mm256_storeu_si256,
+    set1 = _mm256_set1_epi32,
+    add = _mm256_add_epi32,
+    xor = _mm256_xor_si256,
+    and = _mm256_and_si256,
+    andnot = _mm256_andnot_si256,
+    or = _mm256_or_si256,
+    srli = _mm256_srli_epi32,
+    slli = _mm256_slli_epi32,
+);
+
+impl_compress!(
+    compress_avx512,
+    "avx512f",
+    16,
+    __m512i,
+    load = _mm512_loadu_si512,
+    store = _mm512_storeu_si512,
+    set1 = _mm512_set1_epi32,
+    add = _mm512_add_epi32,
+    xor = _mm512_xor_si512,
+    and = _mm512_and_si512,
+    andnot = _mm512_andnot_si512,
+    or = _mm512_or_si512,
+    srli = _mm512_srli_epi32,
+    slli = _mm512_slli_epi32,
+);
@@ -9,8 +9,43 @@ use crate::consts::K64;
 
 cpufeatures::new!(avx2_cpuid, "avx2");
 
+/// `cpufeatures` does not know the SHA512 extension (CPUID leaf 7, sub-leaf 1,
+/// EAX bit 0), so it is detected here on top of the AVX2 check.
+mod sha512_cpuid {
+    #[cfg(target_arch = "x86")]
+    use core::arch::x86::__cpuid_count;
+    #[cfg(target_arch = "x86_64")]
+    use core::arch::x86_64::__cpuid_count;
+    use core::sync::atomic::{AtomicU8, Ordering::Relaxed};
+
+    const UNINIT: u8 = u8::MAX;
+    static STORAGE: AtomicU8 = AtomicU8::new(UNINIT);
+
+    #[cold]
+    fn init() -> bool {
+        // AVX2 support implies that CPUID leaf 7 is available
+        #[allow(unused_unsafe)]
+        let res = super::avx2_cpuid::get()
+            && unsafe { __cpuid_count(7, 0).eax >= 1 && __cpuid_count(7, 1).eax & 1 != 0 };
+        STORAGE.store(res as u8, Relaxed);
+        res
+    }
+
+    #[inline]
+    pub fn get() -> bool {
+        match STORAGE.load(Relaxed) {
+            UNINIT => init(),
+            val => val == 1,
+        }
+    }
+}
+
 pub fn compress(state: &mut [u64; 8], blocks: &[[u8; 128]]) {
-    if avx2_cpuid::get() {
+    if sha512_cpuid::get() {
+        unsafe {
+            sha512_compress_x86_64_sha512(state, blocks);
+        }
+    } else if avx2_cpuid::get() {
         unsafe {
             sha512_compress_x86_64_avx2(state, blocks);
         }
@@ -19,6 +54,70 @@ pub fn compress(state: &mut [u64; 8], blocks: &[[u8; 128]]) {
     }
 }
 
+#[inline(always)]
+unsafe fn sha512_schedule_x4(w0: __m256i, w1: __m256i, w2: __m256i, w3: __m256i) -> __m256i {
+    let t1 = _mm256_sha512msg1_epi64(w0, _mm256_castsi256_si128(w1));
+    let t2 = _mm256_permute4x64_epi64(_mm256_blend_epi32(w2, w3, 0x03), 0x39);
+    _mm256_sha512msg2_epi64(_mm256_add_epi64(t1, t2), w3)
+}
+
+macro_rules! sha512_rounds4 {
+    ($abef:ident, $cdgh:ident, $w:expr, $i:expr) => {{
+        let k = _mm256_loadu_si256(K64.as_ptr().add(4 * $i) as *const _);
+        let wk = _mm256_add_epi64($w, k);
+        $cdgh = _mm256_sha512rnds2_epi64($cdgh, $abef, _mm256_castsi256_si128(wk));
+        $abef = _mm256_sha512rnds2_epi64($abef, $cdgh, _mm256_extracti128_si256(wk, 1));
+    }};
+}
+
+#[target_feature(enable = "sha512,avx,avx2")]
+unsafe fn sha512_compress_x86_64_sha512(state: &mut [u64; 8], blocks: &[[u8; 128]]) {
+    #[allow(non_snake_case)]
+    let MASK = _mm256_set_epi64x(
+        0x0809_0A0B_0C0D_0E0F_i64,
+        0x0001_0203_0405_0607_i64,
+        0x0809_0A0B_0C0D_0E0F_i64,
+        0x0001_0203_0405_0607_i64,
+    );
+
+    let [a, b, c, d, e, f, g, h] = state.map(|v| v as i64);
+    let mut abef = _mm256_set_epi64x(a, b, e, f);
+    let mut cdgh = _mm256_set_epi64x(c, d, g, h);
+
+    for block in blocks {
+        let abef_save = abef;
+        let cdgh_save = cdgh;
+
+        let data_ptr = block.as_ptr() as *const __m256i;
+        let mut w = [
+            _mm256_shuffle_epi8(_mm256_loadu_si256(data_ptr.add(0)), MASK),
+            _mm256_shuffle_epi8(_mm256_loadu_si256(data_ptr.add(1)), MASK),
+            _mm256_shuffle_epi8(_mm256_loadu_si256(data_ptr.add(2)), MASK),
+            _mm256_shuffle_epi8(_mm256_loadu_si256(data_ptr.add(3)), MASK),
+        ];
+
+        for (i, &wi) in w.iter().enumerate() {
+            sha512_rounds4!(abef, cdgh, wi, i);
+        }
+        for i in 4..20 {
+            let j = i % 4;
+            w[j] = sha512_schedule_x4(w[j], w[(j + 1) % 4], w[(j + 2) % 4], w[(j + 3) % 4]);
+            sha512_rounds4!(abef, cdgh, w[j], i);
+        }
+
+        abef = _mm256_add_epi64(abef, abef_save);
+        cdgh = _mm256_add_epi64(cdgh, cdgh_save);
+    }
+
+    let mut fe_ba = [0u64; 4];
+    let mut hg_dc = [0u64; 4];
+    _mm256_storeu_si256(fe_ba.as_mut_ptr() as *mut _, abef);
+    _mm256_storeu_si256(hg_dc.as_mut_ptr() as *mut _, cdgh);
+    *state = [
+        fe_ba[3], fe_ba[2], hg_dc[3], hg_dc[2], fe_ba[1], fe_ba[0], hg_dc[1], hg_dc[0],
+    ];
+}
+
 #[target_feature(enable = "avx2")]
 unsafe fn sha512_compress_x86_64_avx2(state: &mut [u64; 8], blocks: &[[u8; 128]]) {
     let mut start_block = 0;
@@ -318,3 +417,26 @@ const SHA512_ROUNDS_NUM: usize = 80;
 const SHA512_HASH_BYTE_LEN: usize = 64;
 const SHA512_HASH_WORDS_NUM: usize = SHA512_HASH_BYTE_LEN / size_of::<u64>();
 const SHA512_BLOCK_WORDS_NUM: usize = SHA512_BLOCK_BYTE_LEN / size_of::<u64>();
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::check_compress;
+
+    const STATE: [u64; 8] = [0x0123_4567_89ab_cdef; 8];
+
+    #[test]
+    fn backends_match_soft() {
+        let soft = super::super::soft::compress;
+        if sha512_cpuid::get() {
+            check_compress(STATE, soft, |s, b| unsafe {
+                sha512_compress_x86_64_sha512(s, b)
+            });
+        }
+        if avx2_cpuid::get() {
+            check_compress(STATE, soft, |s, b| unsafe {
+                sha512_compress_x86_64_avx2(s, b)
+            });
+        }
+    }
+}
@@ -0,0 +1,208 @@
+use core::{cmp::min, fmt, mem};
+use digest::{ExtendableOutput, Update};
+
+use crate::{TurboShake128, TurboShake128Core, TurboShake128Reader};
+
+const CHUNK_SIZE: usize = 8192;
+const CHAINING_VALUE_SIZE: usize = 32;
+
+const SINGLE_NODE_DOMAIN: u8 = 0x07;
+const FINAL_NODE_DOMAIN: u8 = 0x06;
+const LEAF_DOMAIN: u8 = 0x0B;
+
+#[cfg(feature = "parallel")]
+const PARALLEL_MIN_CHUNKS: usize = 16;
+
+/// KangarooTwelve (KT128) extendable output function.
+///
+/// Input is split into 8 KiB chunks which are hashed independently with
+/// TurboSHAKE128. With the `parallel` feature enabled, large updates hash
+/// their chunks on the rayon thread pool.
+#[derive(Clone)]
+pub struct KangarooTwelve<'cs> {
+    customization: &'cs [u8],
+    first_chunk: [u8; CHUNK_SIZE],
+    first_chunk_len: usize,
+    node: Option<TurboShake128>,
+    leaf: TurboShake128,
+    leaf_len: usize,
+    leaves: u64,
+}
+
+impl<'cs> KangarooTwelve<'cs> {
+    pub fn new(customization: &'cs [u8]) -> Self {
+        Self {
+            customization,
+            first_chunk: [0; CHUNK_SIZE],
+            first_chunk_len: 0,
+            node: None,
+            leaf: new_leaf(),
+            leaf_len: 0,
+            leaves: 0,
+        }
+    }
+
+    fn absorb(&mut self, mut data: &[u8]) {
+        let node = match &mut self.node {
+            Some(node) => node,
+            None => {
+                let n = min(CHUNK_SIZE - self.first_chunk_len, data.len());
+                let (head, tail) = data.split_at(n);
+                self.first_chunk[self.first_chunk_len..][..n].copy_from_slice(head);
+                self.first_chunk_len += n;
+                data = tail;
+                if data.is_empty() {
+                    return;
+                }
+
+                let mut node = TurboShake128::from_core(TurboShake128Core::new(FINAL_NODE_DOMAIN));
+                node.update(&self.first_chunk);
+                node.update(&[0x03, 0, 0, 0, 0, 0, 0, 0]);
+                self.node.insert(node)
+            }
+        };
+
+        if self.leaf_len != 0 {
+            let n = min(CHUNK_SIZE - self.leaf_len, data.len());
+            let (head, tail) = data.split_at(n);
+            self.leaf.update(head);
+            self.leaf_len += n;
+            data = tail;
+            if self.leaf_len != CHUNK_SIZE {
+                return;
+            }
+            let leaf = mem::replace(&mut self.leaf, new_leaf());
+            node.update(&chaining_value(leaf));
+            self.leaf_len = 0;
+            self.leaves += 1;
+        }
+
+        let n = data.len() / CHUNK_SIZE * CHUNK_SIZE;
+        let (chunks, tail) = data.split_at(n);
+        self.leaves += absorb_chunks(node, chunks);
+
+        self.leaf.update(tail);
+        self.leaf_len = tail.len();
+    }
+}
+
+impl<'cs> Default for KangarooTwelve<'cs> {
+    fn default() -> Self {
+        Self::new(&[])
+    }
+}
+
+impl<'cs> Update for KangarooTwelve<'cs> {
+    fn update(&mut self, data: &[u8]) {
+        self.absorb(data);
+    }
+}
+
+impl<'cs> ExtendableOutput for KangarooTwelve<'cs> {
+    type Reader = TurboShake128Reader;
+
+    fn finalize_xof(mut self) -> Self::Reader {
+        let customization = self.customization;
+        self.absorb(customization);
+        let mut b = [0u8; 9];
+        self.absorb(length_encode(customization.len() as u64, &mut b));
+
+        match self.node {
+            None => {
+                let mut node = TurboShake128::from_core(TurboShake128Core::new(SINGLE_NODE_DOMAIN));
+                node.update(&self.first_chunk[..self.first_chunk_len]);
+                node.finalize_xof()
+            }
+            Some(mut node) => {
+                if self.leaf_len != 0 {
+                    node.update(&chaining_value(self.leaf));
+                    self.leaves += 1;
+                }
+                node.update(length_encode(self.leaves, &mut b));
+                node.update(&[0xFF, 0xFF]);
+                node.finalize_xof()
+            }
+        }
+    }
+}
+
+impl<'cs> fmt::Debug for KangarooTwelve<'cs> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("KangarooTwelve { ... }")
+    }
+}
+
+fn new_leaf() -> TurboShake128 {
+    TurboShake128::from_core(TurboShake128Core::new(LEAF_DOMAIN))
+}
+
+fn chaining_value(leaf: TurboShake128) -> [u8; CHAINING_VALUE_SIZE] {
+    let mut cv = [0u8; CHAINING_VALUE_SIZE];
+    leaf.finalize_xof_into(&mut cv);
+    cv
+}
+
+fn leaf_chaining_value(chunk: &[u8]) -> [u8; CHAINING_VALUE_SIZE] {
+    let mut leaf = new_leaf();
+    leaf.update(chunk);
+    chaining_value(leaf)
+}
+
+// Hashes whole chunks as leaves and absorbs their chaining values into `node`.
+#[cfg(not(feature = "parallel"))]
+fn absorb_chunks(node: &mut TurboShake128, chunks: &[u8]) -> u64 {
+    absorb_chunks_serial(node, chunks)
+}
+
+#[cfg(feature = "parallel")]
+fn absorb_chunks(node: &mut TurboShake128, chunks: &[u8]) -> u64 {
+    use rayon::prelude::*;
+
+    let n = chunks.len() / CHUNK_SIZE;
+    if n < PARALLEL_MIN_CHUNKS {
+        return absorb_chunks_serial(node, chunks);
+    }
+
+    let cvs: Vec<_> = chunks
+        .par_chunks_exact(CHUNK_SIZE)
+        .map(leaf_chaining_value)
+        .collect();
+    for cv in cvs.iter() {
+        node.update(cv);
+    }
+    n as u64
+}
+
+fn absorb_chunks_serial(node: &mut TurboShake128, chunks: &[u8]) -> u64 {
+    for chunk in chunks.chunks_exact(CHUNK_SIZE) {
+        node.update(&leaf_chaining_value(chunk));
+    }
+    (chunks.len() / CHUNK_SIZE) as u64
+}
+
+fn length_encode(val: u64, b: &mut [u8; 9]) -> &[u8] {
+    b[..8].copy_from_slice(&val.to_be_bytes());
+    let i = b[..8].iter().take_while(|&&a| a == 0).count();
+    b[8] = (8 - i) as u8;
+    &b[i..]
+}
+
+#[cfg(all(test, feature = "parallel"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parallel_matches_serial() {
+        let data: Vec<u8> = (0..(2 * PARALLEL_MIN_CHUNKS + 1) * CHUNK_SIZE)
+            .map(|i| (i % 251) as u8)
+            .collect();
+
+        let mut parallel = new_leaf();
+        let mut serial = new_leaf();
+        assert_eq!(
+            absorb_chunks(&mut parallel, &data),
+            absorb_chunks_serial(&mut serial, &data)
+        );
+        assert_eq!(chaining_value(parallel), chaining_value(serial));
+    }
+}
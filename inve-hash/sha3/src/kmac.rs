@@ -0,0 +1,104 @@
+use core::fmt;
+use digest::{
+    consts::{U32, U64},
+    core_api::BlockSizeUser,
+    crypto_common::{InvalidLength, Key, KeyInit, KeySizeUser},
+    typenum::Unsigned,
+    ExtendableOutput, FixedOutput, MacMarker, Output, OutputSizeUser, Update,
+};
+
+use crate::{
+    encode_string, left_encode, right_encode, CShake128, CShake128Core, CShake128Reader, CShake256,
+    CShake256Core, CShake256Reader,
+};
+
+macro_rules! impl_kmac {
+    (
+        $name:ident, $cshake:ident, $cshake_core:ident, $reader:ident,
+        $key_size:ident, $output_size:ident $(,)?
+    ) => {
+        #[derive(Clone)]
+        pub struct $name {
+            cshake: $cshake,
+        }
+
+        impl $name {
+            pub fn new_customized(key: &[u8], customization: &[u8]) -> Self {
+                let mut cshake = $cshake::from_core($cshake_core::new_with_function_name(
+                    b"KMAC",
+                    customization,
+                ));
+
+                // bytepad(encode_string(K), rate)
+                let rate = <$cshake_core as BlockSizeUser>::BlockSize::USIZE;
+                let mut b = [0u8; 9];
+                let prefix = left_encode(rate as u64, &mut b);
+                let mut len = prefix.len();
+                cshake.update(prefix);
+                len += left_encode((key.len() as u64) * 8, &mut b).len() + key.len();
+                encode_string(&mut cshake, key);
+                let zeros = [0u8; 168];
+                cshake.update(&zeros[..(rate - len % rate) % rate]);
+
+                Self { cshake }
+            }
+        }
+
+        impl KeySizeUser for $name {
+            type KeySize = $key_size;
+        }
+
+        impl KeyInit for $name {
+            #[inline]
+            fn new(key: &Key<Self>) -> Self {
+                Self::new_customized(key, &[])
+            }
+
+            #[inline]
+            fn new_from_slice(key: &[u8]) -> Result<Self, InvalidLength> {
+                Ok(Self::new_customized(key, &[]))
+            }
+        }
+
+        impl Update for $name {
+            #[inline]
+            fn update(&mut self, data: &[u8]) {
+                self.cshake.update(data);
+            }
+        }
+
+        impl OutputSizeUser for $name {
+            type OutputSize = $output_size;
+        }
+
+        impl FixedOutput for $name {
+            fn finalize_into(mut self, out: &mut Output<Self>) {
+                let mut b = [0u8; 9];
+                self.cshake
+                    .update(right_encode((out.len() as u64) * 8, &mut b));
+                self.cshake.finalize_xof_into(out);
+            }
+        }
+
+        impl ExtendableOutput for $name {
+            type Reader = $reader;
+
+            fn finalize_xof(mut self) -> $reader {
+                let mut b = [0u8; 9];
+                self.cshake.update(right_encode(0, &mut b));
+                self.cshake.finalize_xof()
+            }
+        }
+
+        impl MacMarker for $name {}
+
+        impl fmt::Debug for $name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str(concat!(stringify!($name), " { ... }"))
+            }
+        }
+    };
+}
+
+impl_kmac!(Kmac128, CShake128, CShake128Core, CShake128Reader, U32, U32);
+impl_kmac!(Kmac256, CShake256, CShake256Core, CShake256Reader, U64, U64);
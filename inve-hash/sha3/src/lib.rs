@@ -1,6 +1,6 @@
 pub use digest::{self, Digest};
 
-use core::fmt;
+use core::{convert::TryInto, fmt};
 use digest::{
     block_buffer::Eager,
     consts::{U104, U136, U144, U168, U200, U28, U32, U48, U64, U72},
@@ -9,18 +9,32 @@ use digest::{
         ExtendableOutputCore, FixedOutputCore, OutputSizeUser, Reset, UpdateCore, XofReaderCore,
         XofReaderCoreWrapper,
     },
+    crypto_common::hazmat::{DeserializeStateError, SerializableState, SerializedState},
+    typenum::Unsigned,
     HashMarker, Output,
 };
 
 #[macro_use]
 mod macros;
+mod kangaroo_twelve;
+mod kmac;
+mod parallel_hash;
 mod state;
+mod tuple_hash;
+
+pub use kangaroo_twelve::KangarooTwelve;
+pub use kmac::{Kmac128, Kmac256};
+pub use parallel_hash::{ParallelHash128, ParallelHash256};
+pub use tuple_hash::{TupleHash128, TupleHash256};
 
 use crate::state::Sha3State;
 
 const KECCAK: u8 = 0x01;
 const SHA3: u8 = 0x06;
 const SHAKE: u8 = 0x1f;
+const CSHAKE: u8 = 0x04;
+
+const TURBO_SHAKE_ROUND_COUNT: usize = 12;
 
 impl_sha3!(Keccak224Core, Keccak224, U28, U144, KECCAK, "Keccak-224");
 impl_sha3!(Keccak256Core, Keccak256, U32, U136, KECCAK, "Keccak-256");
@@ -59,3 +73,533 @@ impl_shake!(
     SHAKE,
     "SHAKE256",
 );
+
+impl_turbo_shake!(
+    TurboShake128Core,
+    TurboShake128,
+    TurboShake128ReaderCore,
+    TurboShake128Reader,
+    U168,
+    "TurboSHAKE128",
+);
+impl_turbo_shake!(
+    TurboShake256Core,
+    TurboShake256,
+    TurboShake256ReaderCore,
+    TurboShake256Reader,
+    U136,
+    "TurboSHAKE256",
+);
+
+impl_cshake!(
+    CShake128Core,
+    CShake128,
+    CShake128ReaderCore,
+    CShake128Reader,
+    U168,
+    SHAKE,
+    CSHAKE,
+    "cSHAKE128",
+);
+impl_cshake!(
+    CShake256Core,
+    CShake256,
+    CShake256ReaderCore,
+    CShake256Reader,
+    U136,
+    SHAKE,
+    CSHAKE,
+    "cSHAKE256",
+);
+
+#[inline(always)]
+pub(crate) fn left_encode(val: u64, b: &mut [u8; 9]) -> &[u8] {
+    b[1..].copy_from_slice(&val.to_be_bytes());
+    let i = b[1..8].iter().take_while(|&&a| a == 0).count();
+    b[i] = (8 - i) as u8;
+    &b[i..]
+}
+
+#[inline(always)]
+pub(crate) fn right_encode(val: u64, b: &mut [u8; 9]) -> &[u8] {
+    b[..8].copy_from_slice(&val.to_be_bytes());
+    let i = b[..7].iter().take_while(|&&a| a == 0).count();
+    b[8] = (8 - i) as u8;
+    &b[i..]
+}
+
+pub(crate) fn encode_string(hasher: &mut impl digest::Update, s: &[u8]) {
+    let mut b = [0u8; 9];
+    hasher.update(left_encode((s.len() as u64) * 8, &mut b));
+    hasher.update(s);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use digest::{dev::blobby, ExtendableOutput, FixedOutput, Mac, Update, XofReader};
+    use hex_literal::hex;
+
+    fn read_xof(mut reader: impl XofReader, expected: &[u8]) {
+        let mut out = [0u8; 64];
+        let out = &mut out[..expected.len()];
+        let (a, b) = out.split_at_mut(7);
+        reader.read(a);
+        reader.read(b);
+        assert_eq!(out, expected);
+    }
+
+    macro_rules! cshake_test {
+        ($name:ident, $test_name:expr, $core:ident, $hasher:ident) => {
+            #[test]
+            fn $name() {
+                let data = include_bytes!(concat!("data/", $test_name, ".blb"));
+                for row in blobby::Blob4Iterator::new(data).unwrap() {
+                    let [function_name, customization, input, output] = row.unwrap();
+                    let core = $core::new_with_function_name(function_name, customization);
+                    let mut hasher = $hasher::from_core(core);
+                    for chunk in input.chunks(17) {
+                        hasher.update(chunk);
+                    }
+                    read_xof(hasher.clone().finalize_xof(), output);
+
+                    hasher.reset();
+                    hasher.update(input);
+                    read_xof(hasher.finalize_xof(), output);
+                }
+            }
+        };
+    }
+
+    cshake_test!(cshake128, "cshake128", CShake128Core, CShake128);
+    cshake_test!(cshake256, "cshake256", CShake256Core, CShake256);
+
+    macro_rules! kmac_test {
+        ($name:ident, $test_name:expr, $kmac:ident, $xof:expr) => {
+            #[test]
+            fn $name() {
+                let data = include_bytes!(concat!("data/", $test_name, ".blb"));
+                for row in blobby::Blob4Iterator::new(data).unwrap() {
+                    let [key, customization, input, output] = row.unwrap();
+                    let mut mac = $kmac::new_customized(key, customization);
+                    Mac::update(&mut mac, input);
+                    if $xof {
+                        read_xof(mac.finalize_xof(), output);
+                    } else {
+                        assert_eq!(mac.clone().finalize_fixed()[..], output[..]);
+                        assert!(mac.verify_slice(output).is_ok());
+                    }
+                }
+            }
+        };
+    }
+
+    kmac_test!(kmac128, "kmac128", Kmac128, false);
+    kmac_test!(kmac256, "kmac256", Kmac256, false);
+    kmac_test!(kmac128_xof, "kmac128xof", Kmac128, true);
+    kmac_test!(kmac256_xof, "kmac256xof", Kmac256, true);
+
+    macro_rules! tuple_hash_test {
+        ($name:ident, $test_name:expr, $hasher:ident, $xof:expr) => {
+            #[test]
+            fn $name() {
+                let data = include_bytes!(concat!("data/", $test_name, ".blb"));
+                for row in blobby::Blob3Iterator::new(data).unwrap() {
+                    let [customization, items, output] = row.unwrap();
+                    let mut hasher = $hasher::new(customization);
+                    for item in blobby::BlobIterator::new(items).unwrap() {
+                        hasher.update_item(item.unwrap());
+                    }
+                    if $xof {
+                        read_xof(hasher.finalize_xof(), output);
+                    } else {
+                        let mut out = [0u8; 64];
+                        hasher.finalize_into(&mut out[..output.len()]);
+                        assert_eq!(&out[..output.len()], output);
+                    }
+                }
+            }
+        };
+    }
+
+    tuple_hash_test!(tuple_hash128, "tuplehash128", TupleHash128, false);
+    tuple_hash_test!(tuple_hash256, "tuplehash256", TupleHash256, false);
+    tuple_hash_test!(tuple_hash128_xof, "tuplehash128xof", TupleHash128, true);
+    tuple_hash_test!(tuple_hash256_xof, "tuplehash256xof", TupleHash256, true);
+
+    macro_rules! parallel_hash_test {
+        ($name:ident, $test_name:expr, $hasher:ident, $xof:expr) => {
+            #[test]
+            fn $name() {
+                let data = include_bytes!(concat!("data/", $test_name, ".blb"));
+                for row in blobby::Blob4Iterator::new(data).unwrap() {
+                    let [block_size, customization, input, output] = row.unwrap();
+                    let block_size = u64::from_le_bytes(block_size.try_into().unwrap());
+                    for chunk_size in 1..=input.len() {
+                        let mut hasher = $hasher::new(block_size as usize, customization);
+                        for chunk in input.chunks(chunk_size) {
+                            hasher.update(chunk);
+                        }
+                        if $xof {
+                            read_xof(hasher.finalize_xof(), output);
+                        } else {
+                            let mut out = [0u8; 64];
+                            hasher.finalize_into(&mut out[..output.len()]);
+                            assert_eq!(&out[..output.len()], output);
+                        }
+                    }
+                }
+            }
+        };
+    }
+
+    parallel_hash_test!(parallel_hash128, "parallelhash128", ParallelHash128, false);
+    parallel_hash_test!(parallel_hash256, "parallelhash256", ParallelHash256, false);
+    parallel_hash_test!(
+        parallel_hash128_xof,
+        "parallelhash128xof",
+        ParallelHash128,
+        true
+    );
+    parallel_hash_test!(
+        parallel_hash256_xof,
+        "parallelhash256xof",
+        ParallelHash256,
+        true
+    );
+
+    #[test]
+    fn shake_reader_types() {
+        let mut reader: Shake128Reader = Shake128::default().finalize_xof();
+        let mut out = [0u8; 16];
+        reader.read(&mut out);
+        assert_eq!(out, hex!("7f9c2ba4e88f827d616045507605853e"));
+
+        let mut reader: Shake256Reader = Shake256::default().finalize_xof();
+        reader.read(&mut out);
+        assert_eq!(out, hex!("46b9dd2b0ba88d13233b3feb743eeb24"));
+    }
+
+    fn ptn(len: usize) -> Vec<u8> {
+        (0..len).map(|i| (i % 251) as u8).collect()
+    }
+
+    fn turbo_shake<H: ExtendableOutput + Update>(mut hasher: H, input: &[u8], expected: &[u8]) {
+        hasher.update(input);
+        let mut out = vec![0u8; expected.len()];
+        hasher.finalize_xof_into(&mut out);
+        assert_eq!(out, expected);
+    }
+
+    #[test]
+    fn turbo_shake128() {
+        let new = |d| TurboShake128::from_core(TurboShake128Core::new(d));
+        turbo_shake(
+            new(0x1F),
+            &[],
+            &hex!("1e415f1c5983aff2169217277d17bb538cd945a397ddec541f1ce41af2c1b74c"),
+        );
+        turbo_shake(
+            new(0x1F),
+            &[],
+            &hex!(
+                "1e415f1c5983aff2169217277d17bb538cd945a397ddec541f1ce41af2c1b74c
+                 3e8ccae2a4dae56c84a04c2385c03c15e8193bdf58737363321691c05462c8df"
+            ),
+        );
+
+        let mut out = vec![0u8; 10032];
+        new(0x1F).finalize_xof_into(&mut out);
+        assert_eq!(
+            out[10000..],
+            hex!("a3b9b0385900ce761f22aed548e754da10a5242d62e8c658e3f3a923a7555607")
+        );
+
+        turbo_shake(
+            new(0x1F),
+            &ptn(1),
+            &hex!("55cedd6f60af7bb29a4042ae832ef3f58db7299f893ebb9247247d856958daa9"),
+        );
+        turbo_shake(
+            new(0x1F),
+            &ptn(17),
+            &hex!("9c97d036a3bac819db70ede0ca554ec6e4c2a1a4ffbfd9ec269ca6a111161233"),
+        );
+        turbo_shake(
+            new(0x1F),
+            &ptn(289),
+            &hex!("96c77c279e0126f7fc07c9b07f5cdae1e0be60bdbe10620040e75d7223a624d2"),
+        );
+        turbo_shake(
+            new(0x1F),
+            &ptn(4913),
+            &hex!("d4976eb56bcf118520582b709f73e1d6853e001fdaf80e1b13e0d0599d5fb372"),
+        );
+        turbo_shake(
+            new(0x1F),
+            &ptn(83521),
+            &hex!("da67c7039e98bf530cf7a37830c6664e14cbab7f540f58403b1b82951318ee5c"),
+        );
+        turbo_shake(
+            new(0x01),
+            &[0xFF; 1],
+            &hex!("012ad664922ce3f81b058735b50aacbde383f1a9a75180b4b9f929550a5552b5"),
+        );
+        turbo_shake(
+            new(0x06),
+            &[0xFF; 3],
+            &hex!("3d03988bb59e681851a192f429ae03988e8f444bc06036a3f1a7d2ccd758d174"),
+        );
+        turbo_shake(
+            new(0x07),
+            &[0xFF; 7],
+            &hex!("7837ae05dac9f0c6c426595a54f94d5d863b6458e8f248bcb7bd3a0e4776fc37"),
+        );
+        turbo_shake(
+            new(0x0B),
+            &[0xFF; 3],
+            &hex!("a59658332b8e13c455a21a25300379d2012c5c6aba6aabfa6c50b28bfc01601f"),
+        );
+        turbo_shake(
+            new(0x30),
+            &[0xFF; 1],
+            &hex!("553122e2135e363c3292bed2c6421fa232bab03daa07c7d6636603286506325b"),
+        );
+        turbo_shake(
+            new(0x7F),
+            &[0xFF; 3],
+            &hex!("16274cc656d44cefd422395d0f9053bda6d28e122aba15c765e5ad0e6eaf26f9"),
+        );
+    }
+
+    #[test]
+    fn turbo_shake256() {
+        let new = |d| TurboShake256::from_core(TurboShake256Core::new(d));
+        turbo_shake(
+            new(0x1F),
+            &[],
+            &hex!(
+                "367a329dafea871c7802ec67f905ae13c57695dc2c6663c61035f59a18f8e7db
+                 11edc0e12e91ea60eb6b32df06dd7f002fbafabb6e13ec1cc20d995547600db0"
+            ),
+        );
+        turbo_shake(
+            new(0x1F),
+            &[],
+            &hex!(
+                "367a329dafea871c7802ec67f905ae13c57695dc2c6663c61035f59a18f8e7db
+                 11edc0e12e91ea60eb6b32df06dd7f002fbafabb6e13ec1cc20d995547600db0
+                 6c0618043e89afee80f552390fdd09f45c51953be5f0904ac669c6ab50b885c9
+                 f5717c743a7a78bf0b72ed4b9a7f51c7a0a06876f0d0526b68b8368ce563c770"
+            ),
+        );
+
+        let mut out = vec![0u8; 10032];
+        new(0x1F).finalize_xof_into(&mut out);
+        assert_eq!(
+            out[10000..],
+            hex!("abefa11630c661269249742685ec082f207265dccf2f43534e9c61ba0c9d1d75")
+        );
+
+        turbo_shake(
+            new(0x1F),
+            &ptn(1),
+            &hex!(
+                "3e1712f928f8eaf1054632b2aa0a246ed8b0c378728f60bc970410155c28820e
+                 90cc90d8a3006aa2372c5c5ea176b0682bf22bae7467ac94f74d43d39b0482e2"
+            ),
+        );
+        turbo_shake(
+            new(0x1F),
+            &ptn(17),
+            &hex!(
+                "b3bab0300e6a191fbe6137939835923578794ea54843f5011090fa2f3780a9e5
+                 cb22c59d78b40a0fbff9e672c0fbe0970bd2c845091c6044d687054da5d8e9c7"
+            ),
+        );
+        turbo_shake(
+            new(0x1F),
+            &ptn(289),
+            &hex!(
+                "66b810db8e90780424c0847372fdc95710882fde31c6df75beb9d4cd9305cfca
+                 e35e7b83e8b7e6eb4b78605880116316fe2c078a09b94ad7b8213c0a738b65c0"
+            ),
+        );
+        turbo_shake(
+            new(0x1F),
+            &ptn(4913),
+            &hex!(
+                "c74ebc919a5b3b0dd1228185ba02d29ef442d69d3d4276a93efe0bf9a16a7dc0
+                 cd4eabadab8cd7a5edd96695f5d360abe09e2c6511a3ec397da3b76b9e1674fb"
+            ),
+        );
+        turbo_shake(
+            new(0x1F),
+            &ptn(83521),
+            &hex!(
+                "02cc3a8897e6f4f6ccb6fd46631b1f5207b66c6de9c7b55b2d1a23134a170afd
+                 ac234eaba9a77cff88c1f020b73724618c5687b362c430b248cd38647f848a1d"
+            ),
+        );
+        turbo_shake(
+            new(0x01),
+            &[0xFF; 1],
+            &hex!(
+                "403108cf3fa80ed8a5c80228381e4d0b1a563b11e7a07cc65d175f37cbc6c9a2
+                 3746b5fc21ec1e6849bd0504cd05c0fd4cad3141da35905f5e3a84df5ec80864"
+            ),
+        );
+        turbo_shake(
+            new(0x06),
+            &[0xFF; 3],
+            &hex!(
+                "e5538cdd28302a2e81e41f65fd2a4052014d0cd463df671d1e510a9d95c37d71
+                 35ef2728430a9e317004f836c9a238ef35370280d03dce7f0612f0315b3cbf63"
+            ),
+        );
+        turbo_shake(
+            new(0x07),
+            &[0xFF; 7],
+            &hex!(
+                "26338ded0072c80776004cf5c561ae657dd2b87aaeb73c79c8ae16408b794cdc
+                 a2504582719e5fdef17994b6487941bcb008708b06577b39d0febb23f3c05b59"
+            ),
+        );
+        turbo_shake(
+            new(0x0B),
+            &[0xFF; 3],
+            &hex!(
+                "a238ed4e0672e5e5309e06ba33ac2bef5b6f4cf22ea8411841cf0fb9626cfa2c
+                 e1a4a78288c3942b1abf04f672e9d2e579efdc1e4aee4cbe4c8e4c1dd0ac07f6"
+            ),
+        );
+        turbo_shake(
+            new(0x30),
+            &[0xFF; 1],
+            &hex!(
+                "f3fe12873d34bcbb2e608779d6b70e7f86bec7e90bf113cbd4fdd0c4e2f4625e
+                 148dd7ee1a52776cf77f240514d9ccfc3b5ddab8ee255e39ee389072962c111a"
+            ),
+        );
+        turbo_shake(
+            new(0x7F),
+            &[0xFF; 3],
+            &hex!(
+                "abe569c1f77ec340f02705e7d37c9ab7e155516e4a6a150021d70b6fac0bb40c
+                 069f9a9828a0d575cd99f9bae435ab1acf7ed9110ba97ce0388d074bac768776"
+            ),
+        );
+    }
+
+    fn kangaroo_twelve(input: &[u8], customization: &[u8], expected: &[u8]) {
+        let mut out = vec![0u8; expected.len()];
+        let mut hasher = KangarooTwelve::new(customization);
+        hasher.update(input);
+        hasher.finalize_xof_into(&mut out);
+        assert_eq!(out, expected);
+
+        for chunk_size in [1000, 8191, 8192, 8193, 200_000] {
+            let mut hasher = KangarooTwelve::new(customization);
+            for chunk in input.chunks(chunk_size) {
+                hasher.update(chunk);
+            }
+            hasher.finalize_xof_into(&mut out);
+            assert_eq!(out, expected);
+        }
+    }
+
+    #[test]
+    fn kangaroo_twelve_vectors() {
+        kangaroo_twelve(
+            &[],
+            &[],
+            &hex!("1ac2d450fc3b4205d19da7bfca1b37513c0803577ac7167f06fe2ce1f0ef39e5"),
+        );
+        kangaroo_twelve(
+            &[],
+            &[],
+            &hex!(
+                "1ac2d450fc3b4205d19da7bfca1b37513c0803577ac7167f06fe2ce1f0ef39e5
+                 4269c056b8c82e48276038b6d292966cc07a3d4645272e31ff38508139eb0a71"
+            ),
+        );
+
+        let mut out = vec![0u8; 10032];
+        KangarooTwelve::default().finalize_xof_into(&mut out);
+        assert_eq!(
+            out[10000..],
+            hex!("e8dc563642f7228c84684c898405d3a834799158c079b12880277a1d28e2ff6d")
+        );
+
+        kangaroo_twelve(
+            &ptn(1),
+            &[],
+            &hex!("2bda92450e8b147f8a7cb629e784a058efca7cf7d8218e02d345dfaa65244a1f"),
+        );
+        kangaroo_twelve(
+            &ptn(17),
+            &[],
+            &hex!("6bf75fa2239198db4772e36478f8e19b0f371205f6a9a93a273f51df37122888"),
+        );
+        kangaroo_twelve(
+            &ptn(289),
+            &[],
+            &hex!("0c315ebcdedbf61426de7dcf8fb725d1e74675d7f5327a5067f367b108ecb67c"),
+        );
+        kangaroo_twelve(
+            &ptn(4913),
+            &[],
+            &hex!("cb552e2ec77d9910701d578b457ddf772c12e322e4ee7fe417f92c758f0d59d0"),
+        );
+        kangaroo_twelve(
+            &ptn(83521),
+            &[],
+            &hex!("8701045e22205345ff4dda05555cbb5c3af1a771c2b89baef37db43d9998b9fe"),
+        );
+        kangaroo_twelve(
+            &ptn(1419857),
+            &[],
+            &hex!("844d610933b1b9963cbdeb5ae3b6b05cc7cbd67ceedf883eb678a0a8e0371682"),
+        );
+        kangaroo_twelve(
+            &[0xFF; 0],
+            &ptn(1),
+            &hex!("fab658db63e94a246188bf7af69a133045f46ee984c56e3c3328caaf1aa1a583"),
+        );
+        kangaroo_twelve(
+            &[0xFF; 1],
+            &ptn(41),
+            &hex!("d848c5068ced736f4462159b9867fd4c20b808acc3d5bc48e0b06ba0a3762ec4"),
+        );
+        kangaroo_twelve(
+            &[0xFF; 3],
+            &ptn(1681),
+            &hex!("c389e5009ae57120854c2e8c64670ac01358cf4c1baf89447a724234dc7ced74"),
+        );
+        kangaroo_twelve(
+            &[0xFF; 7],
+            &ptn(68921),
+            &hex!("75d2f86a2e644566726b4fbcfc5657b9dbcf070c7b0dca06450ab291d7443bcf"),
+        );
+        kangaroo_twelve(
+            &ptn(8191),
+            &ptn(0),
+            &hex!("1b577636f723643e990cc7d6a659837436fd6a103626600eb8301cd1dbe553d6"),
+        );
+        kangaroo_twelve(
+            &ptn(8192),
+            &ptn(0),
+            &hex!("48f256f6772f9edfb6a8b661ec92dc93b95ebd05a08a17b39ae3490870c926c3"),
+        );
+        kangaroo_twelve(
+            &ptn(8192),
+            &ptn(8189),
+            &hex!("3ed12f70fb05ddb58689510ab3e4d23c6c6033849aa01e1d8c220a297fedcd0b"),
+        );
+        kangaroo_twelve(
+            &ptn(8192),
+            &ptn(8190),
+            &hex!("6a7c1b6a5cd0d8c9ca943a4a216cc64604559a2ea45f78570a15253d67ba00ae"),
+        );
+    }
+}
@@ -66,6 +66,26 @@ macro_rules! impl_sha3 {
             }
         }
 
+        impl SerializableState for $name {
+            type SerializedStateSize = U200;
+
+            fn serialize(&self) -> SerializedState<Self> {
+                let mut out = SerializedState::<Self>::default();
+                self.state.as_bytes(&mut out);
+                out
+            }
+
+            fn deserialize(
+                serialized_state: &SerializedState<Self>,
+            ) -> Result<Self, DeserializeStateError> {
+                let mut state = Sha3State::default();
+                for (v, chunk) in state.state.iter_mut().zip(serialized_state.chunks_exact(8)) {
+                    *v = u64::from_le_bytes(chunk.try_into().unwrap());
+                }
+                Ok(Self { state })
+            }
+        }
+
         impl AlgorithmName for $name {
             fn write_alg_name(f: &mut fmt::Formatter<'_>) -> fmt::Result {
                 f.write_str(stringify!($full_name))
@@ -192,6 +212,268 @@ macro_rules! impl_shake {
 
         #[doc = $alg_name]
         #[doc = " reader state."]
-        pub type $reader_full = XofReaderCoreWrapper<$name>;
+        pub type $reader_full = XofReaderCoreWrapper<$reader>;
+    };
+}
+
+macro_rules! impl_cshake {
+    (
+        $name:ident, $full_name:ident, $reader:ident, $reader_full:ident,
+        $rate:ident, $shake_pad:expr, $cshake_pad:expr, $alg_name:expr,
+    ) => {
+        #[doc = "Core "]
+        #[doc = $alg_name]
+        #[doc = " hasher state."]
+        #[derive(Clone)]
+        #[allow(non_camel_case_types)]
+        pub struct $name {
+            padding: u8,
+            state: Sha3State,
+            initial_state: Sha3State,
+        }
+
+        impl $name {
+            pub fn new(customization: &[u8]) -> Self {
+                Self::new_with_function_name(&[], customization)
+            }
+
+            pub fn new_with_function_name(function_name: &[u8], customization: &[u8]) -> Self {
+                let mut state = Sha3State::default();
+                // With empty N and S cSHAKE is defined to be plain SHAKE
+                if function_name.is_empty() && customization.is_empty() {
+                    return Self {
+                        padding: $shake_pad,
+                        state: state.clone(),
+                        initial_state: state,
+                    };
+                }
+
+                let mut buffer = Buffer::<Self>::default();
+                let mut b = [0u8; 9];
+                let mut absorb = |data: &[u8]| {
+                    buffer.digest_blocks(data, |blocks| {
+                        for block in blocks {
+                            state.absorb_block(block);
+                        }
+                    })
+                };
+                absorb(left_encode($rate::to_u64(), &mut b));
+                absorb(left_encode((function_name.len() * 8) as u64, &mut b));
+                absorb(function_name);
+                absorb(left_encode((customization.len() * 8) as u64, &mut b));
+                absorb(customization);
+                if buffer.get_pos() != 0 {
+                    state.absorb_block(buffer.pad_with_zeros());
+                }
+
+                Self {
+                    padding: $cshake_pad,
+                    state: state.clone(),
+                    initial_state: state,
+                }
+            }
+        }
+
+        impl HashMarker for $name {}
+
+        impl BlockSizeUser for $name {
+            type BlockSize = $rate;
+        }
+
+        impl BufferKindUser for $name {
+            type BufferKind = Eager;
+        }
+
+        impl UpdateCore for $name {
+            #[inline]
+            fn update_blocks(&mut self, blocks: &[Block<Self>]) {
+                for block in blocks {
+                    self.state.absorb_block(block)
+                }
+            }
+        }
+
+        impl ExtendableOutputCore for $name {
+            type ReaderCore = $reader;
+
+            #[inline]
+            fn finalize_xof_core(&mut self, buffer: &mut Buffer<Self>) -> Self::ReaderCore {
+                let pos = buffer.get_pos();
+                let block = buffer.pad_with_zeros();
+                block[pos] = self.padding;
+                let n = block.len();
+                block[n - 1] |= 0x80;
+
+                self.state.absorb_block(block);
+                $reader {
+                    state: self.state.clone(),
+                }
+            }
+        }
+
+        impl Reset for $name {
+            #[inline]
+            fn reset(&mut self) {
+                self.state = self.initial_state.clone();
+            }
+        }
+
+        impl AlgorithmName for $name {
+            fn write_alg_name(f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str(stringify!($full_name))
+            }
+        }
+
+        impl fmt::Debug for $name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str(concat!(stringify!($name), " { ... }"))
+            }
+        }
+
+        #[doc = "Core "]
+        #[doc = $alg_name]
+        #[doc = " reader state."]
+        #[derive(Clone)]
+        #[allow(non_camel_case_types)]
+        pub struct $reader {
+            state: Sha3State,
+        }
+
+        impl BlockSizeUser for $reader {
+            type BlockSize = $rate;
+        }
+
+        impl XofReaderCore for $reader {
+            #[inline]
+            fn read_block(&mut self) -> Block<Self> {
+                let mut block = Block::<Self>::default();
+                self.state.as_bytes(&mut block);
+                self.state.apply_f();
+                block
+            }
+        }
+
+        #[doc = $alg_name]
+        #[doc = " hasher state."]
+        pub type $full_name = CoreWrapper<$name>;
+
+        #[doc = $alg_name]
+        #[doc = " reader state."]
+        pub type $reader_full = XofReaderCoreWrapper<$reader>;
+    };
+}
+
+macro_rules! impl_turbo_shake {
+    (
+        $name:ident, $full_name:ident, $reader:ident, $reader_full:ident,
+        $rate:ident, $alg_name:expr $(,)?
+    ) => {
+        #[doc = "Core "]
+        #[doc = $alg_name]
+        #[doc = " hasher state."]
+        #[derive(Clone)]
+        #[allow(non_camel_case_types)]
+        pub struct $name {
+            domain_separation: u8,
+            state: Sha3State,
+        }
+
+        impl $name {
+            /// Panics if `domain_separation` is not in the `0x01..=0x7F` range.
+            pub fn new(domain_separation: u8) -> Self {
+                assert!((0x01..=0x7F).contains(&domain_separation));
+                Self {
+                    domain_separation,
+                    state: Sha3State::new(TURBO_SHAKE_ROUND_COUNT),
+                }
+            }
+        }
+
+        impl HashMarker for $name {}
+
+        impl BlockSizeUser for $name {
+            type BlockSize = $rate;
+        }
+
+        impl BufferKindUser for $name {
+            type BufferKind = Eager;
+        }
+
+        impl UpdateCore for $name {
+            #[inline]
+            fn update_blocks(&mut self, blocks: &[Block<Self>]) {
+                for block in blocks {
+                    self.state.absorb_block(block)
+                }
+            }
+        }
+
+        impl ExtendableOutputCore for $name {
+            type ReaderCore = $reader;
+
+            #[inline]
+            fn finalize_xof_core(&mut self, buffer: &mut Buffer<Self>) -> Self::ReaderCore {
+                let pos = buffer.get_pos();
+                let block = buffer.pad_with_zeros();
+                block[pos] = self.domain_separation;
+                let n = block.len();
+                block[n - 1] |= 0x80;
+
+                self.state.absorb_block(block);
+                $reader {
+                    state: self.state.clone(),
+                }
+            }
+        }
+
+        impl Reset for $name {
+            #[inline]
+            fn reset(&mut self) {
+                *self = Self::new(self.domain_separation);
+            }
+        }
+
+        impl AlgorithmName for $name {
+            fn write_alg_name(f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str(stringify!($full_name))
+            }
+        }
+
+        impl fmt::Debug for $name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str(concat!(stringify!($name), " { ... }"))
+            }
+        }
+
+        #[doc = "Core "]
+        #[doc = $alg_name]
+        #[doc = " reader state."]
+        #[derive(Clone)]
+        #[allow(non_camel_case_types)]
+        pub struct $reader {
+            state: Sha3State,
+        }
+
+        impl BlockSizeUser for $reader {
+            type BlockSize = $rate;
+        }
+
+        impl XofReaderCore for $reader {
+            #[inline]
+            fn read_block(&mut self) -> Block<Self> {
+                let mut block = Block::<Self>::default();
+                self.state.as_bytes(&mut block);
+                self.state.apply_f();
+                block
+            }
+        }
+
+        #[doc = $alg_name]
+        #[doc = " hasher state."]
+        pub type $full_name = CoreWrapper<$name>;
+
+        #[doc = $alg_name]
+        #[doc = " reader state."]
+        pub type $reader_full = XofReaderCoreWrapper<$reader>;
     };
 }
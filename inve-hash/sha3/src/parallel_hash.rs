@@ -0,0 +1,117 @@
+use core::{fmt, mem};
+use digest::{ExtendableOutput, Update};
+
+use crate::{
+    left_encode, right_encode, CShake128, CShake128Core, CShake128Reader, CShake256, CShake256Core,
+    CShake256Reader, Shake128, Shake256,
+};
+
+macro_rules! impl_parallel_hash {
+    (
+        $name:ident, $cshake:ident, $cshake_core:ident, $reader:ident,
+        $shake:ident, $chain_len:expr $(,)?
+    ) => {
+        /// ParallelHash of NIST SP 800-185. The leaves are hashed one after
+        /// another on the calling thread.
+        #[derive(Clone)]
+        pub struct $name {
+            cshake: $cshake,
+            leaf: $shake,
+            leaf_len: usize,
+            block_size: usize,
+            blocks: u64,
+        }
+
+        impl $name {
+            /// Panics if `block_size` is zero.
+            pub fn new(block_size: usize, customization: &[u8]) -> Self {
+                assert!(block_size != 0, "block size must be non-zero");
+
+                let mut cshake = $cshake::from_core($cshake_core::new_with_function_name(
+                    b"ParallelHash",
+                    customization,
+                ));
+                let mut b = [0u8; 9];
+                cshake.update(left_encode(block_size as u64, &mut b));
+
+                Self {
+                    cshake,
+                    leaf: Default::default(),
+                    leaf_len: 0,
+                    block_size,
+                    blocks: 0,
+                }
+            }
+
+            fn finish_leaf(&mut self) {
+                let mut chain = [0u8; $chain_len];
+                mem::take(&mut self.leaf).finalize_xof_into(&mut chain);
+                self.cshake.update(&chain);
+                self.leaf_len = 0;
+                self.blocks += 1;
+            }
+
+            fn finalize_cshake(mut self, output_bits: u64) -> $cshake {
+                if self.leaf_len != 0 {
+                    self.finish_leaf();
+                }
+                let mut b = [0u8; 9];
+                self.cshake.update(right_encode(self.blocks, &mut b));
+                self.cshake.update(right_encode(output_bits, &mut b));
+                self.cshake
+            }
+
+            pub fn finalize_into(self, out: &mut [u8]) {
+                let bits = (out.len() as u64) * 8;
+                self.finalize_cshake(bits).finalize_xof_into(out);
+            }
+        }
+
+        impl Update for $name {
+            fn update(&mut self, mut data: &[u8]) {
+                while !data.is_empty() {
+                    let n = core::cmp::min(self.block_size - self.leaf_len, data.len());
+                    let (head, tail) = data.split_at(n);
+                    self.leaf.update(head);
+                    self.leaf_len += n;
+                    data = tail;
+
+                    if self.leaf_len == self.block_size {
+                        self.finish_leaf();
+                    }
+                }
+            }
+        }
+
+        impl ExtendableOutput for $name {
+            type Reader = $reader;
+
+            fn finalize_xof(self) -> $reader {
+                self.finalize_cshake(0).finalize_xof()
+            }
+        }
+
+        impl fmt::Debug for $name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str(concat!(stringify!($name), " { ... }"))
+            }
+        }
+    };
+}
+
+impl_parallel_hash!(
+    ParallelHash128,
+    CShake128,
+    CShake128Core,
+    CShake128Reader,
+    Shake128,
+    32,
+);
+impl_parallel_hash!(
+    ParallelHash256,
+    CShake256,
+    CShake256Core,
+    CShake256Reader,
+    Shake256,
+    64,
+);
@@ -1,13 +1,28 @@
 use core::convert::TryInto;
 
 const PLEN: usize = 25;
+const DEFAULT_ROUND_COUNT: usize = 24;
 
-#[derive(Clone, Default)]
+#[derive(Clone)]
 pub(crate) struct Sha3State {
     pub state: [u64; PLEN],
+    round_count: usize,
+}
+
+impl Default for Sha3State {
+    fn default() -> Self {
+        Self::new(DEFAULT_ROUND_COUNT)
+    }
 }
 
 impl Sha3State {
+    pub(crate) fn new(round_count: usize) -> Self {
+        Self {
+            state: [0u64; PLEN],
+            round_count,
+        }
+    }
+
     #[inline(always)]
     pub(crate) fn absorb_block(&mut self, block: &[u8]) {
         debug_assert_eq!(block.len() % 8, 0);
@@ -16,7 +31,7 @@ impl Sha3State {
             *s ^= u64::from_le_bytes(b.try_into().unwrap());
         }
 
-        keccak::f1600(&mut self.state);
+        self.apply_f();
     }
 
     #[inline(always)]
@@ -28,6 +43,6 @@ impl Sha3State {
 
     #[inline(always)]
     pub(crate) fn apply_f(&mut self) {
-        keccak::f1600(&mut self.state);
+        keccak::p1600(&mut self.state, self.round_count);
     }
 }
@@ -0,0 +1,77 @@
+use core::fmt;
+use digest::{ExtendableOutput, Update};
+
+use crate::{
+    encode_string, right_encode, CShake128, CShake128Core, CShake128Reader, CShake256,
+    CShake256Core, CShake256Reader,
+};
+
+macro_rules! impl_tuple_hash {
+    ($name:ident, $cshake:ident, $cshake_core:ident, $reader:ident $(,)?) => {
+        #[derive(Clone)]
+        pub struct $name {
+            cshake: $cshake,
+        }
+
+        impl $name {
+            pub fn new(customization: &[u8]) -> Self {
+                Self {
+                    cshake: $cshake::from_core($cshake_core::new_with_function_name(
+                        b"TupleHash",
+                        customization,
+                    )),
+                }
+            }
+
+            pub fn update_item(&mut self, item: &[u8]) {
+                encode_string(&mut self.cshake, item);
+            }
+
+            #[must_use]
+            pub fn chain_item(mut self, item: impl AsRef<[u8]>) -> Self {
+                self.update_item(item.as_ref());
+                self
+            }
+
+            pub fn finalize_into(mut self, out: &mut [u8]) {
+                let mut b = [0u8; 9];
+                self.cshake
+                    .update(right_encode((out.len() as u64) * 8, &mut b));
+                self.cshake.finalize_xof_into(out);
+            }
+        }
+
+        /// Each call absorbs one element of the tuple.
+        impl Update for $name {
+            #[inline]
+            fn update(&mut self, data: &[u8]) {
+                self.update_item(data);
+            }
+        }
+
+        impl ExtendableOutput for $name {
+            type Reader = $reader;
+
+            fn finalize_xof(mut self) -> $reader {
+                let mut b = [0u8; 9];
+                self.cshake.update(right_encode(0, &mut b));
+                self.cshake.finalize_xof()
+            }
+        }
+
+        impl Default for $name {
+            fn default() -> Self {
+                Self::new(&[])
+            }
+        }
+
+        impl fmt::Debug for $name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str(concat!(stringify!($name), " { ... }"))
+            }
+        }
+    };
+}
+
+impl_tuple_hash!(TupleHash128, CShake128, CShake128Core, CShake128Reader);
+impl_tuple_hash!(TupleHash256, CShake256, CShake256Core, CShake256Reader);
@@ -0,0 +1,80 @@
+#![allow(clippy::many_single_char_names)]
+use crate::{Block, Sm3Core, STATE_LEN};
+use core::convert::TryInto;
+
+#[inline(always)]
+fn p0(x: u32) -> u32 {
+    x ^ x.rotate_left(9) ^ x.rotate_left(17)
+}
+
+#[inline(always)]
+fn p1(x: u32) -> u32 {
+    x ^ x.rotate_left(15) ^ x.rotate_left(23)
+}
+
+#[inline(always)]
+fn ff(x: u32, y: u32, z: u32, j: usize) -> u32 {
+    if j < 16 {
+        x ^ y ^ z
+    } else {
+        (x & y) | (x & z) | (y & z)
+    }
+}
+
+#[inline(always)]
+fn gg(x: u32, y: u32, z: u32, j: usize) -> u32 {
+    if j < 16 {
+        x ^ y ^ z
+    } else {
+        (x & y) | (!x & z)
+    }
+}
+
+fn compress_block(state: &mut [u32; STATE_LEN], block: &[u8; 64]) {
+    let mut w = [0u32; 68];
+    for (w, chunk) in w.iter_mut().zip(block.chunks_exact(4)) {
+        *w = u32::from_be_bytes(chunk.try_into().unwrap());
+    }
+    for j in 16..68 {
+        w[j] = p1(w[j - 16] ^ w[j - 9] ^ w[j - 3].rotate_left(15))
+            ^ w[j - 13].rotate_left(7)
+            ^ w[j - 6];
+    }
+
+    let [mut a, mut b, mut c, mut d, mut e, mut f, mut g, mut h] = *state;
+    for j in 0..64 {
+        let t: u32 = if j < 16 { 0x79cc4519 } else { 0x7a879d8a };
+        let a12 = a.rotate_left(12);
+        let ss1 = a12
+            .wrapping_add(e)
+            .wrapping_add(t.rotate_left((j % 32) as u32))
+            .rotate_left(7);
+        let ss2 = ss1 ^ a12;
+        let tt1 = ff(a, b, c, j)
+            .wrapping_add(d)
+            .wrapping_add(ss2)
+            .wrapping_add(w[j] ^ w[j + 4]);
+        let tt2 = gg(e, f, g, j)
+            .wrapping_add(h)
+            .wrapping_add(ss1)
+            .wrapping_add(w[j]);
+        d = c;
+        c = b.rotate_left(9);
+        b = a;
+        a = tt1;
+        h = g;
+        g = f.rotate_left(19);
+        f = e;
+        e = p0(tt2);
+    }
+
+    for (s, v) in state.iter_mut().zip([a, b, c, d, e, f, g, h]) {
+        *s ^= v;
+    }
+}
+
+pub(crate) fn compress(state: &mut [u32; STATE_LEN], blocks: &[Block<Sm3Core>]) {
+    for block in blocks {
+        compress_block(state, block.as_ref());
+    }
+}
@@ -0,0 +1,146 @@
+pub use digest::{self, Digest};
+
+use core::{convert::TryInto, fmt, slice::from_ref};
+use digest::{
+    block_buffer::Eager,
+    core_api::{
+        AlgorithmName, Block, BlockSizeUser, Buffer, BufferKindUser, CoreWrapper, FixedOutputCore,
+        OutputSizeUser, Reset, UpdateCore,
+    },
+    crypto_common::hazmat::{DeserializeStateError, SerializableState, SerializedState},
+    typenum::{Unsigned, U32, U40, U64},
+    HashMarker, Output,
+};
+
+mod compress;
+
+use compress::compress;
+
+const STATE_LEN: usize = 8;
+const INITIAL_H: [u32; STATE_LEN] = [
+    0x7380166f, 0x4914b2b9, 0x172442d7, 0xda8a0600, 0xa96f30bc, 0x163138aa, 0xe38dee4d, 0xb0fb0e4e,
+];
+
+#[derive(Clone)]
+pub struct Sm3Core {
+    h: [u32; STATE_LEN],
+    block_len: u64,
+}
+
+impl HashMarker for Sm3Core {}
+
+impl BlockSizeUser for Sm3Core {
+    type BlockSize = U64;
+}
+
+impl BufferKindUser for Sm3Core {
+    type BufferKind = Eager;
+}
+
+impl OutputSizeUser for Sm3Core {
+    type OutputSize = U32;
+}
+
+impl UpdateCore for Sm3Core {
+    #[inline]
+    fn update_blocks(&mut self, blocks: &[Block<Self>]) {
+        self.block_len += blocks.len() as u64;
+        compress(&mut self.h, blocks);
+    }
+}
+
+impl FixedOutputCore for Sm3Core {
+    #[inline]
+    fn finalize_fixed_core(&mut self, buffer: &mut Buffer<Self>, out: &mut Output<Self>) {
+        let bs = Self::BlockSize::U64;
+        let bit_len = 8 * (buffer.get_pos() as u64 + bs * self.block_len);
+
+        let mut h = self.h;
+        buffer.len64_padding_be(bit_len, |b| compress(&mut h, from_ref(b)));
+        for (chunk, v) in out.chunks_exact_mut(4).zip(h.iter()) {
+            chunk.copy_from_slice(&v.to_be_bytes());
+        }
+    }
+}
+
+impl Default for Sm3Core {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            h: INITIAL_H,
+            block_len: 0,
+        }
+    }
+}
+
+impl Reset for Sm3Core {
+    #[inline]
+    fn reset(&mut self) {
+        *self = Default::default();
+    }
+}
+
+impl SerializableState for Sm3Core {
+    type SerializedStateSize = U40;
+
+    fn serialize(&self) -> SerializedState<Self> {
+        let mut out = SerializedState::<Self>::default();
+        let (h, block_len) = out.split_at_mut(4 * STATE_LEN);
+        for (chunk, v) in h.chunks_exact_mut(4).zip(self.h.iter()) {
+            chunk.copy_from_slice(&v.to_le_bytes());
+        }
+        block_len.copy_from_slice(&self.block_len.to_le_bytes());
+        out
+    }
+
+    fn deserialize(
+        serialized_state: &SerializedState<Self>,
+    ) -> Result<Self, DeserializeStateError> {
+        let (h_bytes, block_len) = serialized_state.split_at(4 * STATE_LEN);
+        let mut h = [0u32; STATE_LEN];
+        for (v, chunk) in h.iter_mut().zip(h_bytes.chunks_exact(4)) {
+            *v = u32::from_le_bytes(chunk.try_into().unwrap());
+        }
+        Ok(Self {
+            h,
+            block_len: u64::from_le_bytes(block_len.try_into().unwrap()),
+        })
+    }
+}
+
+impl AlgorithmName for Sm3Core {
+    fn write_alg_name(f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("Sm3")
+    }
+}
+
+impl fmt::Debug for Sm3Core {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("Sm3Core { ... }")
+    }
+}
+
+pub type Sm3 = CoreWrapper<Sm3Core>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use digest::dev::fixed_reset_test;
+    use digest::new_test;
+    use hex_literal::hex;
+
+    new_test!(sm3_main, "sm3", Sm3, fixed_reset_test);
+
+    // Examples from GB/T 32905-2016, appendix A
+    #[test]
+    fn sm3_examples() {
+        assert_eq!(
+            Sm3::digest(b"abc")[..],
+            hex!("66c7f0f462eeedd9d1f2d46bdc10e4e24167c4875cf2f7a2297da02b8f4ba8e0")[..]
+        );
+        assert_eq!(
+            Sm3::digest(b"abcd".repeat(16))[..],
+            hex!("debe9ff92275b8a138604889c18e5a4d6fdb70e5387e5765293dcba39c0c5732")[..]
+        );
+    }
+}
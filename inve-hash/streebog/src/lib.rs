@@ -0,0 +1,51 @@
+pub use digest::{self, Digest};
+
+use digest::{
+    consts::{U32, U64},
+    core_api::{CoreWrapper, CtVariableCoreWrapper},
+};
+
+mod consts;
+mod core_api;
+mod table;
+
+pub use core_api::StreebogVarCore;
+
+pub type Streebog256 = CoreWrapper<CtVariableCoreWrapper<StreebogVarCore, U32>>;
+pub type Streebog512 = CoreWrapper<CtVariableCoreWrapper<StreebogVarCore, U64>>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use digest::dev::fixed_reset_test;
+    use digest::new_test;
+
+    new_test!(
+        streebog256_main,
+        "streebog256",
+        Streebog256,
+        fixed_reset_test
+    );
+    new_test!(
+        streebog512_main,
+        "streebog512",
+        Streebog512,
+        fixed_reset_test
+    );
+
+    #[test]
+    fn lin_table() {
+        let mut table = [[0u64; 256]; 8];
+        for (i, row) in table.iter_mut().enumerate() {
+            for (j, v) in row.iter_mut().enumerate() {
+                let b = consts::P[j];
+                for k in 0..8 {
+                    if b & (1 << k) != 0 {
+                        *v ^= consts::A[8 * i + k];
+                    }
+                }
+            }
+        }
+        assert_eq!(table, table::SHUFFLED_LIN_TABLE);
+    }
+}
@@ -0,0 +1,391 @@
+use crate::{
+    memory::Blocks, Algorithm, Argon2, Block, Error, Memory, Params, Result, Version, SYNC_POINTS,
+};
+use blake2::{
+    digest::{self, Digest, Output, VariableOutput},
+    Blake2b512, Blake2bVar,
+};
+
+#[cfg(feature = "parallel")]
+use {
+    alloc::vec::Vec,
+    rayon::iter::{IndexedParallelIterator, IntoParallelIterator, ParallelIterator},
+};
+
+#[cfg(feature = "zeroize")]
+use zeroize::Zeroize;
+
+const ADDRESSES_IN_BLOCK: u32 = 128;
+
+const BLAKE2B_OUTBYTES: usize = 64;
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+struct Position {
+    pass: u32,
+    lane: u32,
+    slice: u32,
+    index: u32,
+}
+
+pub(crate) struct Instance {
+    version: Version,
+
+    passes: u32,
+
+    memory_blocks: u32,
+
+    segment_length: u32,
+
+    lane_length: u32,
+
+    lanes: u32,
+
+    #[cfg(feature = "parallel")]
+    threads: u32,
+
+    alg: Algorithm,
+}
+
+impl Instance {
+    #[allow(unused_mut)]
+    pub fn hash(
+        context: &Argon2<'_>,
+        alg: Algorithm,
+        mut initial_hash: Output<Blake2b512>,
+        mut memory: Memory<'_>,
+        out: &mut [u8],
+    ) -> Result<()> {
+        let instance = Self::new(context, alg, &memory);
+
+        instance.fill_first_blocks(&mut memory, &initial_hash)?;
+
+        #[cfg(feature = "zeroize")]
+        initial_hash.zeroize();
+
+        instance.fill_memory_blocks(&mut memory);
+
+        instance.finalize(&memory, out)
+    }
+
+    fn new(context: &Argon2<'_>, alg: Algorithm, memory: &Memory<'_>) -> Self {
+        let segment_length = memory.segment_length();
+
+        Instance {
+            version: context.version,
+            passes: context.params.t_cost(),
+            memory_blocks: memory.len() as u32,
+            segment_length,
+            lane_length: segment_length * SYNC_POINTS,
+            lanes: context.params.lanes(),
+            #[cfg(feature = "parallel")]
+            threads: context.params.p_cost().min(context.params.lanes()),
+            alg,
+        }
+    }
+
+    #[cfg(feature = "parallel")]
+    fn fill_memory_blocks_par(&self, memory: &mut Memory<'_>) {
+        for r in 0..self.passes {
+            for s in 0..SYNC_POINTS {
+                let segments: Vec<_> = memory.segments(s).collect();
+
+                segments
+                    .into_par_iter()
+                    .enumerate()
+                    .for_each(|(l, mut segment)| {
+                        self.fill_segment(
+                            &mut segment,
+                            Position {
+                                pass: r,
+                                lane: l as u32,
+                                slice: s,
+                                index: 0,
+                            },
+                        );
+                    });
+            }
+        }
+    }
+
+    fn fill_memory_blocks(&self, memory: &mut Memory<'_>) {
+        #[cfg(feature = "parallel")]
+        if self.threads > 1 {
+            self.fill_memory_blocks_par(memory);
+            return;
+        }
+
+        self.fill_memory_blocks_serial(memory);
+    }
+
+    fn fill_memory_blocks_serial(&self, memory: &mut Memory<'_>) {
+        for r in 0..self.passes {
+            for s in 0..SYNC_POINTS {
+                for l in 0..self.lanes {
+                    self.fill_segment(
+                        memory,
+                        Position {
+                            pass: r,
+                            lane: l,
+                            slice: s,
+                            index: 0,
+                        },
+                    );
+                }
+            }
+        }
+    }
+
+    fn finalize(&self, memory: &Memory<'_>, out: &mut [u8]) -> Result<()> {
+        let mut blockhash = memory.get_block((self.lane_length - 1) as usize);
+
+        for l in 1..self.lanes {
+            let last_block_in_lane = l * self.lane_length + (self.lane_length - 1);
+            blockhash ^= memory.get_block(last_block_in_lane as usize);
+        }
+
+        let mut blockhash_bytes = [0u8; Block::SIZE];
+
+        for (chunk, v) in blockhash_bytes.chunks_mut(8).zip(blockhash.iter()) {
+            chunk.copy_from_slice(&v.to_le_bytes())
+        }
+
+        blake2b_long(&[&blockhash_bytes], out)?;
+
+        #[cfg(feature = "zeroize")]
+        blockhash.zeroize();
+
+        #[cfg(feature = "zeroize")]
+        blockhash_bytes.zeroize();
+
+        Ok(())
+    }
+
+    fn fill_first_blocks(&self, memory: &mut Memory<'_>, blockhash: &[u8]) -> Result<()> {
+        let mut hash = [0u8; Block::SIZE];
+
+        for l in 0..self.lanes {
+            for i in 0u32..2u32 {
+                blake2b_long(&[blockhash, &i.to_le_bytes(), &l.to_le_bytes()], &mut hash)?;
+                memory
+                    .get_block_mut((l * self.lane_length + i) as usize)
+                    .load(&hash);
+            }
+        }
+
+        Ok(())
+    }
+
+    fn fill_segment<B: Blocks>(&self, segment: &mut B, mut position: Position) {
+        let mut address_block = Block::default();
+        let mut input_block = Block::default();
+        let zero_block = Block::default();
+
+        let data_independent_addressing = (self.alg == Algorithm::Argon2i)
+            || (self.alg == Algorithm::Argon2id
+                && (position.pass == 0)
+                && (position.slice < SYNC_POINTS / 2));
+
+        if data_independent_addressing {
+            input_block[0] = position.pass as u64;
+            input_block[1] = position.lane as u64;
+            input_block[2] = position.slice as u64;
+            input_block[3] = self.memory_blocks as u64;
+            input_block[4] = self.passes as u64;
+            input_block[5] = self.alg as u64;
+        }
+
+        let mut starting_index = 0;
+
+        if position.pass == 0 && position.slice == 0 {
+            starting_index = 2; // we have already generated the first two blocks
+
+            if data_independent_addressing {
+                next_addresses(&mut address_block, &mut input_block, &zero_block);
+            }
+        }
+
+        let curr_offset = position.lane * self.lane_length
+            + position.slice * self.segment_length
+            + starting_index;
+
+        // `u32::is_multiple_of` is too recent for the toolchains we support.
+        #[allow(clippy::manual_is_multiple_of)]
+        let mut prev_offset = if curr_offset % self.lane_length == 0 {
+            curr_offset + self.lane_length - 1
+        } else {
+            curr_offset - 1
+        };
+
+        for (i, curr_offset) in (starting_index..self.segment_length).zip(curr_offset..) {
+            if curr_offset % self.lane_length == 1 {
+                prev_offset = curr_offset - 1;
+            }
+
+            let pseudo_rand = if data_independent_addressing {
+                if i % ADDRESSES_IN_BLOCK == 0 {
+                    next_addresses(&mut address_block, &mut input_block, &zero_block);
+                }
+                address_block[(i % ADDRESSES_IN_BLOCK) as usize]
+            } else {
+                segment.get_block(prev_offset as usize)[0]
+            };
+
+            let mut ref_lane = (pseudo_rand >> 32) as u32 % self.lanes;
+
+            if position.pass == 0 && position.slice == 0 {
+                ref_lane = position.lane;
+            }
+
+            position.index = i;
+
+            let ref_index = self.index_alpha(
+                position,
+                (pseudo_rand & 0xFFFFFFFF) as u32,
+                ref_lane == position.lane,
+            );
+
+            let ref_block = segment.get_block((self.lane_length * ref_lane + ref_index) as usize);
+            let prev_block = segment.get_block(prev_offset as usize);
+
+            let without_xor = self.version == Version::V0x10 || position.pass == 0;
+            segment.get_block_mut(curr_offset as usize).fill_block(
+                prev_block,
+                ref_block,
+                !without_xor,
+            );
+
+            prev_offset += 1;
+        }
+    }
+
+    fn index_alpha(&self, position: Position, pseudo_rand: u32, same_lane: bool) -> u32 {
+        let reference_area_size = if 0 == position.pass {
+            if position.slice == 0 {
+                position.index - 1 // all but the previous
+            } else if same_lane {
+                position.slice * self.segment_length + position.index - 1
+            } else {
+                position.slice * self.segment_length - if position.index == 0 { 1 } else { 0 }
+            }
+        } else {
+            if same_lane {
+                self.lane_length - self.segment_length + position.index - 1
+            } else {
+                self.lane_length - self.segment_length - if position.index == 0 { 1 } else { 0 }
+            }
+        };
+
+        let mut relative_position = pseudo_rand as u64;
+        relative_position = (relative_position * relative_position) >> 32;
+        let relative_position = reference_area_size
+            - 1
+            - (((reference_area_size as u64 * relative_position) >> 32) as u32);
+
+        let mut start_position = 0;
+
+        if position.pass != 0 {
+            start_position = if position.slice == SYNC_POINTS - 1 {
+                0
+            } else {
+                (position.slice + 1) * self.segment_length
+            }
+        }
+
+        (start_position + relative_position) % self.lane_length
+    }
+}
+
+fn next_addresses(address_block: &mut Block, input_block: &mut Block, zero_block: &Block) {
+    input_block[6] += 1;
+    address_block.fill_block(*zero_block, *input_block, false);
+    address_block.fill_block(*zero_block, *address_block, false);
+}
+
+fn blake2b_long(inputs: &[&[u8]], mut out: &mut [u8]) -> Result<()> {
+    if out.len() < Params::MIN_OUTPUT_LEN {
+        return Err(Error::OutputTooShort);
+    }
+
+    if out.len() > Params::MAX_OUTPUT_LEN {
+        return Err(Error::OutputTooLong);
+    }
+
+    let outlen_bytes = (out.len() as u32).to_le_bytes();
+
+    if out.len() <= BLAKE2B_OUTBYTES {
+        use digest::Update;
+
+        let mut digest = Blake2bVar::new(out.len()).expect("`out` length is valid for Blake2bVar");
+        Update::update(&mut digest, &outlen_bytes);
+
+        for input in inputs {
+            Update::update(&mut digest, input);
+        }
+
+        digest
+            .finalize_variable(out)
+            .expect("`out` length is valid for Blake2bVar");
+    } else {
+        let mut digest = Blake2b512::new();
+        digest.update(outlen_bytes);
+
+        for input in inputs {
+            digest.update(input);
+        }
+
+        let mut hash = digest.finalize();
+
+        let n = BLAKE2B_OUTBYTES / 2;
+
+        let (chunk, tail) = out.split_at_mut(n);
+        out = tail;
+        chunk.copy_from_slice(&hash[..n]);
+
+        while out.len() > BLAKE2B_OUTBYTES {
+            let (chunk, tail) = out.split_at_mut(n);
+            out = tail;
+            hash = Blake2b512::digest(hash);
+            chunk.copy_from_slice(&hash[..n]);
+        }
+
+        Blake2bVar::digest_variable(hash, out).expect("`out` length is valid for Blake2bVar");
+    }
+
+    Ok(())
+}
+
+#[cfg(all(test, feature = "parallel"))]
+mod tests {
+    use super::Instance;
+    use crate::{Algorithm, Argon2, Block, Memory, Params, Version};
+
+    #[test]
+    fn parallel_matches_serial() {
+        let params = Params::new(64, 2, 4, None).unwrap();
+
+        for &alg in &[Algorithm::Argon2d, Algorithm::Argon2i, Algorithm::Argon2id] {
+            let ctx = Argon2::new(alg, Version::V0x13, params.clone());
+            let initial_hash = ctx.initial_hash(b"password", b"somesalt", &[0; 32]);
+            let mut outputs = [[0u8; 32]; 2];
+
+            for (parallel, out) in outputs.iter_mut().enumerate() {
+                let mut blocks = vec![Block::default(); params.block_count()];
+                let mut memory = Memory::new(&mut blocks, params.segment_length());
+                let instance = Instance::new(&ctx, alg, &memory);
+                assert!(instance.threads > 1);
+
+                instance
+                    .fill_first_blocks(&mut memory, &initial_hash)
+                    .unwrap();
+                if parallel == 1 {
+                    instance.fill_memory_blocks_par(&mut memory);
+                } else {
+                    instance.fill_memory_blocks_serial(&mut memory);
+                }
+                instance.finalize(&memory, out).unwrap();
+            }
+
+            assert_eq!(outputs[0], outputs[1]);
+        }
+    }
+}
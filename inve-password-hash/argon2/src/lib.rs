@@ -0,0 +1,405 @@
+#[cfg(feature = "alloc")]
+#[macro_use]
+extern crate alloc;
+
+#[cfg(feature = "std")]
+extern crate std;
+
+mod algorithm;
+mod block;
+mod error;
+mod instance;
+mod memory;
+mod params;
+mod version;
+
+pub use crate::{
+    algorithm::Algorithm,
+    block::Block,
+    error::{Error, Result},
+    params::{Params, ParamsBuilder},
+    version::Version,
+};
+
+#[cfg(feature = "password-hash")]
+#[cfg_attr(docsrs, doc(cfg(feature = "password-hash")))]
+pub use {
+    crate::algorithm::{ARGON2D_IDENT, ARGON2ID_IDENT, ARGON2I_IDENT},
+    password_hash::{self, PasswordHash, PasswordHasher, PasswordVerifier},
+};
+
+use crate::{
+    instance::Instance,
+    memory::{Memory, SYNC_POINTS},
+};
+use blake2::{digest::Output, Blake2b512, Digest};
+
+#[cfg(all(feature = "alloc", feature = "password-hash"))]
+use password_hash::{Decimal, Ident, ParamsString, Salt};
+
+pub const MAX_PWD_LEN: usize = 0xFFFFFFFF;
+
+pub const MIN_SALT_LEN: usize = 8;
+
+pub const MAX_SALT_LEN: usize = 0xFFFFFFFF;
+
+pub const RECOMMENDED_SALT_LEN: usize = 16;
+
+pub const MAX_SECRET_LEN: usize = 0xFFFFFFFF;
+
+#[derive(Clone)]
+pub struct Argon2<'key> {
+    algorithm: Algorithm,
+
+    version: Version,
+
+    params: Params,
+
+    secret: Option<&'key [u8]>,
+}
+
+impl Default for Argon2<'_> {
+    fn default() -> Self {
+        Self::new(Algorithm::default(), Version::default(), Params::default())
+    }
+}
+
+impl<'key> Argon2<'key> {
+    pub fn new(algorithm: Algorithm, version: Version, params: Params) -> Self {
+        Self {
+            algorithm,
+            version,
+            params,
+            secret: None,
+        }
+    }
+
+    pub fn new_with_secret(
+        secret: &'key [u8],
+        algorithm: Algorithm,
+        version: Version,
+        params: Params,
+    ) -> Result<Self> {
+        if MAX_SECRET_LEN < secret.len() {
+            return Err(Error::SecretTooLong);
+        }
+
+        Ok(Self {
+            algorithm,
+            version,
+            params,
+            secret: Some(secret),
+        })
+    }
+
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    pub fn hash_password_into(&self, pwd: &[u8], salt: &[u8], out: &mut [u8]) -> Result<()> {
+        let mut blocks = vec![Block::default(); self.params.block_count()];
+        self.hash_password_into_with_memory(pwd, salt, out, &mut blocks)
+    }
+
+    pub fn hash_password_into_with_memory(
+        &self,
+        pwd: &[u8],
+        salt: &[u8],
+        out: &mut [u8],
+        mut memory_blocks: impl AsMut<[Block]>,
+    ) -> Result<()> {
+        if out.len() < self.params.output_len().unwrap_or(Params::MIN_OUTPUT_LEN) {
+            return Err(Error::OutputTooShort);
+        }
+
+        if out.len() > self.params.output_len().unwrap_or(Params::MAX_OUTPUT_LEN) {
+            return Err(Error::OutputTooLong);
+        }
+
+        if pwd.len() > MAX_PWD_LEN {
+            return Err(Error::PwdTooLong);
+        }
+
+        if salt.len() < MIN_SALT_LEN {
+            return Err(Error::SaltTooShort);
+        }
+
+        if salt.len() > MAX_SALT_LEN {
+            return Err(Error::SaltTooLong);
+        }
+
+        let initial_hash = self.initial_hash(pwd, salt, out);
+
+        let segment_length = self.params.segment_length();
+        let block_count = self.params.block_count();
+        let memory_blocks = memory_blocks
+            .as_mut()
+            .get_mut(..block_count)
+            .ok_or(Error::MemoryTooLittle)?;
+
+        let memory = Memory::new(memory_blocks, segment_length);
+        Instance::hash(self, self.algorithm, initial_hash, memory, out)
+    }
+
+    pub fn params(&self) -> &Params {
+        &self.params
+    }
+
+    pub(crate) fn initial_hash(&self, pwd: &[u8], salt: &[u8], out: &[u8]) -> Output<Blake2b512> {
+        let mut digest = Blake2b512::new();
+        digest.update(self.params.lanes().to_le_bytes());
+        digest.update((out.len() as u32).to_le_bytes());
+        digest.update(self.params.m_cost().to_le_bytes());
+        digest.update(self.params.t_cost().to_le_bytes());
+        digest.update(self.version.to_le_bytes());
+        digest.update(self.algorithm.to_le_bytes());
+        digest.update((pwd.len() as u32).to_le_bytes());
+        digest.update(pwd);
+        digest.update((salt.len() as u32).to_le_bytes());
+        digest.update(salt);
+
+        if let Some(secret) = &self.secret {
+            digest.update((secret.len() as u32).to_le_bytes());
+            digest.update(secret);
+        } else {
+            digest.update(0u32.to_le_bytes());
+        }
+
+        digest.update((self.params.data().len() as u32).to_le_bytes());
+        digest.update(self.params.data());
+
+        digest.finalize()
+    }
+}
+
+#[cfg(all(feature = "alloc", feature = "password-hash"))]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+#[cfg_attr(docsrs, doc(cfg(feature = "password-hash")))]
+impl PasswordHasher for Argon2<'_> {
+    type Params = Params;
+
+    fn hash_password<'a, S>(
+        &self,
+        password: &[u8],
+        salt: &'a S,
+    ) -> password_hash::Result<PasswordHash<'a>>
+    where
+        S: AsRef<str> + ?Sized,
+    {
+        let salt = Salt::try_from(salt.as_ref())?;
+        let mut salt_arr = [0u8; 64];
+        let salt_bytes = salt.b64_decode(&mut salt_arr)?;
+        let output_len = self
+            .params
+            .output_len()
+            .unwrap_or(Params::DEFAULT_OUTPUT_LEN);
+
+        let output = password_hash::Output::init_with(output_len, |out| {
+            Ok(self.hash_password_into(password, salt_bytes, out)?)
+        })?;
+
+        Ok(PasswordHash {
+            algorithm: self.algorithm.ident(),
+            version: Some(self.version.into()),
+            params: ParamsString::try_from(&self.params)?,
+            salt: Some(salt),
+            hash: Some(output),
+        })
+    }
+
+    fn hash_password_customized<'a>(
+        &self,
+        password: &[u8],
+        alg_id: Option<Ident<'a>>,
+        version: Option<Decimal>,
+        params: Params,
+        salt: impl Into<Salt<'a>>,
+    ) -> password_hash::Result<PasswordHash<'a>> {
+        let algorithm = alg_id
+            .map(Algorithm::try_from)
+            .transpose()?
+            .unwrap_or_default();
+
+        let version = version
+            .map(Version::try_from)
+            .transpose()?
+            .unwrap_or_default();
+
+        let salt = salt.into();
+
+        Self {
+            secret: self.secret,
+            algorithm,
+            version,
+            params,
+        }
+        .hash_password(password, salt.as_str())
+    }
+}
+
+impl<'key> From<Params> for Argon2<'key> {
+    fn from(params: Params) -> Self {
+        Self::new(Algorithm::default(), Version::default(), params)
+    }
+}
+
+impl<'key> From<&Params> for Argon2<'key> {
+    fn from(params: &Params) -> Self {
+        Self::from(params.clone())
+    }
+}
+
+#[cfg(all(test, feature = "alloc", feature = "password-hash"))]
+mod tests {
+    use crate::{
+        Algorithm, Argon2, Error, Params, ParamsBuilder, PasswordHash, PasswordHasher,
+        PasswordVerifier, Salt, Version,
+    };
+    use hex_literal::hex;
+    use password_hash::SaltString;
+
+    const EXAMPLE_PASSWORD: &[u8] = b"hunter42";
+
+    const EXAMPLE_SALT: &str = "examplesaltvalue";
+
+    #[test]
+    fn decoded_salt_too_short() {
+        let argon2 = Argon2::default();
+
+        let salt = Salt::new("somesalt").unwrap();
+
+        let res =
+            argon2.hash_password_customized(EXAMPLE_PASSWORD, None, None, Params::default(), salt);
+        assert_eq!(
+            res,
+            Err(password_hash::Error::SaltInvalid(
+                password_hash::errors::InvalidValue::TooShort
+            ))
+        );
+    }
+
+    #[test]
+    fn hash_simple_retains_configured_params() {
+        let t_cost = 4;
+        let m_cost = 2048;
+        let p_cost = 2;
+        let version = Version::V0x10;
+
+        let params = Params::new(m_cost, t_cost, p_cost, None).unwrap();
+        let hasher = Argon2::new(Algorithm::default(), version, params);
+        let hash = hasher
+            .hash_password(EXAMPLE_PASSWORD, EXAMPLE_SALT)
+            .unwrap();
+
+        assert_eq!(hash.version.unwrap(), version.into());
+
+        for &(param, value) in &[("t", t_cost), ("m", m_cost), ("p", p_cost)] {
+            assert_eq!(
+                hash.params
+                    .get(param)
+                    .and_then(|p| p.decimal().ok())
+                    .unwrap(),
+                value
+            );
+        }
+    }
+
+    fn kat(algorithm: Algorithm, version: Version, expected_tag: [u8; 32]) {
+        let mut builder = ParamsBuilder::new();
+        builder.m_cost(32).unwrap();
+        builder.t_cost(3).unwrap();
+        builder.p_cost(4).unwrap();
+        builder.data(&[0x04; 12]).unwrap();
+        let params = builder.params().unwrap();
+
+        let ctx = Argon2::new_with_secret(&[0x03; 8], algorithm, version, params).unwrap();
+        let mut out = [0u8; 32];
+        ctx.hash_password_into(&[0x01; 32], &[0x02; 16], &mut out)
+            .unwrap();
+        assert_eq!(out, expected_tag);
+    }
+
+    // Reference implementation KATs for version 0x10
+    #[test]
+    fn argon2_v0x10() {
+        kat(
+            Algorithm::Argon2d,
+            Version::V0x10,
+            hex!("96a9d4e5a1734092c85e29f410a45914a5dd1f5cbf08b2670da68a0285abf32b"),
+        );
+        kat(
+            Algorithm::Argon2i,
+            Version::V0x10,
+            hex!("87aeedd6517ab830cd9765cd8231abb2e647a5dee08f7c05e02fcb763335d0fd"),
+        );
+        kat(
+            Algorithm::Argon2id,
+            Version::V0x10,
+            hex!("b64615f07789b66b645b67ee9ed3b377ae350b6bfcbb0fc95141ea8f322613c0"),
+        );
+    }
+
+    // RFC 9106, Section 5
+    #[test]
+    fn argon2_v0x13() {
+        kat(
+            Algorithm::Argon2d,
+            Version::V0x13,
+            hex!("512b391b6f1162975371d30919734294f868e3be3984f3c1a13a4db9fabe4acb"),
+        );
+        kat(
+            Algorithm::Argon2i,
+            Version::V0x13,
+            hex!("c814d9d1dc7f37aa13f0d77f2494bda1c8de6b016dd388d29952a4c4672b6ce8"),
+        );
+        kat(
+            Algorithm::Argon2id,
+            Version::V0x13,
+            hex!("0d640df58d78766c08c037a34a8b53c9d01ef0452d75b65eb52520e96b01e659"),
+        );
+    }
+
+    #[test]
+    fn bad_lengths() {
+        let ctx = Argon2::new(Algorithm::Argon2id, Version::V0x13, Params::default());
+        let mut out = [0u8; 32];
+        assert_eq!(
+            ctx.hash_password_into(b"password", &[0u8; crate::MIN_SALT_LEN - 1], &mut out),
+            Err(Error::SaltTooShort)
+        );
+
+        let mut out = [0u8; Params::MIN_OUTPUT_LEN - 1];
+        assert_eq!(
+            ctx.hash_password_into(b"password", b"diffsalt", &mut out),
+            Err(Error::OutputTooShort)
+        );
+    }
+
+    // phc-winner-argon2 test suite
+    #[test]
+    fn phc_strings() {
+        for hash in [
+            "$argon2d$v=16$m=32,t=2,p=3,data=Dw8PDw8P$AAAAAAAAAAA$KnH4gniiaFnDvlA1xev3yovC4cnrrI6tnHOYtmja90o",
+            "$argon2d$v=16$m=32,t=2,p=3,keyid=8PDw8A,data=Dw8PDw8P$AAAAAAAAAAA$KnH4gniiaFnDvlA1xev3yovC4cnrrI6tnHOYtmja90o",
+        ] {
+            let hash = PasswordHash::new(hash).unwrap();
+            assert!(Argon2::default().verify_password(b"password", &hash).is_ok());
+            assert!(Argon2::default().verify_password(b"sassword", &hash).is_err());
+        }
+    }
+
+    #[test]
+    fn phc_round_trip() {
+        let params = Params::new(64, 2, 2, None).unwrap();
+        let ctx = Argon2::new(Algorithm::Argon2id, Version::V0x13, params);
+        let salt = SaltString::new("c29tZXNhbHQ").unwrap();
+        let hash = ctx.hash_password(b"password", &salt).unwrap().to_string();
+        assert!(hash.starts_with("$argon2id$v=19$m=64,t=2,p=2$c29tZXNhbHQ$"));
+
+        let hash = PasswordHash::new(&hash).unwrap();
+        assert!(Argon2::default()
+            .verify_password(b"password", &hash)
+            .is_ok());
+        assert!(Argon2::default()
+            .verify_password(b"sassword", &hash)
+            .is_err());
+    }
+}
@@ -0,0 +1,149 @@
+use crate::Block;
+
+#[cfg(feature = "parallel")]
+use core::{marker::PhantomData, slice};
+
+pub(crate) const SYNC_POINTS: u32 = 4;
+
+pub(crate) struct Memory<'a> {
+    data: &'a mut [Block],
+
+    segment_length: u32,
+}
+
+impl<'a> Memory<'a> {
+    pub(crate) fn new(data: &'a mut [Block], segment_length: u32) -> Self {
+        Self {
+            data,
+            segment_length,
+        }
+    }
+
+    pub(crate) fn get_block(&self, idx: usize) -> Block {
+        self.data[idx]
+    }
+
+    pub(crate) fn get_block_mut(&mut self, idx: usize) -> &mut Block {
+        &mut self.data[idx]
+    }
+
+    /// Splits the memory for filling the given slice: one [`Segment`] per
+    /// lane, in lane order.
+    #[cfg(feature = "parallel")]
+    pub(crate) fn segments(&mut self, slice: u32) -> impl Iterator<Item = Segment<'_>> {
+        let segment_length = self.segment_length as usize;
+        let lane_length = segment_length * SYNC_POINTS as usize;
+        let len = self.data.len();
+        let data = self.data.as_mut_ptr();
+
+        (0..len / lane_length).map(move |lane| {
+            let offset = lane * lane_length + slice as usize * segment_length;
+
+            // SAFETY: `offset + segment_length <= lane * lane_length +
+            // lane_length <= len`, so the segment lies within `data`. Lane
+            // `lane` owns `[lane * lane_length, (lane + 1) * lane_length)`,
+            // and within it slice `slice` owns `segment_length` blocks at a
+            // fixed offset, so the segments yielded for distinct lanes never
+            // overlap. They all borrow `self` mutably, so nothing else can
+            // touch `data` while they are alive.
+            #[allow(unsafe_code)]
+            let blocks = unsafe { slice::from_raw_parts_mut(data.add(offset), segment_length) };
+
+            Segment {
+                blocks,
+                offset,
+                data,
+                len,
+                lane_length,
+                slice: slice as usize,
+                memory: PhantomData,
+            }
+        })
+    }
+
+    #[inline]
+    pub(crate) fn len(&self) -> usize {
+        self.data.len()
+    }
+
+    #[inline]
+    pub(crate) fn segment_length(&self) -> u32 {
+        self.segment_length
+    }
+}
+
+/// Block storage that a segment is filled from and written to.
+pub(crate) trait Blocks {
+    fn get_block(&self, idx: usize) -> Block;
+
+    fn get_block_mut(&mut self, idx: usize) -> &mut Block;
+}
+
+impl Blocks for Memory<'_> {
+    fn get_block(&self, idx: usize) -> Block {
+        Memory::get_block(self, idx)
+    }
+
+    fn get_block_mut(&mut self, idx: usize) -> &mut Block {
+        Memory::get_block_mut(self, idx)
+    }
+}
+
+/// One lane's segment of the current slice, borrowed exclusively, together
+/// with read access to the rest of the memory.
+///
+/// The blocks outside the segment are read through a raw pointer while the
+/// other lanes' segments are being filled, possibly on other threads. This
+/// relies on the sync-point invariant of Argon2: during a slice only the
+/// blocks of that slice are written, and a lane never references the
+/// current slice of another lane. [`Segment::get_block`] checks it.
+#[cfg(feature = "parallel")]
+pub(crate) struct Segment<'a> {
+    blocks: &'a mut [Block],
+
+    offset: usize,
+
+    data: *const Block,
+
+    len: usize,
+
+    lane_length: usize,
+
+    slice: usize,
+
+    memory: PhantomData<&'a [Block]>,
+}
+
+// SAFETY: the segment only writes to `blocks`, which no other segment
+// aliases, and `data` is only used to read blocks outside the current slice.
+#[cfg(feature = "parallel")]
+#[allow(unsafe_code)]
+unsafe impl Send for Segment<'_> {}
+
+#[cfg(feature = "parallel")]
+impl Blocks for Segment<'_> {
+    fn get_block(&self, idx: usize) -> Block {
+        match idx.checked_sub(self.offset) {
+            Some(i) if i < self.blocks.len() => self.blocks[i],
+            _ => {
+                assert!(idx < self.len, "block index out of range");
+                assert_ne!(
+                    idx % self.lane_length / self.blocks.len(),
+                    self.slice,
+                    "reference into a segment of the current slice"
+                );
+
+                // SAFETY: `idx` is in bounds and outside the current slice, so
+                // no segment writes to it while `self` is alive.
+                #[allow(unsafe_code)]
+                unsafe {
+                    self.data.add(idx).read()
+                }
+            }
+        }
+    }
+
+    fn get_block_mut(&mut self, idx: usize) -> &mut Block {
+        &mut self.blocks[idx - self.offset]
+    }
+}
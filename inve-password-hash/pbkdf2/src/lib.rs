@@ -0,0 +1,253 @@
+#[cfg(feature = "std")]
+extern crate std;
+
+#[cfg(feature = "simple")]
+extern crate alloc;
+
+#[cfg(feature = "simple")]
+#[cfg_attr(docsrs, doc(cfg(feature = "simple")))]
+pub use password_hash;
+
+#[cfg(feature = "simple")]
+mod simple;
+
+#[cfg(feature = "simple")]
+pub use crate::simple::{Algorithm, Params, Pbkdf2};
+
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+use digest::{generic_array::typenum::Unsigned, FixedOutput, KeyInit, Update};
+
+#[inline(always)]
+fn xor(res: &mut [u8], salt: &[u8]) {
+    debug_assert!(salt.len() >= res.len(), "length mismatch in xor");
+    res.iter_mut().zip(salt.iter()).for_each(|(a, b)| *a ^= b);
+}
+
+#[inline(always)]
+fn pbkdf2_body<PRF>(i: u32, chunk: &mut [u8], prf: &PRF, salt: &[u8], rounds: u32)
+where
+    PRF: KeyInit + Update + FixedOutput + Clone,
+{
+    for v in chunk.iter_mut() {
+        *v = 0;
+    }
+
+    let mut salt = {
+        let mut prfc = prf.clone();
+        prfc.update(salt);
+        prfc.update(&(i + 1).to_be_bytes());
+
+        let salt = prfc.finalize_fixed();
+        xor(chunk, &salt);
+        salt
+    };
+
+    for _ in 1..rounds {
+        let mut prfc = prf.clone();
+        prfc.update(&salt);
+        salt = prfc.finalize_fixed();
+
+        xor(chunk, &salt);
+    }
+}
+
+#[cfg(not(feature = "parallel"))]
+#[inline]
+pub fn pbkdf2<PRF>(password: &[u8], salt: &[u8], rounds: u32, res: &mut [u8])
+where
+    PRF: KeyInit + Update + FixedOutput + Clone + Sync,
+{
+    let n = PRF::OutputSize::to_usize();
+    let prf = PRF::new_from_slice(password).expect("PRF initialization failure");
+
+    for (i, chunk) in res.chunks_mut(n).enumerate() {
+        pbkdf2_body(i as u32, chunk, &prf, salt, rounds);
+    }
+}
+
+#[cfg(feature = "parallel")]
+#[inline]
+pub fn pbkdf2<PRF>(password: &[u8], salt: &[u8], rounds: u32, res: &mut [u8])
+where
+    PRF: KeyInit + Update + FixedOutput + Clone + Sync,
+{
+    let n = PRF::OutputSize::to_usize();
+    let prf = PRF::new_from_slice(password).expect("PRF initialization failure");
+
+    res.par_chunks_mut(n).enumerate().for_each(|(i, chunk)| {
+        pbkdf2_body(i as u32, chunk, &prf, salt, rounds);
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::pbkdf2;
+    use hex_literal::hex;
+    use hmac::Hmac;
+    use sha1::Sha1;
+    use sha2::{Sha256, Sha512};
+
+    struct Test {
+        password: &'static [u8],
+        salt: &'static [u8],
+        rounds: u32,
+        output: &'static [u8],
+    }
+
+    const LONG_PASSWORD: &[u8] = b"passwordPASSWORDpassword";
+    const LONG_SALT: &[u8] = b"saltSALTsaltSALTsaltSALTsaltSALTsalt";
+
+    fn check<PRF>(tests: &[Test])
+    where
+        PRF: digest::KeyInit + digest::Update + digest::FixedOutput + Clone + Sync,
+    {
+        for test in tests {
+            let mut buf = [0u8; 128];
+            let out = &mut buf[..test.output.len()];
+            pbkdf2::<PRF>(test.password, test.salt, test.rounds, out);
+            assert_eq!(out, test.output);
+        }
+    }
+
+    // RFC 6070
+    #[test]
+    fn pbkdf2_hmac_sha1() {
+        check::<Hmac<Sha1>>(&[
+            Test {
+                password: b"password",
+                salt: b"salt",
+                rounds: 1,
+                output: &hex!("0c60c80f961f0e71f3a9b524af6012062fe037a6"),
+            },
+            Test {
+                password: b"password",
+                salt: b"salt",
+                rounds: 2,
+                output: &hex!("ea6c014dc72d6f8ccd1ed92ace1d41f0d8de8957"),
+            },
+            Test {
+                password: b"password",
+                salt: b"salt",
+                rounds: 4096,
+                output: &hex!("4b007901b765489abead49d926f721d065a429c1"),
+            },
+            Test {
+                password: LONG_PASSWORD,
+                salt: LONG_SALT,
+                rounds: 4096,
+                output: &hex!("3d2eec4fe41c849b80c8d83662c0e44a8b291a964cf2f07038"),
+            },
+            Test {
+                password: b"pass\0word",
+                salt: b"sa\0lt",
+                rounds: 4096,
+                output: &hex!("56fa6aa75548099dcc37d7f03425e0c3"),
+            },
+        ]);
+    }
+
+    #[test]
+    fn pbkdf2_hmac_sha256() {
+        check::<Hmac<Sha256>>(&[
+            Test {
+                password: b"password",
+                salt: b"salt",
+                rounds: 1,
+                output: &hex!("120fb6cffcf8b32c43e7225256c4f837a86548c92ccc35480805987cb70be17b"),
+            },
+            Test {
+                password: b"password",
+                salt: b"salt",
+                rounds: 2,
+                output: &hex!("ae4d0c95af6b46d32d0adff928f06dd02a303f8ef3c251dfd6e2d85a95474c43"),
+            },
+            Test {
+                password: b"password",
+                salt: b"salt",
+                rounds: 4096,
+                output: &hex!("c5e478d59288c841aa530db6845c4c8d962893a001ce4e11a4963873aa98134a"),
+            },
+            Test {
+                password: LONG_PASSWORD,
+                salt: LONG_SALT,
+                rounds: 4096,
+                output: &hex!(
+                    "348c89dbcbd32b2f32d814b8116e84cf2b17347ebc1800181c4e2a1fb8dd53e1"
+                    "c635518c7dac47e9"
+                ),
+            },
+        ]);
+    }
+
+    #[test]
+    fn pbkdf2_hmac_sha512() {
+        check::<Hmac<Sha512>>(&[
+            Test {
+                password: b"password",
+                salt: b"salt",
+                rounds: 1,
+                output: &hex!(
+                    "867f70cf1ade02cff3752599a3a53dc4af34c7a669815ae5d513554e1c8cf252"
+                    "c02d470a285a0501bad999bfe943c08f050235d7d68b1da55e63f73b60a57fce"
+                ),
+            },
+            Test {
+                password: b"password",
+                salt: b"salt",
+                rounds: 2,
+                output: &hex!(
+                    "e1d9c16aa681708a45f5c7c4e215ceb66e011a2e9f0040713f18aefdb866d53c"
+                    "f76cab2868a39b9f7840edce4fef5a82be67335c77a6068e04112754f27ccf4e"
+                ),
+            },
+            Test {
+                password: LONG_PASSWORD,
+                salt: LONG_SALT,
+                rounds: 4096,
+                output: &hex!(
+                    "8c0511f4c6e597c6ac6315d8f0362e225f3c501495ba23b868c005174dc4ee71"
+                    "115b59f9e60cd9532fa33e0f75aefe30225c583a186cd82bd4daea9724a3d3b8"
+                    "04f75bdd41494fa3"
+                ),
+            },
+        ]);
+    }
+
+    #[cfg(feature = "simple")]
+    #[test]
+    fn phc_string() {
+        use crate::{Algorithm, Params, Pbkdf2};
+        use password_hash::{PasswordHash, PasswordHasher, PasswordVerifier, Salt};
+
+        let params = Params {
+            rounds: 4096,
+            output_length: 32,
+        };
+        let salt = Salt::new("c2FsdA").unwrap();
+        let hash = Pbkdf2
+            .hash_password_customized(b"password", None, None, params, salt)
+            .unwrap();
+        let phc = hash.to_string();
+        assert_eq!(
+            phc,
+            "$pbkdf2-sha256$i=4096,l=32$c2FsdA$xeR41ZKIyEGqUw22hFxMjZYok6ABzk4RpJY4c6qYE0o"
+        );
+
+        let parsed = PasswordHash::new(&phc).unwrap();
+        assert_eq!(Params::try_from(&parsed).unwrap(), params);
+        assert!(Pbkdf2.verify_password(b"password", &parsed).is_ok());
+        assert!(Pbkdf2.verify_password(b"passw0rd", &parsed).is_err());
+
+        let sha512 = PasswordHash::new(
+            "$pbkdf2-sha512$i=1000,l=64$c2FsdHNhbHQ$Q6v4xwJ8a9nWPp2BeEoAYYhHSo2xRmPWART17vTpSxt2q6iNp7BOozW557qqa95eNjUO4gKs0CyvJbYGGku1tA",
+        )
+        .unwrap();
+        assert_eq!(
+            Algorithm::try_from(sha512.algorithm).unwrap(),
+            Algorithm::Pbkdf2Sha512
+        );
+        assert!(Pbkdf2.verify_password(b"password", &sha512).is_ok());
+    }
+}
@@ -0,0 +1,187 @@
+#[cfg(feature = "std")]
+extern crate std;
+
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+use hmac::Hmac;
+use pbkdf2::pbkdf2;
+use sha2::Sha256;
+
+pub mod errors;
+mod params;
+mod romix;
+
+#[cfg(feature = "simple")]
+mod simple;
+
+pub use crate::params::Params;
+
+#[cfg(feature = "simple")]
+pub use password_hash;
+
+#[cfg(feature = "simple")]
+pub use crate::simple::{Scrypt, ALG_ID};
+
+pub fn scrypt(
+    password: &[u8],
+    salt: &[u8],
+    params: &Params,
+    output: &mut [u8],
+) -> Result<(), errors::InvalidOutputLen> {
+    if output.is_empty() || output.len() / 32 > 0xffff_ffff {
+        return Err(errors::InvalidOutputLen);
+    }
+
+    let n = 1 << params.log_n;
+    let r128 = (params.r as usize) * 128;
+    let pr128 = (params.p as usize) * r128;
+    let nr128 = n * r128;
+
+    let mut b = vec![0u8; pr128];
+    pbkdf2::<Hmac<Sha256>>(password, salt, 1, &mut b);
+
+    #[cfg(not(feature = "parallel"))]
+    {
+        let mut v = vec![0u8; nr128];
+        let mut t = vec![0u8; r128];
+
+        for chunk in &mut b.chunks_mut(r128) {
+            romix::scrypt_ro_mix(chunk, &mut v, &mut t, n);
+        }
+    }
+
+    // Each lane needs its own `V`, so memory use grows to `p * 128 * r * N`
+    #[cfg(feature = "parallel")]
+    b.par_chunks_mut(r128).for_each(|chunk| {
+        let mut v = vec![0u8; nr128];
+        let mut t = vec![0u8; r128];
+        romix::scrypt_ro_mix(chunk, &mut v, &mut t, n);
+    });
+
+    pbkdf2::<Hmac<Sha256>>(password, &b, 1, output);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{scrypt, Params};
+    use hex_literal::hex;
+
+    struct Test {
+        password: &'static str,
+        salt: &'static str,
+        log_n: u8,
+        r: u32,
+        p: u32,
+        expected: [u8; 64],
+    }
+
+    // RFC 7914, Section 12 (the last vector is omitted as too slow)
+    #[test]
+    fn rfc7914() {
+        let tests = [
+            Test {
+                password: "",
+                salt: "",
+                log_n: 4,
+                r: 1,
+                p: 1,
+                expected: hex!(
+                    "77d6576238657b203b19ca42c18a0497f16b4844e3074ae8dfdffa3fede21442"
+                    "fcd0069ded0948f8326a753a0fc81f17e8d3e0fb2e0d3628cf35e20c38d18906"
+                ),
+            },
+            Test {
+                password: "password",
+                salt: "NaCl",
+                log_n: 10,
+                r: 8,
+                p: 16,
+                expected: hex!(
+                    "fdbabe1c9d3472007856e7190d01e9fe7c6ad7cbc8237830e77376634b373162"
+                    "2eaf30d92e22a3886ff109279d9830dac727afb94a83ee6d8360cbdfa2cc0640"
+                ),
+            },
+            Test {
+                password: "pleaseletmein",
+                salt: "SodiumChloride",
+                log_n: 14,
+                r: 8,
+                p: 1,
+                expected: hex!(
+                    "7023bdcb3afd7348461c06cd81fd38ebfda8fbba904f8e3ea9b543f6545da1f2"
+                    "d5432955613f0fcf62d49705242a9af9e61e85dc0d651e40dfcf017b45575887"
+                ),
+            },
+        ];
+
+        for test in tests {
+            let params = Params::new(test.log_n, test.r, test.p).unwrap();
+            let mut out = [0u8; 64];
+            scrypt(
+                test.password.as_bytes(),
+                test.salt.as_bytes(),
+                &params,
+                &mut out,
+            )
+            .unwrap();
+            assert_eq!(out, test.expected);
+        }
+    }
+
+    #[test]
+    fn invalid_params() {
+        assert!(Params::new(4, 0, 1).is_err());
+        assert!(Params::new(4, 1, 0).is_err());
+        assert!(Params::new(16, 1, 1).is_err());
+        assert!(Params::new(4, 1 << 20, 1 << 10).is_err());
+        assert!(Params::new(15, 8, 1).is_ok());
+    }
+
+    #[test]
+    fn empty_output() {
+        let params = Params::new(4, 1, 1).unwrap();
+        assert!(scrypt(b"password", b"salt", &params, &mut []).is_err());
+    }
+
+    #[cfg(feature = "simple")]
+    #[test]
+    fn phc_string() {
+        use crate::Scrypt;
+        use password_hash::{PasswordHash, PasswordHasher, PasswordVerifier, Salt};
+
+        let hash = PasswordHash::new(
+            "$scrypt$ln=10,r=8,p=1$aM15713r3Xsvxbi31lqr1Q$NCjrUA2xUtbbHAmo50WhMzLYAmm2QSUui4rICAfsnnI",
+        )
+        .unwrap();
+        assert!(Scrypt.verify_password(b"password", &hash).is_ok());
+        assert!(Scrypt.verify_password(b"invalid", &hash).is_err());
+
+        let params = Params::new(4, 1, 2).unwrap();
+        let salt = Salt::new("c2FsdHNhbHQ").unwrap();
+        let hash = Scrypt
+            .hash_password_customized(b"password", None, None, params, salt)
+            .unwrap()
+            .to_string();
+        assert!(hash.starts_with("$scrypt$ln=4,r=1,p=2$c2FsdHNhbHQ$"));
+        let hash = PasswordHash::new(&hash).unwrap();
+        assert!(Scrypt.verify_password(b"password", &hash).is_ok());
+    }
+
+    #[cfg(feature = "simple")]
+    #[test]
+    fn phc_string_output_len() {
+        use crate::Scrypt;
+        use password_hash::{PasswordHash, PasswordVerifier};
+
+        for hash in [
+            "$scrypt$ln=4,r=1,p=1$c2FsdHNhbHQ$0a3GPom3Z1unCocbxYK40Q",
+            "$scrypt$ln=4,r=1,p=1$c2FsdHNhbHQ$0a3GPom3Z1unCocbxYK40UHyGj5hLe7J6BfIEEPjKTlXWbMJZ3OCBq0kYvdvbqvbsXXEygjYoiCmKRA+32rLrg",
+        ] {
+            let hash = PasswordHash::new(hash).unwrap();
+            assert!(Scrypt.verify_password(b"password", &hash).is_ok());
+            assert!(Scrypt.verify_password(b"invalid", &hash).is_err());
+        }
+    }
+}
@@ -14,9 +14,17 @@ use cipher::zeroize::{Zeroize, ZeroizeOnDrop};
 
 mod backends;
 mod legacy;
+#[cfg(feature = "rng")]
+mod rng;
 mod xchacha;
 
 pub use legacy::{ChaCha20Legacy, ChaCha20LegacyCore, LegacyNonce};
+#[cfg(feature = "rng")]
+#[cfg_attr(docsrs, doc(cfg(feature = "rng")))]
+pub use rng::{
+    ChaCha12Rng, ChaCha12RngCore, ChaCha20Rng, ChaCha20RngCore, ChaCha8Rng, ChaCha8RngCore,
+    ChaChaRng, ChaChaRngCore,
+};
 pub use xchacha::{hchacha, XChaCha12, XChaCha20, XChaCha8, XChaChaCore, XNonce};
 
 const CONSTANTS: [u32; 4] = [0x6170_7865, 0x3320_646e, 0x7962_2d32, 0x6b20_6574];
@@ -61,6 +69,7 @@ cfg_if! {
     }
 }
 
+#[derive(Clone)]
 pub struct ChaChaCore<R: Unsigned> {
     state: [u32; STATE_WORDS],
     #[allow(dead_code)]
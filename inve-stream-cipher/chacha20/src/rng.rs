@@ -0,0 +1,403 @@
+//! Deterministic random number generators based on ChaCha.
+//!
+//! The generators follow the original ChaCha layout with a 64-bit block
+//! counter and a 64-bit stream id, and produce the same output as the
+//! `rand_chacha` crate for the same seed, stream and word position.
+
+use crate::{Block, ChaChaCore, Key, Nonce};
+use cipher::{
+    consts::{U10, U4, U6, U64},
+    generic_array::{typenum::Unsigned, GenericArray},
+    KeyIvInit, StreamBackend, StreamCipherCore, StreamCipherSeekCore, StreamClosure,
+};
+use core::fmt;
+use rand_core::{
+    block::{BlockRng, BlockRngCore},
+    CryptoRng, Error, RngCore, SeedableRng,
+};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+const BLOCK_WORDS: u8 = 16;
+const BUF_BLOCKS: u8 = 4;
+const BUF_WORDS: usize = BLOCK_WORDS as usize * BUF_BLOCKS as usize;
+
+pub type ChaCha8Rng = ChaChaRng<U4>;
+
+pub type ChaCha12Rng = ChaChaRng<U6>;
+
+pub type ChaCha20Rng = ChaChaRng<U10>;
+
+pub type ChaCha8RngCore = ChaChaRngCore<U4>;
+
+pub type ChaCha12RngCore = ChaChaRngCore<U6>;
+
+pub type ChaCha20RngCore = ChaChaRngCore<U10>;
+
+/// Output buffer of [`ChaChaRngCore`], four keystream blocks.
+#[derive(Clone, Copy)]
+pub struct Results([u32; BUF_WORDS]);
+
+impl Default for Results {
+    fn default() -> Self {
+        Self([0; BUF_WORDS])
+    }
+}
+
+impl AsRef<[u32]> for Results {
+    fn as_ref(&self) -> &[u32] {
+        &self.0
+    }
+}
+
+impl AsMut<[u32]> for Results {
+    fn as_mut(&mut self) -> &mut [u32] {
+        &mut self.0
+    }
+}
+
+/// Block generator for [`BlockRng`], see [`ChaChaRng`] for the buffered
+/// generator.
+#[derive(Clone)]
+pub struct ChaChaRngCore<R: Unsigned + Clone>(ChaChaCore<R>);
+
+impl<R: Unsigned + Clone> ChaChaRngCore<R> {
+    #[inline]
+    fn get_block_pos(&self) -> u64 {
+        let state = &self.0.state;
+        u64::from(state[12]) | (u64::from(state[13]) << 32)
+    }
+
+    #[inline]
+    fn set_block_pos(&mut self, pos: u64) {
+        self.0.state[12] = pos as u32;
+        self.0.state[13] = (pos >> 32) as u32;
+    }
+
+    #[inline]
+    fn get_stream(&self) -> u64 {
+        let state = &self.0.state;
+        u64::from(state[14]) | (u64::from(state[15]) << 32)
+    }
+
+    #[inline]
+    fn set_stream(&mut self, stream: u64) {
+        self.0.state[14] = stream as u32;
+        self.0.state[15] = (stream >> 32) as u32;
+    }
+
+    fn get_seed(&self) -> [u8; 32] {
+        let mut seed = [0u8; 32];
+        for (chunk, val) in seed.chunks_exact_mut(4).zip(&self.0.state[4..12]) {
+            chunk.copy_from_slice(&val.to_le_bytes());
+        }
+        seed
+    }
+}
+
+impl<R: Unsigned + Clone> BlockRngCore for ChaChaRngCore<R> {
+    type Item = u32;
+    type Results = Results;
+
+    fn generate(&mut self, results: &mut Results) {
+        let mut blocks = [Block::default(); BUF_BLOCKS as usize];
+
+        // Backends only advance the low counter word, so blocks past its
+        // wrap-around are generated after carrying into the high word.
+        let low = self.0.get_block_pos();
+        let n = match u32::MAX - low {
+            rem if rem < u32::from(BUF_BLOCKS) => rem as usize + 1,
+            _ => BUF_BLOCKS as usize,
+        };
+        self.0.process_with_backend(FillBlocks(&mut blocks[..n]));
+        if self.0.get_block_pos() == 0 {
+            self.0.state[13] = self.0.state[13].wrapping_add(1);
+        }
+        if n < blocks.len() {
+            self.0.process_with_backend(FillBlocks(&mut blocks[n..]));
+        }
+
+        for (word, chunk) in results
+            .0
+            .iter_mut()
+            .zip(blocks.iter().flat_map(|b| b.chunks_exact(4)))
+        {
+            *word = u32::from_le_bytes(chunk.try_into().unwrap());
+        }
+    }
+}
+
+impl<R: Unsigned + Clone> SeedableRng for ChaChaRngCore<R> {
+    type Seed = [u8; 32];
+
+    #[inline]
+    fn from_seed(seed: Self::Seed) -> Self {
+        Self(ChaChaCore::new(&Key::from(seed), &Nonce::default()))
+    }
+}
+
+impl<R: Unsigned + Clone> CryptoRng for ChaChaRngCore<R> {}
+
+impl<R: Unsigned + Clone> fmt::Debug for ChaChaRngCore<R> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "ChaCha{}RngCore {{ ... }}", 2 * R::USIZE)
+    }
+}
+
+struct FillBlocks<'a>(&'a mut [Block]);
+
+impl<'a> cipher::BlockSizeUser for FillBlocks<'a> {
+    type BlockSize = U64;
+}
+
+impl<'a> StreamClosure for FillBlocks<'a> {
+    #[inline(always)]
+    fn call<B: StreamBackend<BlockSize = U64>>(self, backend: &mut B) {
+        let mut chunks = self.0.chunks_exact_mut(B::ParBlocksSize::USIZE);
+        for chunk in &mut chunks {
+            backend.gen_par_ks_blocks(GenericArray::from_mut_slice(chunk));
+        }
+        for block in chunks.into_remainder() {
+            backend.gen_ks_block(block);
+        }
+    }
+}
+
+/// ChaCha random number generator with `R` double rounds.
+///
+/// The keystream is addressed by a 64-bit stream id and a 68-bit word
+/// position, which allows independent generators to be derived from one
+/// seed and any part of the output to be reproduced without generating
+/// everything before it.
+#[derive(Clone)]
+pub struct ChaChaRng<R: Unsigned + Clone> {
+    rng: BlockRng<ChaChaRngCore<R>>,
+}
+
+impl<R: Unsigned + Clone> ChaChaRng<R> {
+    /// Offset of the next word to be returned, in 32-bit words.
+    #[inline]
+    pub fn get_word_pos(&self) -> u128 {
+        let buf_start_block = self
+            .rng
+            .core
+            .get_block_pos()
+            .wrapping_sub(u64::from(BUF_BLOCKS));
+        let index = self.rng.index() as u64;
+        let block = buf_start_block.wrapping_add(index / u64::from(BLOCK_WORDS));
+        u128::from(block) * u128::from(BLOCK_WORDS) + u128::from(index % u64::from(BLOCK_WORDS))
+    }
+
+    /// Seek to `word_offset` in the current stream. Only the low 68 bits
+    /// are used since the keystream repeats after 2^64 blocks.
+    #[inline]
+    pub fn set_word_pos(&mut self, word_offset: u128) {
+        let block = (word_offset / u128::from(BLOCK_WORDS)) as u64;
+        self.rng.core.set_block_pos(block);
+        self.rng
+            .generate_and_set((word_offset % u128::from(BLOCK_WORDS)) as usize);
+    }
+
+    /// Switch to `stream`, keeping the current word position.
+    #[inline]
+    pub fn set_stream(&mut self, stream: u64) {
+        self.rng.core.set_stream(stream);
+        if self.rng.index() != BUF_WORDS {
+            // Discard words buffered from the previous stream
+            let word_pos = self.get_word_pos();
+            self.set_word_pos(word_pos);
+        }
+    }
+
+    #[inline]
+    pub fn get_stream(&self) -> u64 {
+        self.rng.core.get_stream()
+    }
+
+    #[inline]
+    pub fn get_seed(&self) -> [u8; 32] {
+        self.rng.core.get_seed()
+    }
+}
+
+impl<R: Unsigned + Clone> SeedableRng for ChaChaRng<R> {
+    type Seed = [u8; 32];
+
+    #[inline]
+    fn from_seed(seed: Self::Seed) -> Self {
+        ChaChaRngCore::from_seed(seed).into()
+    }
+}
+
+impl<R: Unsigned + Clone> From<ChaChaRngCore<R>> for ChaChaRng<R> {
+    fn from(core: ChaChaRngCore<R>) -> Self {
+        Self {
+            rng: BlockRng::new(core),
+        }
+    }
+}
+
+impl<R: Unsigned + Clone> RngCore for ChaChaRng<R> {
+    #[inline]
+    fn next_u32(&mut self) -> u32 {
+        self.rng.next_u32()
+    }
+
+    #[inline]
+    fn next_u64(&mut self) -> u64 {
+        self.rng.next_u64()
+    }
+
+    #[inline]
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        self.rng.fill_bytes(dest)
+    }
+
+    #[inline]
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
+        self.rng.try_fill_bytes(dest)
+    }
+}
+
+impl<R: Unsigned + Clone> CryptoRng for ChaChaRng<R> {}
+
+impl<R: Unsigned + Clone> PartialEq for ChaChaRng<R> {
+    fn eq(&self, rhs: &Self) -> bool {
+        self.get_seed() == rhs.get_seed()
+            && self.get_stream() == rhs.get_stream()
+            && self.get_word_pos() == rhs.get_word_pos()
+    }
+}
+
+impl<R: Unsigned + Clone> Eq for ChaChaRng<R> {}
+
+impl<R: Unsigned + Clone> fmt::Debug for ChaChaRng<R> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "ChaCha{}Rng {{ ... }}", 2 * R::USIZE)
+    }
+}
+
+/// Serialized form of [`ChaChaRng`], compatible with `rand_chacha`.
+#[cfg(feature = "serde")]
+#[derive(Serialize, Deserialize)]
+struct SerdeState {
+    seed: [u8; 32],
+    stream: u64,
+    word_pos: u128,
+}
+
+#[cfg(feature = "serde")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+impl<R: Unsigned + Clone> Serialize for ChaChaRng<R> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        SerdeState {
+            seed: self.get_seed(),
+            stream: self.get_stream(),
+            word_pos: self.get_word_pos(),
+        }
+        .serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+impl<'de, R: Unsigned + Clone> Deserialize<'de> for ChaChaRng<R> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let state = SerdeState::deserialize(deserializer)?;
+        let mut rng = Self::from_seed(state.seed);
+        rng.set_stream(state.stream);
+        rng.set_word_pos(state.word_pos);
+        Ok(rng)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ChaCha12Rng, ChaCha20Rng, ChaCha8Rng};
+    use hex_literal::hex;
+    use rand_core::{RngCore, SeedableRng};
+
+    const SEED: [u8; 32] = hex!("000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f");
+
+    #[test]
+    fn zero_seed_keystream() {
+        let mut rng = ChaCha20Rng::from_seed([0; 32]);
+        let mut buf = [0u8; 64];
+        rng.fill_bytes(&mut buf);
+        assert_eq!(
+            buf,
+            hex!(
+                "76b8e0ada0f13d90405d6ae55386bd28bdd219b8a08ded1aa836efcc8b770dc7"
+                "da41597c5157488d7724e03fb8d84a376a43b8f41518a11cc387b669b2ee6586"
+            )
+        );
+    }
+
+    macro_rules! rand_chacha_test {
+        ($name:ident, $rng:ty, $reference:ty) => {
+            #[test]
+            fn $name() {
+                let mut rng = <$rng>::from_seed(SEED);
+                let mut reference = <$reference>::from_seed(SEED);
+                for (stream, word_pos) in [
+                    (0, 0),
+                    (1, 5),
+                    (u64::MAX, 1000),
+                    (7, (u128::from(u32::MAX) - 2) * 16 + 3),
+                    (7, (u128::from(u64::MAX) - 1) * 16),
+                ] {
+                    rng.set_stream(stream);
+                    rng.set_word_pos(word_pos);
+                    reference.set_stream(stream);
+                    reference.set_word_pos(word_pos);
+                    assert_eq!(rng.get_word_pos(), word_pos);
+
+                    let mut buf = [0u8; 1000];
+                    let mut expected = [0u8; 1000];
+                    rng.fill_bytes(&mut buf);
+                    reference.fill_bytes(&mut expected);
+                    assert_eq!(buf, expected);
+                    assert_eq!(rng.next_u64(), reference.next_u64());
+                    assert_eq!(rng.get_word_pos(), reference.get_word_pos());
+                }
+
+                // Switching streams mid-buffer discards the buffered words
+                rng.set_stream(3);
+                reference.set_stream(3);
+                assert_eq!(rng.next_u32(), reference.next_u32());
+                assert_eq!(rng.get_stream(), 3);
+                assert_eq!(rng.get_seed(), SEED);
+            }
+        };
+    }
+
+    rand_chacha_test!(chacha8_rand_chacha, ChaCha8Rng, rand_chacha::ChaCha8Rng);
+    rand_chacha_test!(chacha12_rand_chacha, ChaCha12Rng, rand_chacha::ChaCha12Rng);
+    rand_chacha_test!(chacha20_rand_chacha, ChaCha20Rng, rand_chacha::ChaCha20Rng);
+
+    #[test]
+    fn clone_and_eq() {
+        let mut rng = ChaCha20Rng::from_seed(SEED);
+        rng.next_u32();
+        let mut clone = rng.clone();
+        assert_eq!(rng, clone);
+        assert_eq!(rng.next_u64(), clone.next_u64());
+
+        clone.set_stream(1);
+        assert_ne!(rng, clone);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_roundtrip() {
+        let mut rng = ChaCha20Rng::from_seed(SEED);
+        rng.set_stream(42);
+        rng.set_word_pos(12345);
+
+        let json = serde_json::to_string(&rng).unwrap();
+        let mut restored: ChaCha20Rng = serde_json::from_str(&json).unwrap();
+        assert_eq!(rng, restored);
+        assert_eq!(rng.next_u64(), restored.next_u64());
+    }
+}
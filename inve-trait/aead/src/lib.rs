@@ -174,6 +174,17 @@ pub trait AeadInPlace: AeadCore {
     ) -> Result<()>;
 }
 
+/// AEADs whose tag is computed over the ciphertext and can be recomputed
+/// without decrypting it, as needed by transforms which commit to the tag.
+pub trait AeadComputeTag: AeadCore {
+    fn compute_tag(
+        &self,
+        nonce: &Nonce<Self>,
+        associated_data: &[u8],
+        ciphertext: &[u8],
+    ) -> Result<Tag<Self>>;
+}
+
 pub trait AeadMutInPlace: AeadCore {
     fn encrypt_in_place(
         &mut self,
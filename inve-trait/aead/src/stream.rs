@@ -10,6 +10,15 @@ use generic_array::{
 #[cfg(feature = "alloc")]
 use {crate::Payload, alloc::vec::Vec};
 
+#[cfg(feature = "std")]
+mod io;
+mod seekable;
+
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub use io::{DecryptReader, EncryptWriter};
+pub use seekable::{Header, SeekableStream, HEADER_PREFIX_LEN, MAGIC, VERSION};
+
 pub type Nonce<A, S> = GenericArray<u8, NonceSize<A, S>>;
 
 pub type NonceSize<A, S> =
@@ -0,0 +1,425 @@
+use super::{Header, NewStream, NonceSize, SeekableStream, StreamPrimitive};
+use crate::{AeadInPlace, Error};
+use alloc::vec::Vec;
+use core::ops::Sub;
+use generic_array::ArrayLength;
+use std::io::{self, Read, Seek, SeekFrom, Write};
+
+fn invalid_data(err: Error) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, err)
+}
+
+/// Encrypts everything written to it into the segmented file format.
+///
+/// Segments are written out as soon as they are known not to be the last
+/// one. [`EncryptWriter::finish`] must be called to write the final segment:
+/// a file which is not finished will fail to decrypt.
+pub struct EncryptWriter<W, A, S>
+where
+    W: Write,
+    A: AeadInPlace,
+    S: StreamPrimitive<A, Counter = u32>,
+    A::NonceSize: Sub<<S as StreamPrimitive<A>>::NonceOverhead>,
+    NonceSize<A, S>: ArrayLength<u8>,
+{
+    inner: W,
+    stream: SeekableStream<A, S>,
+    buffer: Vec<u8>,
+    index: u32,
+}
+
+impl<W, A, S> EncryptWriter<W, A, S>
+where
+    W: Write,
+    A: AeadInPlace,
+    S: StreamPrimitive<A, Counter = u32> + NewStream<A>,
+    A::NonceSize: Sub<<S as StreamPrimitive<A>>::NonceOverhead>,
+    NonceSize<A, S>: ArrayLength<u8>,
+{
+    /// Write `header` to `inner` and start encrypting.
+    pub fn new(mut inner: W, aead: A, header: &Header<NonceSize<A, S>>) -> io::Result<Self> {
+        inner.write_all(&header.to_vec())?;
+        let stream = SeekableStream::from_aead(aead, header);
+        Ok(Self {
+            inner,
+            buffer: Vec::with_capacity(stream.ciphertext_segment_size()),
+            stream,
+            index: 0,
+        })
+    }
+}
+
+impl<W, A, S> EncryptWriter<W, A, S>
+where
+    W: Write,
+    A: AeadInPlace,
+    S: StreamPrimitive<A, Counter = u32>,
+    A::NonceSize: Sub<<S as StreamPrimitive<A>>::NonceOverhead>,
+    NonceSize<A, S>: ArrayLength<u8>,
+{
+    /// Encrypt and write the last segment, returning the inner writer.
+    pub fn finish(mut self) -> io::Result<W> {
+        self.write_segment(true)?;
+        self.inner.flush()?;
+        Ok(self.inner)
+    }
+
+    fn write_segment(&mut self, last_segment: bool) -> io::Result<()> {
+        // Only the last segment may take the final index
+        let next_index = match self.index.checked_add(1) {
+            Some(index) => index,
+            None if last_segment => self.index,
+            None => {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    "too many stream segments",
+                ))
+            }
+        };
+
+        self.stream
+            .encrypt_segment_in_place(self.index, last_segment, &mut self.buffer)
+            .map_err(invalid_data)?;
+        self.inner.write_all(&self.buffer)?;
+        self.buffer.clear();
+        self.index = next_index;
+        Ok(())
+    }
+}
+
+impl<W, A, S> Write for EncryptWriter<W, A, S>
+where
+    W: Write,
+    A: AeadInPlace,
+    S: StreamPrimitive<A, Counter = u32>,
+    A::NonceSize: Sub<<S as StreamPrimitive<A>>::NonceOverhead>,
+    NonceSize<A, S>: ArrayLength<u8>,
+{
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        if buf.is_empty() {
+            return Ok(0);
+        }
+        if self.buffer.len() == self.stream.segment_size() {
+            self.write_segment(false)?;
+        }
+
+        let n = buf
+            .len()
+            .min(self.stream.segment_size() - self.buffer.len());
+        self.buffer.extend_from_slice(&buf[..n]);
+        Ok(n)
+    }
+
+    /// Flush the inner writer. Buffered plaintext of the current segment is
+    /// only written once the segment is complete.
+    fn flush(&mut self) -> io::Result<()> {
+        self.inner.flush()
+    }
+}
+
+/// Decrypts a file in the segmented format with random access.
+///
+/// Apart from the last segment, which is checked when the reader is
+/// created, only the segments covering the data actually read are fetched
+/// and authenticated. Seeking is done in plaintext coordinates.
+pub struct DecryptReader<R, A, S>
+where
+    R: Read + Seek,
+    A: AeadInPlace,
+    S: StreamPrimitive<A, Counter = u32>,
+    A::NonceSize: Sub<<S as StreamPrimitive<A>>::NonceOverhead>,
+    NonceSize<A, S>: ArrayLength<u8>,
+{
+    inner: R,
+    stream: SeekableStream<A, S>,
+    header: Header<NonceSize<A, S>>,
+    segments: u64,
+    ciphertext_len: u64,
+    len: u64,
+    pos: u64,
+    buffer: Vec<u8>,
+    buffered: Option<u64>,
+}
+
+impl<R, A, S> DecryptReader<R, A, S>
+where
+    R: Read + Seek,
+    A: AeadInPlace,
+    S: StreamPrimitive<A, Counter = u32> + NewStream<A>,
+    A::NonceSize: Sub<<S as StreamPrimitive<A>>::NonceOverhead>,
+    NonceSize<A, S>: ArrayLength<u8>,
+{
+    /// Read the header at the start of `inner`, failing if its algorithm id
+    /// is not `algorithm_id`.
+    pub fn new(mut inner: R, aead: A, algorithm_id: u16) -> io::Result<Self> {
+        let header_len = Header::<NonceSize<A, S>>::ENCODED_LEN;
+        let mut header = alloc::vec![0; header_len];
+        inner.seek(SeekFrom::Start(0))?;
+        inner.read_exact(&mut header)?;
+
+        let header = Header::decode(&header).map_err(invalid_data)?;
+        if header.algorithm_id != algorithm_id {
+            return Err(invalid_data(Error));
+        }
+
+        let stream = SeekableStream::from_aead(aead, &header);
+        let ciphertext_len = inner
+            .seek(SeekFrom::End(0))?
+            .checked_sub(header_len as u64)
+            .ok_or_else(|| invalid_data(Error))?;
+        let (segments, len) = stream.layout(ciphertext_len).map_err(invalid_data)?;
+
+        let mut reader = Self {
+            inner,
+            buffer: Vec::with_capacity(stream.ciphertext_segment_size()),
+            stream,
+            header,
+            segments,
+            ciphertext_len,
+            len,
+            pos: 0,
+            buffered: None,
+        };
+
+        // Authenticating the last segment up front detects truncated files
+        // and makes `len` trustworthy.
+        reader.load_segment(segments - 1)?;
+        Ok(reader)
+    }
+}
+
+impl<R, A, S> DecryptReader<R, A, S>
+where
+    R: Read + Seek,
+    A: AeadInPlace,
+    S: StreamPrimitive<A, Counter = u32>,
+    A::NonceSize: Sub<<S as StreamPrimitive<A>>::NonceOverhead>,
+    NonceSize<A, S>: ArrayLength<u8>,
+{
+    pub fn header(&self) -> &Header<NonceSize<A, S>> {
+        &self.header
+    }
+
+    /// Length of the plaintext.
+    pub fn len(&self) -> u64 {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn into_inner(self) -> R {
+        self.inner
+    }
+
+    /// Fetch and decrypt segment `index` unless it is already buffered.
+    fn load_segment(&mut self, index: u64) -> io::Result<()> {
+        if self.buffered == Some(index) {
+            return Ok(());
+        }
+        self.buffered = None;
+
+        let header_len = Header::<NonceSize<A, S>>::ENCODED_LEN as u64;
+        let ct_segment = self.stream.ciphertext_segment_size() as u64;
+        let offset = index * ct_segment;
+        let last_segment = index + 1 == self.segments;
+        let ct_len = if last_segment {
+            self.ciphertext_len - offset
+        } else {
+            ct_segment
+        };
+
+        self.buffer.resize(ct_len as usize, 0);
+        self.inner.seek(SeekFrom::Start(header_len + offset))?;
+        self.inner.read_exact(&mut self.buffer)?;
+        self.stream
+            .decrypt_segment_in_place(index as u32, last_segment, &mut self.buffer)
+            .map_err(invalid_data)?;
+
+        self.buffered = Some(index);
+        Ok(())
+    }
+}
+
+impl<R, A, S> Read for DecryptReader<R, A, S>
+where
+    R: Read + Seek,
+    A: AeadInPlace,
+    S: StreamPrimitive<A, Counter = u32>,
+    A::NonceSize: Sub<<S as StreamPrimitive<A>>::NonceOverhead>,
+    NonceSize<A, S>: ArrayLength<u8>,
+{
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        if buf.is_empty() || self.pos >= self.len {
+            return Ok(0);
+        }
+
+        let segment_size = self.stream.segment_size() as u64;
+        let index = self.pos / segment_size;
+        self.load_segment(index)?;
+
+        let start = (self.pos % segment_size) as usize;
+        let n = buf.len().min(self.buffer.len() - start);
+        buf[..n].copy_from_slice(&self.buffer[start..start + n]);
+        self.pos += n as u64;
+        Ok(n)
+    }
+}
+
+impl<R, A, S> Seek for DecryptReader<R, A, S>
+where
+    R: Read + Seek,
+    A: AeadInPlace,
+    S: StreamPrimitive<A, Counter = u32>,
+    A::NonceSize: Sub<<S as StreamPrimitive<A>>::NonceOverhead>,
+    NonceSize<A, S>: ArrayLength<u8>,
+{
+    fn seek(&mut self, pos: SeekFrom) -> io::Result<u64> {
+        let (base, offset) = match pos {
+            SeekFrom::Start(pos) => (pos, 0),
+            SeekFrom::End(offset) => (self.len, offset),
+            SeekFrom::Current(offset) => (self.pos, offset),
+        };
+        self.pos = if offset >= 0 {
+            base.checked_add(offset as u64)
+        } else {
+            base.checked_sub(offset.unsigned_abs())
+        }
+        .ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "invalid seek to a negative or overflowing position",
+            )
+        })?;
+        Ok(self.pos)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{DecryptReader, EncryptWriter};
+    use crate::stream::{seekable::tests::MockAead, Header, NonceSize, StreamBE32, StreamLE31};
+    use alloc::vec::Vec;
+    use generic_array::typenum::Unsigned;
+    use std::io::{Cursor, ErrorKind, Read, Seek, SeekFrom, Write};
+
+    const ALGORITHM_ID: u16 = 0x1234;
+
+    macro_rules! io_test {
+        ($name:ident, $stream:ident) => {
+            mod $name {
+                use super::*;
+
+                type Writer = EncryptWriter<Vec<u8>, MockAead, $stream<MockAead>>;
+                type Reader = DecryptReader<Cursor<Vec<u8>>, MockAead, $stream<MockAead>>;
+                type Prefix = NonceSize<MockAead, $stream<MockAead>>;
+
+                fn encrypt(plaintext: &[u8], write_size: usize) -> Vec<u8> {
+                    let nonce_prefix = (1..).take(Prefix::USIZE).collect();
+                    let header = Header::new(ALGORITHM_ID, 16, nonce_prefix).unwrap();
+                    let mut writer = Writer::new(Vec::new(), MockAead, &header).unwrap();
+                    for chunk in plaintext.chunks(write_size) {
+                        writer.write_all(chunk).unwrap();
+                    }
+                    writer.finish().unwrap()
+                }
+
+                fn reader(file: Vec<u8>) -> std::io::Result<Reader> {
+                    Reader::new(Cursor::new(file), MockAead, ALGORITHM_ID)
+                }
+
+                #[test]
+                fn roundtrip() {
+                    let plaintext: Vec<u8> = (0..100).collect();
+                    for len in [0, 1, 15, 16, 17, 32, 33, 100] {
+                        let file = encrypt(&plaintext[..len], 7);
+                        assert_eq!(file, encrypt(&plaintext[..len], 16));
+
+                        let mut reader = reader(file).unwrap();
+                        assert_eq!(reader.len(), len as u64);
+                        let mut decrypted = Vec::new();
+                        reader.read_to_end(&mut decrypted).unwrap();
+                        assert_eq!(decrypted, &plaintext[..len]);
+                    }
+                }
+
+                #[test]
+                fn random_access() {
+                    let plaintext: Vec<u8> = (0..100).collect();
+                    let mut reader = reader(encrypt(&plaintext, 100)).unwrap();
+
+                    for (pos, len) in [(90, 10), (0, 3), (31, 2), (15, 20), (99, 1), (47, 0)] {
+                        let mut buf = alloc::vec![0; len];
+                        assert_eq!(reader.seek(SeekFrom::Start(pos)).unwrap(), pos);
+                        reader.read_exact(&mut buf).unwrap();
+                        assert_eq!(buf, &plaintext[pos as usize..][..len]);
+                    }
+
+                    assert_eq!(reader.seek(SeekFrom::End(-5)).unwrap(), 95);
+                    assert_eq!(reader.seek(SeekFrom::Current(-45)).unwrap(), 50);
+                    let mut buf = [0; 4];
+                    reader.read_exact(&mut buf).unwrap();
+                    assert_eq!(buf, [50, 51, 52, 53]);
+                    assert!(reader.seek(SeekFrom::Current(-55)).is_err());
+
+                    reader.seek(SeekFrom::End(10)).unwrap();
+                    assert_eq!(reader.read(&mut buf).unwrap(), 0);
+                }
+
+                #[test]
+                fn tampering() {
+                    let plaintext: Vec<u8> = (0..100).collect();
+                    let file = encrypt(&plaintext, 100);
+                    let header_len = Header::<Prefix>::ENCODED_LEN;
+                    let ct_segment = 16 + 16;
+
+                    // A modified segment only fails when it is read
+                    let mut bad = file.clone();
+                    bad[header_len + ct_segment + 3] ^= 1;
+                    let mut r = reader(bad).unwrap();
+                    let mut buf = [0; 16];
+                    r.read_exact(&mut buf).unwrap();
+                    let err = r.read_exact(&mut buf).unwrap_err();
+                    assert_eq!(err.kind(), ErrorKind::InvalidData);
+                    r.seek(SeekFrom::Start(32)).unwrap();
+                    r.read_exact(&mut buf).unwrap();
+
+                    // Header fields are authenticated, even when the reader
+                    // expects the modified algorithm id
+                    for (pos, id) in [(7, ALGORITHM_ID ^ 1), (11, ALGORITHM_ID), (header_len - 1, ALGORITHM_ID)] {
+                        let mut bad = file.clone();
+                        bad[pos] ^= 1;
+                        assert!(Reader::new(Cursor::new(bad), MockAead, id).is_err());
+                    }
+
+                    // Truncation at a segment boundary
+                    let truncated = file[..header_len + 3 * ct_segment].to_vec();
+                    assert!(reader(truncated).is_err());
+
+                    assert!(Reader::new(Cursor::new(file), MockAead, ALGORITHM_ID + 1).is_err());
+                }
+            }
+        };
+    }
+
+    io_test!(be32, StreamBE32);
+    io_test!(le31, StreamLE31);
+
+    #[test]
+    fn last_index() {
+        type Writer = EncryptWriter<Vec<u8>, MockAead, StreamBE32<MockAead>>;
+
+        let nonce_prefix = (1..)
+            .take(NonceSize::<MockAead, StreamBE32<MockAead>>::USIZE)
+            .collect();
+        let header = Header::new(ALGORITHM_ID, 16, nonce_prefix).unwrap();
+        let mut writer = Writer::new(Vec::new(), MockAead, &header).unwrap();
+        writer.index = u32::MAX;
+
+        writer.write_all(&[0; 16]).unwrap();
+        let err = writer.write_all(&[0]).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::InvalidInput);
+        writer.finish().unwrap();
+    }
+}
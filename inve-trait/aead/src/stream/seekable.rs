@@ -0,0 +1,395 @@
+use super::{NewStream, NonceSize, StreamPrimitive};
+use crate::{AeadInPlace, Buffer, Error, Key, NewAead, Result};
+use core::{marker::PhantomData, ops::Sub};
+use generic_array::{typenum::Unsigned, ArrayLength, GenericArray};
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+pub const MAGIC: [u8; 4] = *b"AEST";
+
+pub const VERSION: u8 = 1;
+
+/// Length of the fixed part of a [`Header`], which precedes the nonce prefix.
+pub const HEADER_PREFIX_LEN: usize = 12;
+
+/// Header of the segmented file format.
+///
+/// Encoded as:
+///
+/// | Offset | Length | Field                                   |
+/// |--------|--------|-----------------------------------------|
+/// | 0      | 4      | [`MAGIC`]                               |
+/// | 4      | 1      | [`VERSION`]                             |
+/// | 5      | 1      | length of the nonce prefix              |
+/// | 6      | 2      | algorithm id, big endian                |
+/// | 8      | 4      | plaintext segment size, big endian      |
+/// | 12     | N      | STREAM nonce prefix                     |
+///
+/// The algorithm id is chosen by the application to identify the AEAD and
+/// STREAM construction. The first [`HEADER_PREFIX_LEN`] bytes are passed as
+/// associated data of every segment, and the nonce prefix is part of every
+/// segment nonce, so the whole header is authenticated.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Header<N: ArrayLength<u8>> {
+    pub algorithm_id: u16,
+    pub segment_size: u32,
+    pub nonce_prefix: GenericArray<u8, N>,
+}
+
+impl<N: ArrayLength<u8>> Header<N> {
+    pub const ENCODED_LEN: usize = HEADER_PREFIX_LEN + N::USIZE;
+
+    /// Returns an error if `segment_size` is zero.
+    pub fn new(
+        algorithm_id: u16,
+        segment_size: u32,
+        nonce_prefix: GenericArray<u8, N>,
+    ) -> Result<Self> {
+        if segment_size == 0 {
+            return Err(Error);
+        }
+        Ok(Self {
+            algorithm_id,
+            segment_size,
+            nonce_prefix,
+        })
+    }
+
+    /// Write the header into the first [`Header::ENCODED_LEN`] bytes of `out`.
+    pub fn encode(&self, out: &mut [u8]) -> Result<()> {
+        if out.len() < Self::ENCODED_LEN {
+            return Err(Error);
+        }
+        let (prefix, nonce) = out[..Self::ENCODED_LEN].split_at_mut(HEADER_PREFIX_LEN);
+        prefix.copy_from_slice(&self.prefix());
+        nonce.copy_from_slice(&self.nonce_prefix);
+        Ok(())
+    }
+
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    pub fn to_vec(&self) -> Vec<u8> {
+        let mut out = alloc::vec![0; Self::ENCODED_LEN];
+        self.encode(&mut out)
+            .expect("buffer has the encoded length");
+        out
+    }
+
+    /// Parse a header from the start of `bytes`, ignoring any trailing data.
+    pub fn decode(bytes: &[u8]) -> Result<Self> {
+        if bytes.len() < Self::ENCODED_LEN
+            || bytes[..4] != MAGIC
+            || bytes[4] != VERSION
+            || usize::from(bytes[5]) != N::USIZE
+        {
+            return Err(Error);
+        }
+
+        let algorithm_id = u16::from_be_bytes([bytes[6], bytes[7]]);
+        let segment_size = u32::from_be_bytes([bytes[8], bytes[9], bytes[10], bytes[11]]);
+        let nonce_prefix =
+            GenericArray::clone_from_slice(&bytes[HEADER_PREFIX_LEN..Self::ENCODED_LEN]);
+        Self::new(algorithm_id, segment_size, nonce_prefix)
+    }
+
+    fn prefix(&self) -> [u8; HEADER_PREFIX_LEN] {
+        let mut prefix = [0u8; HEADER_PREFIX_LEN];
+        prefix[..4].copy_from_slice(&MAGIC);
+        prefix[4] = VERSION;
+        prefix[5] = N::U8;
+        prefix[6..8].copy_from_slice(&self.algorithm_id.to_be_bytes());
+        prefix[8..].copy_from_slice(&self.segment_size.to_be_bytes());
+        prefix
+    }
+}
+
+/// STREAM with random access to its segments.
+///
+/// The plaintext is split into segments of [`Header::segment_size`] bytes,
+/// only the last of which may be shorter (or empty, for an empty message).
+/// Segment `i` is encrypted with the STREAM nonce for position `i` and each
+/// ciphertext segment is longer than its plaintext by the AEAD overhead, so
+/// any segment can be located and processed independently of the others.
+pub struct SeekableStream<A, S>
+where
+    A: AeadInPlace,
+    S: StreamPrimitive<A, Counter = u32>,
+    A::NonceSize: Sub<<S as StreamPrimitive<A>>::NonceOverhead>,
+    NonceSize<A, S>: ArrayLength<u8>,
+{
+    stream: S,
+    associated_data: [u8; HEADER_PREFIX_LEN],
+    segment_size: u32,
+    aead: PhantomData<A>,
+}
+
+impl<A, S> SeekableStream<A, S>
+where
+    A: AeadInPlace,
+    S: StreamPrimitive<A, Counter = u32>,
+    A::NonceSize: Sub<<S as StreamPrimitive<A>>::NonceOverhead>,
+    NonceSize<A, S>: ArrayLength<u8>,
+{
+    pub fn new(key: &Key<A>, header: &Header<NonceSize<A, S>>) -> Self
+    where
+        A: NewAead,
+        S: NewStream<A>,
+    {
+        Self::from_aead(A::new(key), header)
+    }
+
+    pub fn from_aead(aead: A, header: &Header<NonceSize<A, S>>) -> Self
+    where
+        S: NewStream<A>,
+    {
+        Self::from_stream_primitive(S::from_aead(aead, &header.nonce_prefix), header)
+    }
+
+    /// `stream` must have been created with the nonce prefix of `header`.
+    pub fn from_stream_primitive(stream: S, header: &Header<NonceSize<A, S>>) -> Self {
+        Self {
+            stream,
+            associated_data: header.prefix(),
+            segment_size: header.segment_size,
+            aead: PhantomData,
+        }
+    }
+
+    /// Size of a plaintext segment.
+    pub fn segment_size(&self) -> usize {
+        self.segment_size as usize
+    }
+
+    /// Size of a ciphertext segment.
+    pub fn ciphertext_segment_size(&self) -> usize {
+        self.segment_size() + Self::overhead()
+    }
+
+    /// Number of segments and plaintext length for a ciphertext of
+    /// `ciphertext_len` bytes, not counting the header.
+    pub fn layout(&self, ciphertext_len: u64) -> Result<(u64, u64)> {
+        let ct_segment = self.ciphertext_segment_size() as u64;
+        let overhead = Self::overhead() as u64;
+
+        let segments = if ciphertext_len == 0 {
+            0
+        } else {
+            (ciphertext_len - 1) / ct_segment + 1
+        };
+        let last_len = ciphertext_len - segments.saturating_sub(1) * ct_segment;
+        if segments == 0 || segments - 1 > u64::from(S::COUNTER_MAX) || last_len < overhead {
+            return Err(Error);
+        }
+        Ok((segments, ciphertext_len - segments * overhead))
+    }
+
+    pub fn encrypt_segment_in_place(
+        &self,
+        index: u32,
+        last_segment: bool,
+        buffer: &mut dyn Buffer,
+    ) -> Result<()> {
+        self.check_segment(index, last_segment, buffer.len())?;
+        self.stream
+            .encrypt_in_place(index, last_segment, &self.associated_data, buffer)
+    }
+
+    pub fn decrypt_segment_in_place(
+        &self,
+        index: u32,
+        last_segment: bool,
+        buffer: &mut dyn Buffer,
+    ) -> Result<()> {
+        let len = buffer.len().checked_sub(Self::overhead()).ok_or(Error)?;
+        self.check_segment(index, last_segment, len)?;
+        self.stream
+            .decrypt_in_place(index, last_segment, &self.associated_data, buffer)
+    }
+
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    pub fn encrypt_segment(
+        &self,
+        index: u32,
+        last_segment: bool,
+        plaintext: &[u8],
+    ) -> Result<Vec<u8>> {
+        let mut buffer = Vec::with_capacity(plaintext.len() + Self::overhead());
+        buffer.extend_from_slice(plaintext);
+        self.encrypt_segment_in_place(index, last_segment, &mut buffer)?;
+        Ok(buffer)
+    }
+
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    pub fn decrypt_segment(
+        &self,
+        index: u32,
+        last_segment: bool,
+        ciphertext: &[u8],
+    ) -> Result<Vec<u8>> {
+        let mut buffer = Vec::from(ciphertext);
+        self.decrypt_segment_in_place(index, last_segment, &mut buffer)?;
+        Ok(buffer)
+    }
+
+    fn overhead() -> usize {
+        A::TagSize::USIZE + A::CiphertextOverhead::USIZE
+    }
+
+    fn check_segment(&self, index: u32, last_segment: bool, len: usize) -> Result<()> {
+        let valid = if last_segment {
+            len <= self.segment_size() && index <= S::COUNTER_MAX
+        } else {
+            len == self.segment_size() && index < S::COUNTER_MAX
+        };
+        if valid {
+            Ok(())
+        } else {
+            Err(Error)
+        }
+    }
+}
+
+#[cfg(all(test, feature = "alloc"))]
+pub(super) mod tests {
+    use super::{Header, SeekableStream, HEADER_PREFIX_LEN};
+    use crate::{
+        consts::{U0, U12, U16, U8},
+        stream::{NewStream, StreamBE32, StreamLE31, StreamPrimitive},
+        AeadCore, AeadInPlace, Error, Nonce, Payload, Result, Tag,
+    };
+    use generic_array::GenericArray;
+
+    /// Insecure AEAD which is only good enough to detect modifications.
+    #[derive(Clone, Copy)]
+    pub(in crate::stream) struct MockAead;
+
+    impl MockAead {
+        fn tag(nonce: &[u8], associated_data: &[u8], ciphertext: &[u8]) -> Tag<Self> {
+            let mut tag = Tag::<Self>::default();
+            for (chunk, seed) in tag.chunks_mut(8).zip([0xcbf2_9ce4_8422_2325u64, 1]) {
+                let len = (associated_data.len() as u64).to_le_bytes();
+                let data = [nonce, &len, associated_data, ciphertext];
+                let hash = data.iter().flat_map(|d| d.iter()).fold(seed, |h, &b| {
+                    (h ^ u64::from(b)).wrapping_mul(0x0100_0000_01b3)
+                });
+                chunk.copy_from_slice(&hash.to_le_bytes());
+            }
+            tag
+        }
+
+        fn apply_keystream(nonce: &[u8], buffer: &mut [u8]) {
+            for (i, b) in buffer.iter_mut().enumerate() {
+                *b ^= nonce[i % nonce.len()] ^ i as u8;
+            }
+        }
+    }
+
+    impl AeadCore for MockAead {
+        type NonceSize = U12;
+        type TagSize = U16;
+        type CiphertextOverhead = U0;
+    }
+
+    impl AeadInPlace for MockAead {
+        fn encrypt_in_place_detached(
+            &self,
+            nonce: &Nonce<Self>,
+            associated_data: &[u8],
+            buffer: &mut [u8],
+        ) -> Result<Tag<Self>> {
+            Self::apply_keystream(nonce, buffer);
+            Ok(Self::tag(nonce, associated_data, buffer))
+        }
+
+        fn decrypt_in_place_detached(
+            &self,
+            nonce: &Nonce<Self>,
+            associated_data: &[u8],
+            buffer: &mut [u8],
+            tag: &Tag<Self>,
+        ) -> Result<()> {
+            if Self::tag(nonce, associated_data, buffer) != *tag {
+                return Err(Error);
+            }
+            Self::apply_keystream(nonce, buffer);
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn header_encoding() {
+        let header = Header::new(7, 0x1000, GenericArray::from([0xaa; 8])).unwrap();
+        let bytes = header.to_vec();
+        assert_eq!(
+            bytes[..HEADER_PREFIX_LEN],
+            [b'A', b'E', b'S', b'T', 1, 8, 0, 7, 0, 0, 0x10, 0]
+        );
+        assert_eq!(Header::decode(&bytes).unwrap(), header);
+
+        // Bad magic, version, nonce prefix length and segment size
+        for (pos, value) in [(0, b'X'), (4, 2), (5, 7), (10, 0)] {
+            let mut bad = bytes.clone();
+            bad[pos] = value;
+            assert!(Header::<U8>::decode(&bad).is_err());
+        }
+        assert!(Header::<U8>::decode(&bytes[..19]).is_err());
+        assert!(Header::new(7, 0, GenericArray::from([0; 8])).is_err());
+    }
+
+    macro_rules! segments_test {
+        ($name:ident, $stream:ident) => {
+            #[test]
+            fn $name() {
+                let header = Header::new(1, 16, Default::default()).unwrap();
+                let stream =
+                    SeekableStream::<MockAead, $stream<MockAead>>::from_aead(MockAead, &header);
+                let plaintext: Vec<u8> = (0..70).collect();
+                let chunks: Vec<_> = plaintext.chunks(16).collect();
+                assert_eq!(stream.layout(70 + 5 * 16), Ok((5, 70)));
+
+                // Segments are encrypted with the STREAM nonce for their index
+                // and the header prefix as associated data.
+                let primitive = $stream::from_aead(MockAead, &header.nonce_prefix);
+                let aad = &header.to_vec()[..HEADER_PREFIX_LEN];
+                let ciphertexts: Vec<_> = (0..5)
+                    .rev()
+                    .map(|i| {
+                        let last = i == 4;
+                        let ct = stream.encrypt_segment(i, last, chunks[i as usize]).unwrap();
+                        let payload = Payload {
+                            msg: chunks[i as usize],
+                            aad,
+                        };
+                        assert_eq!(ct, primitive.encrypt(i, last, payload).unwrap());
+                        ct
+                    })
+                    .collect();
+
+                for (i, ct) in ciphertexts.iter().rev().enumerate() {
+                    let i = i as u32;
+                    assert_eq!(
+                        stream.decrypt_segment(i, i == 4, ct).unwrap(),
+                        chunks[i as usize]
+                    );
+                    assert!(stream.decrypt_segment(i, i != 4, ct).is_err());
+                    assert!(stream.decrypt_segment(i + 1, i == 4, ct).is_err());
+                }
+
+                assert!(stream.encrypt_segment(0, false, &plaintext[..15]).is_err());
+                assert!(stream.encrypt_segment(0, true, &plaintext[..17]).is_err());
+                assert!(stream
+                    .encrypt_segment(<$stream<MockAead>>::COUNTER_MAX, false, &plaintext[..16])
+                    .is_err());
+                assert!(stream.layout(0).is_err());
+                assert!(stream.layout(32 + 15).is_err());
+                assert_eq!(stream.layout(32 + 16), Ok((2, 16)));
+            }
+        };
+    }
+
+    segments_test!(segments_be32, StreamBE32);
+    segments_test!(segments_le31, StreamLE31);
+}
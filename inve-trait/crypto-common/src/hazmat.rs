@@ -0,0 +1,30 @@
+//! Access to the internal state of block-level algorithms.
+//!
+//! A serialized state is as sensitive as the data absorbed into it (e.g. a
+//! keyed MAC state can be used to forge tags), so handle it accordingly.
+
+use crate::generic_array::{ArrayLength, GenericArray};
+use core::fmt;
+
+pub type SerializedState<T> = GenericArray<u8, <T as SerializableState>::SerializedStateSize>;
+
+pub trait SerializableState: Sized {
+    type SerializedStateSize: ArrayLength<u8>;
+
+    fn serialize(&self) -> SerializedState<Self>;
+
+    fn deserialize(serialized_state: &SerializedState<Self>)
+        -> Result<Self, DeserializeStateError>;
+}
+
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub struct DeserializeStateError;
+
+impl fmt::Display for DeserializeStateError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
+        f.write_str("Deserialization error")
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for DeserializeStateError {}
@@ -12,6 +12,8 @@ use generic_array::{typenum::Unsigned, ArrayLength, GenericArray};
 #[cfg(feature = "rand_core")]
 use rand_core::{CryptoRng, RngCore};
 
+pub mod hazmat;
+
 pub type Block<B> = GenericArray<u8, <B as BlockSizeUser>::BlockSize>;
 pub type Output<T> = GenericArray<u8, <T as OutputSizeUser>::OutputSize>;
 pub type Key<B> = GenericArray<u8, <B as KeySizeUser>::KeySize>;
@@ -8,6 +8,7 @@ use crate::MacMarker;
 use core::{fmt, marker::PhantomData};
 use crypto_common::{
     generic_array::{ArrayLength, GenericArray},
+    hazmat::{DeserializeStateError, SerializableState, SerializedState},
     typenum::{IsLess, IsLessOrEqual, Le, LeEq, NonZero, U256},
     Block, BlockSizeUser, OutputSizeUser,
 };
@@ -149,6 +150,32 @@ where
     }
 }
 
+impl<T, OutSize> SerializableState for CtVariableCoreWrapper<T, OutSize>
+where
+    T: VariableOutputCore + SerializableState,
+    OutSize: ArrayLength<u8> + IsLessOrEqual<T::OutputSize>,
+    LeEq<OutSize, T::OutputSize>: NonZero,
+    T::BlockSize: IsLess<U256>,
+    Le<T::BlockSize, U256>: NonZero,
+{
+    type SerializedStateSize = T::SerializedStateSize;
+
+    #[inline]
+    fn serialize(&self) -> SerializedState<Self> {
+        self.inner.serialize()
+    }
+
+    #[inline]
+    fn deserialize(
+        serialized_state: &SerializedState<Self>,
+    ) -> Result<Self, DeserializeStateError> {
+        T::deserialize(serialized_state).map(|inner| Self {
+            inner,
+            _out: PhantomData,
+        })
+    }
+}
+
 impl<T, OutSize> AlgorithmName for CtVariableCoreWrapper<T, OutSize>
 where
     T: VariableOutputCore + AlgorithmName,
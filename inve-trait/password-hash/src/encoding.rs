@@ -0,0 +1,42 @@
+#[allow(deprecated)]
+use base64ct::{
+    Base64Bcrypt, Base64Crypt, Base64Unpadded as B64, Encoding as _, Error as B64Error,
+};
+
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, PartialOrd, Ord)]
+pub enum Encoding {
+    #[default]
+    B64,
+
+    Bcrypt,
+
+    Crypt,
+}
+
+#[allow(deprecated)]
+impl Encoding {
+    pub fn decode(self, src: impl AsRef<[u8]>, dst: &mut [u8]) -> Result<&[u8], B64Error> {
+        match self {
+            Self::B64 => B64::decode(src, dst),
+            Self::Bcrypt => Base64Bcrypt::decode(src, dst),
+            Self::Crypt => Base64Crypt::decode(src, dst),
+        }
+    }
+
+    pub fn encode<'a>(self, src: &[u8], dst: &'a mut [u8]) -> Result<&'a str, B64Error> {
+        match self {
+            Self::B64 => B64::encode(src, dst),
+            Self::Bcrypt => Base64Bcrypt::encode(src, dst),
+            Self::Crypt => Base64Crypt::encode(src, dst),
+        }
+        .map_err(Into::into)
+    }
+
+    pub fn encoded_len(self, bytes: &[u8]) -> usize {
+        match self {
+            Self::B64 => B64::encoded_len(bytes),
+            Self::Bcrypt => Base64Bcrypt::encoded_len(bytes),
+            Self::Crypt => Base64Crypt::encoded_len(bytes),
+        }
+    }
+}
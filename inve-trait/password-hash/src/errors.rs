@@ -0,0 +1,112 @@
+pub use base64ct::Error as B64Error;
+
+use core::fmt;
+
+pub type Result<T> = core::result::Result<T, Error>;
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum Error {
+    Algorithm,
+
+    B64Encoding(B64Error),
+
+    Crypto,
+
+    OutputTooShort,
+
+    OutputTooLong,
+
+    ParamNameDuplicated,
+
+    ParamNameInvalid,
+
+    ParamValueInvalid(InvalidValue),
+
+    ParamsMaxExceeded,
+
+    Password,
+
+    PhcStringInvalid,
+
+    PhcStringTooShort,
+
+    PhcStringTooLong,
+
+    SaltInvalid(InvalidValue),
+
+    Version,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> core::result::Result<(), fmt::Error> {
+        match self {
+            Self::Algorithm => write!(f, "unsupported algorithm"),
+            Self::B64Encoding(err) => write!(f, "{}", err),
+            Self::Crypto => write!(f, "cryptographic error"),
+            Self::OutputTooShort => f.write_str("PHF output too short (min 10-bytes)"),
+            Self::OutputTooLong => f.write_str("PHF output too long (max 64-bytes)"),
+            Self::ParamNameDuplicated => f.write_str("duplicate parameter"),
+            Self::ParamNameInvalid => f.write_str("invalid parameter name"),
+            Self::ParamValueInvalid(val_err) => write!(f, "invalid parameter value: {}", val_err),
+            Self::ParamsMaxExceeded => f.write_str("maximum number of parameters reached"),
+            Self::Password => write!(f, "invalid password"),
+            Self::PhcStringInvalid => write!(f, "password hash string invalid"),
+            Self::PhcStringTooShort => write!(f, "password hash string too short"),
+            Self::PhcStringTooLong => write!(f, "password hash string too long"),
+            Self::SaltInvalid(val_err) => write!(f, "salt invalid: {}", val_err),
+            Self::Version => write!(f, "invalid algorithm version"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for Error {}
+
+impl From<B64Error> for Error {
+    fn from(err: B64Error) -> Error {
+        Error::B64Encoding(err)
+    }
+}
+
+impl From<base64ct::InvalidLengthError> for Error {
+    fn from(_: base64ct::InvalidLengthError) -> Error {
+        Error::B64Encoding(B64Error::InvalidLength)
+    }
+}
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum InvalidValue {
+    InvalidChar(char),
+
+    InvalidFormat,
+
+    Malformed,
+
+    TooLong,
+
+    TooShort,
+}
+
+impl InvalidValue {
+    pub fn param_error(self) -> Error {
+        Error::ParamValueInvalid(self)
+    }
+
+    pub fn salt_error(self) -> Error {
+        Error::SaltInvalid(self)
+    }
+}
+
+impl fmt::Display for InvalidValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> core::result::Result<(), fmt::Error> {
+        match self {
+            Self::InvalidChar(c) => write!(f, "contains invalid character: '{}'", c),
+            Self::InvalidFormat => f.write_str("value format is invalid"),
+            Self::Malformed => f.write_str("value malformed"),
+            Self::TooLong => f.write_str("value to long"),
+            Self::TooShort => f.write_str("value to short"),
+        }
+    }
+}
@@ -0,0 +1,120 @@
+use crate::{Error, Result};
+use core::{fmt, ops::Deref, str};
+
+#[derive(Copy, Clone, Eq, Hash, PartialEq, PartialOrd, Ord)]
+pub struct Ident<'a>(&'a str);
+
+impl<'a> Ident<'a> {
+    const MAX_LENGTH: usize = 32;
+
+    pub const fn new(s: &'a str) -> Result<Self> {
+        let input = s.as_bytes();
+
+        match input.len() {
+            1..=Self::MAX_LENGTH => {
+                let mut i = 0;
+
+                while i < input.len() {
+                    if !matches!(input[i], b'a'..=b'z' | b'0'..=b'9' | b'-') {
+                        return Err(Error::ParamNameInvalid);
+                    }
+
+                    i += 1;
+                }
+
+                Ok(Self(s))
+            }
+            _ => Err(Error::ParamNameInvalid),
+        }
+    }
+
+    pub const fn new_unwrap(s: &'a str) -> Self {
+        assert!(!s.is_empty(), "PHC ident string can't be empty");
+        assert!(s.len() <= Self::MAX_LENGTH, "PHC ident string too long");
+
+        match Self::new(s) {
+            Ok(ident) => ident,
+            Err(_) => panic!("invalid PHC string format identifier"),
+        }
+    }
+
+    pub fn as_str(&self) -> &'a str {
+        self.0
+    }
+}
+
+impl<'a> AsRef<str> for Ident<'a> {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl<'a> Deref for Ident<'a> {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl<'a> TryFrom<&'a str> for Ident<'a> {
+    type Error = Error;
+
+    fn try_from(s: &'a str) -> Result<Self> {
+        Self::new(s)
+    }
+}
+
+impl<'a> fmt::Display for Ident<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self)
+    }
+}
+
+impl<'a> fmt::Debug for Ident<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Ident").field(&self.as_ref()).finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Error, Ident};
+
+    const INVALID_EMPTY: &str = "";
+    const INVALID_CHAR: &str = "argon2;d";
+    const INVALID_TOO_LONG: &str = "012345678911234567892123456789312";
+    const INVALID_CHAR_AND_TOO_LONG: &str = "0!2345678911234567892123456789312";
+
+    #[test]
+    fn parse_valid() {
+        let valid_examples = ["6", "x", "argon2d", "01234567891123456789212345678931"];
+
+        for &example in &valid_examples {
+            assert_eq!(example, &*Ident::new(example).unwrap());
+        }
+    }
+
+    #[test]
+    fn reject_empty() {
+        assert_eq!(Ident::new(INVALID_EMPTY), Err(Error::ParamNameInvalid));
+    }
+
+    #[test]
+    fn reject_invalid() {
+        assert_eq!(Ident::new(INVALID_CHAR), Err(Error::ParamNameInvalid));
+    }
+
+    #[test]
+    fn reject_too_long() {
+        assert_eq!(Ident::new(INVALID_TOO_LONG), Err(Error::ParamNameInvalid));
+    }
+
+    #[test]
+    fn reject_invalid_char_and_too_long() {
+        assert_eq!(
+            Ident::new(INVALID_CHAR_AND_TOO_LONG),
+            Err(Error::ParamNameInvalid)
+        );
+    }
+}
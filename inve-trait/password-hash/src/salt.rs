@@ -0,0 +1,212 @@
+use crate::{Encoding, Error, Result, Value};
+use core::{fmt, str};
+
+use crate::errors::InvalidValue;
+#[cfg(feature = "rand_core")]
+use rand_core::{CryptoRng, RngCore};
+
+const INVARIANT_VIOLATED_MSG: &str = "salt string invariant violated";
+
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub struct Salt<'a>(Value<'a>);
+
+#[allow(clippy::len_without_is_empty)]
+impl<'a> Salt<'a> {
+    pub const MIN_LENGTH: usize = 4;
+
+    pub const MAX_LENGTH: usize = 64;
+
+    pub const RECOMMENDED_LENGTH: usize = 16;
+
+    pub fn new(input: &'a str) -> Result<Self> {
+        let length = input.len();
+
+        if length < Self::MIN_LENGTH {
+            return Err(Error::SaltInvalid(InvalidValue::TooShort));
+        }
+
+        if length > Self::MAX_LENGTH {
+            return Err(Error::SaltInvalid(InvalidValue::TooLong));
+        }
+
+        input.try_into().map(Self).map_err(|e| match e {
+            Error::ParamValueInvalid(value_err) => Error::SaltInvalid(value_err),
+            err => err,
+        })
+    }
+
+    pub fn b64_decode<'b>(&self, buf: &'b mut [u8]) -> Result<&'b [u8]> {
+        self.0.b64_decode(buf)
+    }
+
+    pub fn as_str(&self) -> &'a str {
+        self.0.as_str()
+    }
+
+    pub fn as_bytes(&self) -> &'a [u8] {
+        self.as_str().as_bytes()
+    }
+
+    pub fn len(&self) -> usize {
+        self.as_str().len()
+    }
+}
+
+impl<'a> AsRef<str> for Salt<'a> {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl<'a> TryFrom<&'a str> for Salt<'a> {
+    type Error = Error;
+
+    fn try_from(input: &'a str) -> Result<Self> {
+        Self::new(input)
+    }
+}
+
+impl<'a> fmt::Display for Salt<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> fmt::Debug for Salt<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Salt({:?})", self.as_str())
+    }
+}
+
+#[derive(Clone, Eq)]
+pub struct SaltString {
+    bytes: [u8; Salt::MAX_LENGTH],
+
+    length: u8,
+}
+
+#[allow(clippy::len_without_is_empty)]
+impl SaltString {
+    #[cfg(feature = "rand_core")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "rand_core")))]
+    pub fn generate(mut rng: impl CryptoRng + RngCore) -> Self {
+        let mut bytes = [0u8; Salt::RECOMMENDED_LENGTH];
+        rng.fill_bytes(&mut bytes);
+        Self::b64_encode(&bytes).expect(INVARIANT_VIOLATED_MSG)
+    }
+
+    pub fn new(s: &str) -> Result<Self> {
+        Salt::new(s)?;
+
+        let length = s.len();
+
+        if length < Salt::MAX_LENGTH {
+            let mut bytes = [0u8; Salt::MAX_LENGTH];
+            bytes[..length].copy_from_slice(s.as_bytes());
+            Ok(SaltString {
+                bytes,
+                length: length as u8,
+            })
+        } else {
+            Err(Error::SaltInvalid(InvalidValue::TooLong))
+        }
+    }
+
+    pub fn b64_encode(input: &[u8]) -> Result<Self> {
+        let mut bytes = [0u8; Salt::MAX_LENGTH];
+        let length = Encoding::B64.encode(input, &mut bytes)?.len() as u8;
+        Ok(Self { bytes, length })
+    }
+
+    pub fn b64_decode<'a>(&self, buf: &'a mut [u8]) -> Result<&'a [u8]> {
+        self.as_salt().b64_decode(buf)
+    }
+
+    pub fn as_salt(&self) -> Salt<'_> {
+        Salt::new(self.as_str()).expect(INVARIANT_VIOLATED_MSG)
+    }
+
+    pub fn as_str(&self) -> &str {
+        str::from_utf8(&self.bytes[..(self.length as usize)]).expect(INVARIANT_VIOLATED_MSG)
+    }
+
+    pub fn as_bytes(&self) -> &[u8] {
+        self.as_str().as_bytes()
+    }
+
+    pub fn len(&self) -> usize {
+        self.as_str().len()
+    }
+}
+
+impl AsRef<str> for SaltString {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl PartialEq for SaltString {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_ref().eq(other.as_ref())
+    }
+}
+
+impl<'a> From<&'a SaltString> for Salt<'a> {
+    fn from(salt_string: &'a SaltString) -> Salt<'a> {
+        salt_string.as_salt()
+    }
+}
+
+impl fmt::Display for SaltString {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl fmt::Debug for SaltString {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "SaltString({:?})", self.as_str())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Error, Salt};
+    use crate::errors::InvalidValue;
+
+    #[test]
+    fn new_with_valid_min_length_input() {
+        let s = "abcd";
+        let salt = Salt::new(s).unwrap();
+        assert_eq!(salt.as_ref(), s);
+    }
+
+    #[test]
+    fn new_with_valid_max_length_input() {
+        let s = "012345678911234567892123456789312345678941234567";
+        let salt = Salt::new(s).unwrap();
+        assert_eq!(salt.as_ref(), s);
+    }
+
+    #[test]
+    fn reject_new_too_short() {
+        for &too_short in &["", "a", "ab", "abc"] {
+            let err = Salt::new(too_short).err().unwrap();
+            assert_eq!(err, Error::SaltInvalid(InvalidValue::TooShort));
+        }
+    }
+
+    #[test]
+    fn reject_new_too_long() {
+        let s = "01234567891123456789212345678931234567894123456785234567896234567";
+        let err = Salt::new(s).err().unwrap();
+        assert_eq!(err, Error::SaltInvalid(InvalidValue::TooLong));
+    }
+
+    #[test]
+    fn reject_new_invalid_char() {
+        let s = "01234_abcd";
+        let err = Salt::new(s).err().unwrap();
+        assert_eq!(err, Error::SaltInvalid(InvalidValue::InvalidChar('_')));
+    }
+}
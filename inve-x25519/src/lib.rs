@@ -0,0 +1,327 @@
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+#[cfg(feature = "pkcs8")]
+#[cfg_attr(docsrs, doc(cfg(feature = "pkcs8")))]
+pub mod pkcs8;
+
+use core::fmt;
+use curve25519::{constants::ED25519_BASEPOINT_TABLE, montgomery::MontgomeryPoint, scalar::Scalar};
+use rand_core::{CryptoRng, RngCore};
+use subtle::ConstantTimeEq;
+use zeroize::{Zeroize, ZeroizeOnDrop};
+
+pub const KEY_SIZE: usize = 32;
+
+/// The u-coordinate of the base point, `9`.
+pub const X25519_BASEPOINT_BYTES: [u8; KEY_SIZE] = [
+    9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+];
+
+/// The `X25519` function of RFC 7748: multiply the u-coordinate `u` by the
+/// scalar `k` after clamping it.
+pub fn x25519(k: [u8; KEY_SIZE], u: [u8; KEY_SIZE]) -> [u8; KEY_SIZE] {
+    let mut scalar = clamp_scalar(k);
+    let point = scalar * MontgomeryPoint(u);
+    scalar.zeroize();
+    point.to_bytes()
+}
+
+fn clamp_scalar(mut bytes: [u8; KEY_SIZE]) -> Scalar {
+    bytes[0] &= 248;
+    bytes[31] &= 127;
+    bytes[31] |= 64;
+    let scalar = Scalar::from_bits(bytes);
+    bytes.zeroize();
+    scalar
+}
+
+fn public_key(secret: &[u8; KEY_SIZE]) -> PublicKey {
+    let mut scalar = clamp_scalar(*secret);
+    let point = (&ED25519_BASEPOINT_TABLE * &scalar).to_montgomery();
+    scalar.zeroize();
+    PublicKey(point)
+}
+
+fn diffie_hellman(secret: &[u8; KEY_SIZE], public_key: &PublicKey) -> SharedSecret {
+    SharedSecret(MontgomeryPoint(x25519(*secret, public_key.to_bytes())))
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct PublicKey(MontgomeryPoint);
+
+impl PublicKey {
+    pub fn as_bytes(&self) -> &[u8; KEY_SIZE] {
+        self.0.as_bytes()
+    }
+
+    pub fn to_bytes(&self) -> [u8; KEY_SIZE] {
+        self.0.to_bytes()
+    }
+}
+
+impl From<[u8; KEY_SIZE]> for PublicKey {
+    fn from(bytes: [u8; KEY_SIZE]) -> Self {
+        Self(MontgomeryPoint(bytes))
+    }
+}
+
+impl AsRef<[u8]> for PublicKey {
+    fn as_ref(&self) -> &[u8] {
+        self.as_bytes()
+    }
+}
+
+impl From<&EphemeralSecret> for PublicKey {
+    fn from(ephemeral_secret: &EphemeralSecret) -> Self {
+        ephemeral_secret.public_key()
+    }
+}
+
+impl From<&StaticSecret> for PublicKey {
+    fn from(static_secret: &StaticSecret) -> Self {
+        static_secret.public_key()
+    }
+}
+
+/// Secret used for a single key exchange. It can be neither serialized nor
+/// cloned.
+pub struct EphemeralSecret {
+    bytes: [u8; KEY_SIZE],
+}
+
+impl EphemeralSecret {
+    pub fn random(mut rng: impl CryptoRng + RngCore) -> Self {
+        let mut bytes = [0u8; KEY_SIZE];
+        rng.fill_bytes(&mut bytes);
+        Self { bytes }
+    }
+
+    pub fn public_key(&self) -> PublicKey {
+        public_key(&self.bytes)
+    }
+
+    pub fn diffie_hellman(&self, public_key: &PublicKey) -> SharedSecret {
+        diffie_hellman(&self.bytes, public_key)
+    }
+}
+
+impl Zeroize for EphemeralSecret {
+    fn zeroize(&mut self) {
+        self.bytes.zeroize()
+    }
+}
+
+impl ZeroizeOnDrop for EphemeralSecret {}
+
+impl Drop for EphemeralSecret {
+    fn drop(&mut self) {
+        self.zeroize();
+    }
+}
+
+/// Long-term secret which may be used for any number of key exchanges.
+///
+/// The bytes are kept as given and only clamped when used, so
+/// [`StaticSecret::to_bytes`] returns exactly what the secret was built from.
+#[derive(Clone)]
+pub struct StaticSecret {
+    bytes: [u8; KEY_SIZE],
+}
+
+impl StaticSecret {
+    pub fn random(mut rng: impl CryptoRng + RngCore) -> Self {
+        let mut bytes = [0u8; KEY_SIZE];
+        rng.fill_bytes(&mut bytes);
+        Self { bytes }
+    }
+
+    pub fn public_key(&self) -> PublicKey {
+        public_key(&self.bytes)
+    }
+
+    pub fn diffie_hellman(&self, public_key: &PublicKey) -> SharedSecret {
+        diffie_hellman(&self.bytes, public_key)
+    }
+
+    pub fn as_bytes(&self) -> &[u8; KEY_SIZE] {
+        &self.bytes
+    }
+
+    pub fn to_bytes(&self) -> [u8; KEY_SIZE] {
+        self.bytes
+    }
+}
+
+impl From<[u8; KEY_SIZE]> for StaticSecret {
+    fn from(bytes: [u8; KEY_SIZE]) -> Self {
+        Self { bytes }
+    }
+}
+
+impl fmt::Debug for StaticSecret {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("StaticSecret { ... }")
+    }
+}
+
+impl Zeroize for StaticSecret {
+    fn zeroize(&mut self) {
+        self.bytes.zeroize()
+    }
+}
+
+impl ZeroizeOnDrop for StaticSecret {}
+
+impl Drop for StaticSecret {
+    fn drop(&mut self) {
+        self.zeroize();
+    }
+}
+
+pub struct SharedSecret(MontgomeryPoint);
+
+impl SharedSecret {
+    pub fn as_bytes(&self) -> &[u8; KEY_SIZE] {
+        self.0.as_bytes()
+    }
+
+    pub fn to_bytes(&self) -> [u8; KEY_SIZE] {
+        self.0.to_bytes()
+    }
+
+    /// Returns `false` if the peer's public key was a low-order point, in
+    /// which case the shared secret is all zeros and does not depend on our
+    /// secret (RFC 7748, section 6.1). Checked in constant time.
+    pub fn was_contributory(&self) -> bool {
+        !bool::from(self.0.as_bytes().ct_eq(&[0u8; KEY_SIZE]))
+    }
+}
+
+impl Zeroize for SharedSecret {
+    fn zeroize(&mut self) {
+        self.0.zeroize()
+    }
+}
+
+impl ZeroizeOnDrop for SharedSecret {}
+
+impl Drop for SharedSecret {
+    fn drop(&mut self) {
+        self.zeroize();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hex_literal::hex;
+
+    #[test]
+    fn rfc7748_vectors() {
+        // Section 5.2
+        assert_eq!(
+            x25519(
+                hex!("a546e36bf0527c9d3b16154b82465edd62144c0ac1fc5a18506a2244ba449ac4"),
+                hex!("e6db6867583030db3594c1a424b15f7c726624ec26b3353b10a903a6d0ab1c4c"),
+            ),
+            hex!("c3da55379de9c6908e94ea4df28d084f32eccf03491c71f754b4075577a28552")
+        );
+        assert_eq!(
+            x25519(
+                hex!("4b66e9d4d1b4673c5ad22691957d6af5c11b6421e0ea01d42ca4169e7918ba0d"),
+                hex!("e5210f12786811d3f4b7959d0538ae2c31dbe7106fc03c3efc4cd549c715a493"),
+            ),
+            hex!("95cbde9476e8907d7aade45cb4b873f88b595a68799fa152e6f8f7647aac7957")
+        );
+    }
+
+    fn iterate(n: usize) -> [u8; KEY_SIZE] {
+        let mut k = X25519_BASEPOINT_BYTES;
+        let mut u = X25519_BASEPOINT_BYTES;
+        for _ in 0..n {
+            let r = x25519(k, u);
+            u = k;
+            k = r;
+        }
+        k
+    }
+
+    #[test]
+    fn rfc7748_iterated() {
+        assert_eq!(
+            iterate(1),
+            hex!("422c8e7a6227d7bca1350b3e2bb7279f7897b87bb6854b783c60e80311ae3079")
+        );
+        assert_eq!(
+            iterate(1_000),
+            hex!("684cf59ba83309552800ef566f2f4d3c1c3887c49360e3875f2eb94d99532c51")
+        );
+    }
+
+    #[test]
+    #[ignore]
+    fn rfc7748_iterated_1m() {
+        assert_eq!(
+            iterate(1_000_000),
+            hex!("7c3911e0ab2586fd864497297e575e6f3bc601c0883c30df5f4dd2d24f665424")
+        );
+    }
+
+    #[test]
+    fn rfc7748_diffie_hellman() {
+        // Section 6.1
+        let alice = StaticSecret::from(hex!(
+            "77076d0a7318a57d3c16c17251b26645df4c2f87ebc0992ab177fba51db92c2a"
+        ));
+        let bob = StaticSecret::from(hex!(
+            "5dab087e624a8a4b79e17f8b83800ee66f3bb1292618b6fd1c2f8b27ff88e0eb"
+        ));
+        let alice_public = alice.public_key();
+        let bob_public = PublicKey::from(&bob);
+        assert_eq!(
+            alice_public.as_bytes(),
+            &hex!("8520f0098930a754748b7ddcb43ef75a0dbf3a0d26381af4eba4a98eaa9b4e6a")
+        );
+        assert_eq!(
+            bob_public.as_bytes(),
+            &hex!("de9edb7d7b7dc1b4d35b61c2ece435373f8343c85b78674dadfc7e146f882b4f")
+        );
+
+        let k = hex!("4a5d9d5ba4ce2de1728e3bf480350f25e07e21c947d19e3376f09b3c1e161742");
+        let alice_shared = alice.diffie_hellman(&bob_public);
+        let bob_shared = bob.diffie_hellman(&alice_public);
+        assert_eq!(alice_shared.as_bytes(), &k);
+        assert_eq!(bob_shared.as_bytes(), &k);
+        assert!(alice_shared.was_contributory());
+    }
+
+    #[test]
+    fn ephemeral_diffie_hellman() {
+        let alice = EphemeralSecret::random(rand_core::OsRng);
+        let bob = StaticSecret::random(rand_core::OsRng);
+        assert_eq!(
+            alice.diffie_hellman(&bob.public_key()).as_bytes(),
+            bob.diffie_hellman(&alice.public_key()).as_bytes()
+        );
+        assert_eq!(
+            bob.public_key().to_bytes(),
+            x25519(bob.to_bytes(), X25519_BASEPOINT_BYTES)
+        );
+    }
+
+    #[test]
+    fn low_order_points() {
+        let secret = StaticSecret::random(rand_core::OsRng);
+        for point in [
+            hex!("0000000000000000000000000000000000000000000000000000000000000000"),
+            hex!("0100000000000000000000000000000000000000000000000000000000000000"),
+            hex!("e0eb7a7c3b41b8ae1656e3faf19fc46ada098deb9c32b1fd866205165f49b800"),
+            hex!("5f9c95bca3508c24b1d0b1559c83ef5b04445cc4581c8e86d8224eddd09f1157"),
+            hex!("ecffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff7f"),
+        ] {
+            let shared = secret.diffie_hellman(&PublicKey::from(point));
+            assert!(!shared.was_contributory());
+        }
+    }
+}
@@ -0,0 +1,153 @@
+pub use pkcs8::{DecodePrivateKey, DecodePublicKey};
+
+#[cfg(feature = "alloc")]
+pub use pkcs8::{spki::EncodePublicKey, EncodePrivateKey};
+
+use crate::{PublicKey, StaticSecret, KEY_SIZE};
+use pkcs8::ObjectIdentifier;
+use zeroize::Zeroizing;
+
+#[cfg(feature = "alloc")]
+use zeroize::Zeroize;
+
+/// `id-X25519` from RFC 8410.
+pub const ALGORITHM_OID: ObjectIdentifier = ObjectIdentifier::new("1.3.101.110");
+
+pub const ALGORITHM_ID: pkcs8::AlgorithmIdentifier<'static> = pkcs8::AlgorithmIdentifier {
+    oid: ALGORITHM_OID,
+    parameters: None,
+};
+
+impl DecodePrivateKey for StaticSecret {}
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+impl EncodePrivateKey for StaticSecret {
+    fn to_pkcs8_der(&self) -> pkcs8::Result<pkcs8::PrivateKeyDocument> {
+        // `CurvePrivateKey ::= OCTET STRING`
+        let mut private_key = [0u8; 2 + KEY_SIZE];
+        private_key[0] = 0x04;
+        private_key[1] = 0x20;
+        private_key[2..].copy_from_slice(&self.bytes);
+
+        let result = pkcs8::PrivateKeyInfo {
+            algorithm: ALGORITHM_ID,
+            private_key: &private_key,
+            public_key: None,
+        }
+        .to_der();
+
+        private_key.zeroize();
+        result
+    }
+}
+
+impl TryFrom<pkcs8::PrivateKeyInfo<'_>> for StaticSecret {
+    type Error = pkcs8::Error;
+
+    fn try_from(private_key: pkcs8::PrivateKeyInfo<'_>) -> pkcs8::Result<Self> {
+        private_key.algorithm.assert_algorithm_oid(ALGORITHM_OID)?;
+
+        if private_key.algorithm.parameters.is_some() {
+            return Err(pkcs8::Error::ParametersMalformed);
+        }
+
+        let bytes: Zeroizing<[u8; KEY_SIZE]> = match private_key.private_key {
+            [0x04, 0x20, rest @ ..] => rest
+                .try_into()
+                .map(Zeroizing::new)
+                .map_err(|_| pkcs8::Error::KeyMalformed),
+            _ => Err(pkcs8::Error::KeyMalformed),
+        }?;
+        let secret = Self::from(*bytes);
+
+        if let Some(public_key) = private_key.public_key {
+            if public_key != secret.public_key().as_bytes() {
+                return Err(pkcs8::Error::KeyMalformed);
+            }
+        }
+
+        Ok(secret)
+    }
+}
+
+impl DecodePublicKey for PublicKey {}
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+impl EncodePublicKey for PublicKey {
+    fn to_public_key_der(&self) -> pkcs8::spki::Result<pkcs8::PublicKeyDocument> {
+        pkcs8::SubjectPublicKeyInfo {
+            algorithm: ALGORITHM_ID,
+            subject_public_key: self.as_bytes(),
+        }
+        .try_into()
+    }
+}
+
+impl TryFrom<pkcs8::spki::SubjectPublicKeyInfo<'_>> for PublicKey {
+    type Error = pkcs8::spki::Error;
+
+    fn try_from(spki: pkcs8::spki::SubjectPublicKeyInfo<'_>) -> pkcs8::spki::Result<Self> {
+        spki.algorithm.assert_algorithm_oid(ALGORITHM_OID)?;
+
+        if spki.algorithm.parameters.is_some() {
+            return Err(pkcs8::spki::Error::KeyMalformed);
+        }
+
+        <[u8; KEY_SIZE]>::try_from(spki.subject_public_key)
+            .map(Self::from)
+            .map_err(|_| pkcs8::spki::Error::KeyMalformed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{DecodePrivateKey, DecodePublicKey};
+    use crate::{PublicKey, StaticSecret};
+    use hex_literal::hex;
+
+    // Key pair generated with `openssl genpkey -algorithm X25519`.
+    const PRIVATE_KEY_DER: [u8; 48] = hex!(
+        "302e020100300506032b656e0422042070ec9da6b2cbb0b404df5202a4bd0a9f94ff4d2b53d4851aca1c7154967ea86e"
+    );
+
+    const PUBLIC_KEY_DER: [u8; 44] = hex!(
+        "302a300506032b656e032100bd76b5f1c21b8fa2d163958a9efd412f856d1a7955d34aacc988154f46de2647"
+    );
+
+    #[test]
+    fn decode() {
+        let secret = StaticSecret::from_pkcs8_der(&PRIVATE_KEY_DER).unwrap();
+        let public = PublicKey::from_public_key_der(&PUBLIC_KEY_DER).unwrap();
+        assert_eq!(
+            secret.as_bytes(),
+            &hex!("70ec9da6b2cbb0b404df5202a4bd0a9f94ff4d2b53d4851aca1c7154967ea86e")
+        );
+        assert_eq!(secret.public_key(), public);
+    }
+
+    #[test]
+    fn wrong_algorithm() {
+        let mut der = PRIVATE_KEY_DER;
+        der[11] = 0x70;
+        assert!(StaticSecret::from_pkcs8_der(&der).is_err());
+
+        let mut der = PUBLIC_KEY_DER;
+        der[8] = 0x70;
+        assert!(PublicKey::from_public_key_der(&der).is_err());
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn encode() {
+        use super::{EncodePrivateKey, EncodePublicKey};
+
+        let secret = StaticSecret::from_pkcs8_der(&PRIVATE_KEY_DER).unwrap();
+        assert_eq!(secret.to_pkcs8_der().unwrap().as_ref(), PRIVATE_KEY_DER);
+        assert_eq!(
+            secret.public_key().to_public_key_der().unwrap().as_ref(),
+            PUBLIC_KEY_DER
+        );
+    }
+}